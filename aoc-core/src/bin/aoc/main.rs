@@ -0,0 +1,349 @@
+use std::collections::HashMap;
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{Duration, Instant};
+
+use rayon::prelude::*;
+
+use aoc_core::utils::file::{data_root, latest_year, read_input};
+use aoc_core::utils::pool::{
+    available_threads, threads_per_job, THREADS_ENV_VAR,
+};
+use aoc_core::utils::report::{Record, FORMAT_ENV_VAR};
+
+const USAGE: &str = "usage: aoc [--year YEAR] DAY...
+       aoc [--year YEAR] all [--jobs N]";
+
+/// Runs the solutions for a year, each with `cargo run --release -p aocYEAR
+/// --bin dayNN`. The year defaults to the newest one in `data`.
+///
+/// Given days, runs them one after another with their output passed straight
+/// through. Given `all`, runs every day of the year, up to N at once (default:
+/// one per CPU), then prints a table of answers and times, checking answers
+/// against those recorded in `data/YEAR/answers.txt`.
+fn main() -> Result<(), Box<dyn Error>> {
+    let mut args: Vec<_> = env::args().skip(1).collect();
+    let year = match args.first().map(String::as_str) {
+        Some("--year") if args.len() >= 2 => {
+            let year = args[1].parse()?;
+            args.drain(..2);
+            year
+        },
+        _ => latest_year()?,
+    };
+
+    // Run from the workspace root, which holds the data root, so each day
+    // finds its inputs wherever this was started from.
+    let data = data_root()?;
+    let runner = Runner {
+        root: data.parent().unwrap_or(Path::new(".")).to_path_buf(),
+        year,
+        cargo: env::var("CARGO").unwrap_or_else(|_| "cargo".to_string()),
+    };
+
+    if args.first().map(String::as_str) == Some("all") {
+        let jobs = match &args[1..] {
+            [] => available_threads(),
+            [flag, jobs] if flag == "--jobs" => jobs.parse()?,
+            _ => return Err(USAGE.into()),
+        };
+        if jobs == 0 {
+            return Err("--jobs must be at least 1".into());
+        }
+        return run_all(&runner, jobs);
+    }
+
+    let days = args
+        .iter()
+        .map(|d| d.parse::<u8>())
+        .collect::<Result<Vec<_>, _>>()?;
+    if days.is_empty() {
+        return Err(USAGE.into());
+    }
+
+    for day in days {
+        let status = runner.run(day).status()?;
+        if !status.success() {
+            return Err(format!("{year} day {day} failed: {status}").into());
+        }
+    }
+
+    Ok(())
+}
+
+/// Runs every day of runner's year, up to jobs at once, writing each day's
+/// output to stderr as it finishes and then a summary table to stdout. Fails
+/// if any day fails or gives an answer other than the one recorded.
+fn run_all(runner: &Runner, jobs: usize) -> Result<(), Box<dyn Error>> {
+    let days = runner.days()?;
+    let recorded = recorded_answers(runner.year)?;
+
+    // Build everything up front, so the days don't all wait on cargo's lock
+    // while one of them builds.
+    let status = runner.cargo("build").arg("--bins").status()?;
+    if !status.success() {
+        return Err(format!("building {} failed: {status}", runner.year).into());
+    }
+
+    let threads = threads_per_job(available_threads(), jobs);
+    let pool = rayon::ThreadPoolBuilder::new().num_threads(jobs).build()?;
+
+    let start = Instant::now();
+    let runs: Vec<_> = pool.install(|| {
+        days.par_iter()
+            .map(|&day| {
+                let run = runner.run_captured(day, threads);
+                let status = run.status(&recorded);
+                let mut stderr = io::stderr().lock();
+                let _ = writeln!(
+                    stderr,
+                    "== day {day:02}: {status} in {:.2?} ==\n{}",
+                    run.elapsed, run.output
+                );
+                if let Some(e) = &run.error {
+                    let _ = writeln!(stderr, "day {day:02} failed: {e}\n");
+                }
+                (run, status)
+            })
+            .collect()
+    });
+    let wall = start.elapsed();
+
+    print_table(&runs);
+
+    let count = |s| runs.iter().filter(|(_, status)| *status == s).count();
+    let (failed, errors) = (count(Status::Fail), count(Status::Error));
+    println!(
+        "{} days in {wall:.2?} with {jobs} jobs: {} passed, {failed} failed, \
+        {errors} errors, {} unrecorded",
+        runs.len(),
+        count(Status::Pass),
+        count(Status::Unrecorded),
+    );
+
+    if failed + errors > 0 {
+        let bad = failed + errors;
+        return Err(format!("{bad} of {} days failed", runs.len()).into());
+    }
+    Ok(())
+}
+
+/// A Runner runs the days of one year's crate with cargo.
+struct Runner {
+    root: PathBuf,
+    year: u16,
+    cargo: String,
+}
+
+impl Runner {
+    /// Returns a cargo command for the year's crate in release mode.
+    fn cargo(&self, subcommand: &str) -> Command {
+        let mut cmd = Command::new(&self.cargo);
+        cmd.current_dir(&self.root)
+            .args([subcommand, "--release", "-q", "-p"])
+            .arg(format!("aoc{}", self.year));
+        cmd
+    }
+
+    /// Returns the command running the given day.
+    fn run(&self, day: u8) -> Command {
+        let mut cmd = self.cargo("run");
+        cmd.arg("--bin").arg(format!("day{day:02}"));
+        cmd
+    }
+
+    /// Returns every day the year's crate has a binary for, in order.
+    fn days(&self) -> Result<Vec<u8>, io::Error> {
+        let bin_dir = self.root.join(format!("aoc{}/src/bin", self.year));
+        let mut days = Vec::new();
+        for entry in fs::read_dir(bin_dir)? {
+            let name = entry?.file_name();
+            let day = name.to_str().and_then(|n| n.strip_prefix("day"));
+            if let Some(Ok(day)) = day.map(str::parse) {
+                days.push(day);
+            }
+        }
+        days.sort_unstable();
+        Ok(days)
+    }
+
+    /// Runs the given day with its output captured, letting it use the given
+    /// number of threads.
+    fn run_captured(&self, day: u8, threads: usize) -> DayRun {
+        let start = Instant::now();
+        let output = self
+            .run(day)
+            .env(FORMAT_ENV_VAR, "tsv")
+            .env(THREADS_ENV_VAR, threads.to_string())
+            .output();
+        let mut run = DayRun {
+            day,
+            records: Vec::new(),
+            output: String::new(),
+            elapsed: start.elapsed(),
+            error: None,
+        };
+
+        match output {
+            Ok(out) => {
+                run.output.push_str(&String::from_utf8_lossy(&out.stderr));
+                for line in String::from_utf8_lossy(&out.stdout).lines() {
+                    match line.parse() {
+                        Ok(record) => run.records.push(record),
+                        Err(_) => {
+                            run.output.push_str(line);
+                            run.output.push('\n');
+                        },
+                    }
+                }
+                if !out.status.success() {
+                    run.error = Some(out.status.to_string());
+                }
+            },
+            Err(e) => run.error = Some(e.to_string()),
+        }
+
+        run
+    }
+}
+
+/// The result of running a day with its output captured.
+struct DayRun {
+    day: u8,
+    /// The answers the day gave, in the order it gave them.
+    records: Vec<Record>,
+    /// Everything else the day wrote, i.e. its stderr and any stdout that
+    /// wasn't an answer.
+    output: String,
+    /// The wall time taken by the day's process.
+    elapsed: Duration,
+    /// Why the day failed, if it did.
+    error: Option<String>,
+}
+
+impl DayRun {
+    /// Checks the day's answers against the recorded ones. An answer recorded
+    /// for a different input (i.e. with a different input hash) is ignored.
+    fn status(&self, recorded: &HashMap<(u8, u8), Record>) -> Status {
+        if self.error.is_some() {
+            return Status::Error;
+        }
+
+        let mut checked = false;
+        for r in &self.records {
+            match recorded.get(&(r.day, r.part)) {
+                Some(want) if want.input_hash == r.input_hash => {
+                    if want.answer != r.answer {
+                        return Status::Fail;
+                    }
+                    checked = true;
+                },
+                _ => (),
+            }
+        }
+
+        if checked {
+            Status::Pass
+        } else {
+            Status::Unrecorded
+        }
+    }
+
+    /// Returns the answer given for a part, if any.
+    fn answer(&self, part: u8) -> Option<&str> {
+        self.records
+            .iter()
+            .find(|r| r.part == part)
+            .map(|r| r.answer.as_str())
+    }
+}
+
+/// How a day's run compared with the recorded answers.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Status {
+    Pass,
+    Fail,
+    /// No answers for the day's input were recorded.
+    Unrecorded,
+    /// The day couldn't be run or exited unsuccessfully.
+    Error,
+}
+
+impl std::fmt::Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Pass => "pass",
+            Self::Fail => "FAIL",
+            Self::Unrecorded => "-",
+            Self::Error => "ERROR",
+        })
+    }
+}
+
+/// Reads the answers recorded for a year, keyed by day and part. They're kept
+/// in `data/YEAR/answers.txt`, as lines written by a Report in the Tsv format,
+/// so can be recorded with e.g. `AOC_FORMAT=tsv cargo run --bin day01 >>
+/// data/2022/answers.txt`. A year without the file has no recorded answers.
+fn recorded_answers(
+    year: u16,
+) -> Result<HashMap<(u8, u8), Record>, Box<dyn Error>> {
+    let contents = match read_input(year, "answers") {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(e.into()),
+    };
+
+    let mut answers = HashMap::new();
+    for (i, line) in contents.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let record: Record = line
+            .parse()
+            .map_err(|e| format!("answers.txt line {}: {e}", i + 1))?;
+        if record.year == year {
+            answers.insert((record.day, record.part), record);
+        }
+    }
+
+    Ok(answers)
+}
+
+/// Prints a table of each day's answers, time and status to stdout.
+fn print_table(runs: &[(DayRun, Status)]) {
+    let header = ["day", "part 1", "part 2", "time", "status"];
+    let rows: Vec<[String; 5]> = runs
+        .iter()
+        .map(|(run, status)| {
+            [
+                run.day.to_string(),
+                run.answer(1).unwrap_or("-").to_string(),
+                run.answer(2).unwrap_or("-").to_string(),
+                format!("{:.2?}", run.elapsed),
+                status.to_string(),
+            ]
+        })
+        .collect();
+
+    let mut widths = header.map(str::len);
+    for row in &rows {
+        for (w, cell) in widths.iter_mut().zip(row) {
+            *w = (*w).max(cell.len());
+        }
+    }
+
+    let mut stdout = io::stdout().lock();
+    for row in [header.map(String::from)].iter().chain(&rows) {
+        let line = row
+            .iter()
+            .zip(widths)
+            .map(|(cell, w)| format!("{cell:<w$}"))
+            .collect::<Vec<_>>()
+            .join("  ");
+        let _ = writeln!(stdout, "{}", line.trim_end());
+    }
+}
@@ -0,0 +1,39 @@
+use std::env;
+use std::error::Error;
+
+use aoc_core::utils::fetch::Fetcher;
+use aoc_core::utils::file::latest_year;
+
+/// Downloads the inputs for the days given as arguments into `data/YEAR`,
+/// skipping any already present. The year defaults to the newest one in
+/// `data`. Needs AOC_SESSION set to an adventofcode.com session cookie.
+fn main() -> Result<(), Box<dyn Error>> {
+    let mut args: Vec<_> = env::args().skip(1).collect();
+    let year = match args.first().map(String::as_str) {
+        Some("--year") if args.len() >= 2 => {
+            let year = args[1].parse()?;
+            args.drain(..2);
+            year
+        },
+        _ => latest_year()?,
+    };
+    let days = args
+        .iter()
+        .map(|d| d.parse::<u8>())
+        .collect::<Result<Vec<_>, _>>()?;
+    if days.is_empty() {
+        return Err("usage: fetch [--year YEAR] DAY...".into());
+    }
+
+    let fetcher = Fetcher::from_env()?;
+    for day in days {
+        let (path, downloaded) = fetcher.fetch(year, day)?;
+        if downloaded {
+            println!("downloaded {}", path.display());
+        } else {
+            println!("{} already present", path.display());
+        }
+    }
+
+    Ok(())
+}
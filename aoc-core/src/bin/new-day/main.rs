@@ -0,0 +1,162 @@
+use std::env;
+use std::error::Error;
+use std::fs::{self, OpenOptions};
+use std::io;
+use std::path::{Path, PathBuf};
+
+use aoc_core::utils::file::{data_root, latest_year};
+
+/// Source files for a new day, as (file name, template) pairs.
+const TEMPLATES: [(&str, &str); 4] = [
+    ("main.rs", include_str!("template/main.rs.tmpl")),
+    ("parse.rs", include_str!("template/parse.rs.tmpl")),
+    ("part1.rs", include_str!("template/part1.rs.tmpl")),
+    ("part2.rs", include_str!("template/part2.rs.tmpl")),
+];
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let args: Vec<_> = env::args().skip(1).collect();
+    let (year, day) = match args.as_slice() {
+        [flag, year, day] if flag == "--year" => (year.parse()?, day.parse()?),
+        [day] => (latest_year()?, day.parse()?),
+        _ => return Err("usage: new-day [--year YEAR] DAY".into()),
+    };
+
+    // The workspace root is the directory holding the data root.
+    let data = data_root()?;
+    let root = data.parent().unwrap_or(Path::new("."));
+    for path in generate(root, year, day)? {
+        println!("created {}", path.display());
+    }
+
+    Ok(())
+}
+
+/// Creates the skeleton for the given day under the workspace root: the
+/// sources in `aocYEAR/src/bin/dayNN` and empty input files in `data/YEAR`.
+/// The year's crate must already exist. Refuses to touch a day whose sources
+/// already exist; input files that already exist are kept as-is. Returns the
+/// paths created.
+fn generate(
+    root: &Path,
+    year: u16,
+    day: u8,
+) -> Result<Vec<PathBuf>, Box<dyn Error>> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("day must be in 1..=25, got {day}").into());
+    }
+
+    let crate_dir = root.join(format!("aoc{year}"));
+    if !crate_dir.join("Cargo.toml").exists() {
+        return Err(
+            format!("no crate for {year} at {}", crate_dir.display()).into()
+        );
+    }
+
+    let name = format!("day{day:02}");
+    let src_dir = crate_dir.join("src/bin").join(&name);
+    if src_dir.exists() {
+        return Err(format!("{} already exists", src_dir.display()).into());
+    }
+
+    let mut created = Vec::new();
+
+    fs::create_dir_all(&src_dir)?;
+    for (file, template) in TEMPLATES {
+        let path = src_dir.join(file);
+        fs::write(&path, render(template, year, day, &name))?;
+        created.push(path);
+    }
+
+    let data_dir = root.join("data").join(year.to_string());
+    for data_dir in [data_dir.clone(), data_dir.join("example")] {
+        fs::create_dir_all(&data_dir)?;
+        let path = data_dir.join(format!("{name}.txt"));
+        match OpenOptions::new().write(true).create_new(true).open(&path) {
+            Ok(_) => created.push(path),
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => (),
+            Err(e) => return Err(e.into()),
+        }
+    }
+
+    Ok(created)
+}
+
+/// Fills in a template's {{YEAR}} (e.g. 2022), {{DAY}} (e.g. 7) and {{NAME}}
+/// (e.g. day07).
+fn render(template: &str, year: u16, day: u8, name: &str) -> String {
+    template
+        .replace("{{YEAR}}", &year.to_string())
+        .replace("{{DAY}}", &day.to_string())
+        .replace("{{NAME}}", name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate() {
+        let root = env::temp_dir()
+            .join(format!("aoc-new-day-test-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+
+        // Days can only be added to a year whose crate exists.
+        assert!(generate(&root, 2022, 7).is_err());
+        fs::create_dir_all(root.join("aoc2022")).unwrap();
+        fs::write(root.join("aoc2022/Cargo.toml"), "").unwrap();
+
+        // An input that's already been downloaded should be left alone.
+        fs::create_dir_all(root.join("data/2022")).unwrap();
+        fs::write(root.join("data/2022/day07.txt"), "real input\n").unwrap();
+
+        let created = generate(&root, 2022, 7).unwrap();
+        assert_eq!(
+            created,
+            vec![
+                root.join("aoc2022/src/bin/day07/main.rs"),
+                root.join("aoc2022/src/bin/day07/parse.rs"),
+                root.join("aoc2022/src/bin/day07/part1.rs"),
+                root.join("aoc2022/src/bin/day07/part2.rs"),
+                root.join("data/2022/example/day07.txt"),
+            ]
+        );
+
+        let main =
+            fs::read_to_string(root.join("aoc2022/src/bin/day07/main.rs"));
+        let main = main.unwrap();
+        assert!(main.contains("use aoc2022::YEAR;"));
+        assert!(main.contains("Report::new(YEAR, 7)"));
+        assert!(main.contains(r#"get_input_lines(YEAR, "day07")"#));
+        assert!(!main.contains("{{"));
+
+        let part1 =
+            fs::read_to_string(root.join("aoc2022/src/bin/day07/part1.rs"));
+        assert!(part1.unwrap().contains(r#""example/day07""#));
+
+        assert_eq!(
+            fs::read_to_string(root.join("data/2022/day07.txt")).unwrap(),
+            "real input\n"
+        );
+        assert_eq!(
+            fs::read_to_string(root.join("data/2022/example/day07.txt"))
+                .unwrap(),
+            ""
+        );
+
+        // A second run must not overwrite the day.
+        fs::write(root.join("aoc2022/src/bin/day07/part1.rs"), "edited")
+            .unwrap();
+        assert!(generate(&root, 2022, 7).is_err());
+        assert_eq!(
+            fs::read_to_string(root.join("aoc2022/src/bin/day07/part1.rs"))
+                .unwrap(),
+            "edited"
+        );
+
+        assert!(generate(&root, 2022, 0).is_err());
+        assert!(generate(&root, 2022, 26).is_err());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,5 @@
+//! Code shared between each year's solutions: common types, input handling,
+//! parsing and reporting.
+
+pub mod types;
+pub mod utils;
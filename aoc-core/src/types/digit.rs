@@ -0,0 +1,470 @@
+use std::cmp::Ordering;
+use std::fmt::{self, Write};
+use std::iter::Sum;
+use std::ops::Add;
+use std::str::FromStr;
+
+use num::BigInt;
+
+use super::grid::FixedWidthDisplay;
+use crate::utils::int::{ArithError, Int};
+
+/// A Digit is a single digit in base BASE, which defaults to decimal.
+///
+/// Ordinary bases are 2..=36, with digits written 0-9 then a-z (either case is
+/// accepted when parsing). Balanced bases have digits centred on zero, and are
+/// written as in SNAFU: '=' is -2, '-' is -1, then 0, 1 and 2. So balanced
+/// bases are 3 and 5. Using any other base fails to compile.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Ord, PartialOrd)]
+pub struct Digit<const BASE: u8 = 10, const BALANCED: bool = false>(i8);
+
+/// A digit of a SNAFU number: balanced base 5.
+pub type SnafuDigit = Digit<5, true>;
+
+impl<const BASE: u8, const BALANCED: bool> Digit<BASE, BALANCED> {
+    const VALID_BASE: () = assert!(
+        if BALANCED {
+            BASE == 3 || BASE == 5
+        } else {
+            2 <= BASE && BASE <= 36
+        },
+        "unsupported base"
+    );
+
+    /// The smallest value a digit can have.
+    pub const MIN: i8 = if BALANCED { -((BASE / 2) as i8) } else { 0 };
+
+    /// The largest value a digit can have.
+    pub const MAX: i8 = Self::MIN + (BASE as i8 - 1);
+
+    /// Creates a Digit with the given value, if it's in MIN..=MAX.
+    pub fn new(value: i8) -> Option<Self> {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID_BASE;
+        (Self::MIN..=Self::MAX)
+            .contains(&value)
+            .then_some(Self(value))
+    }
+
+    /// Returns the digit's value.
+    pub fn value(self) -> i8 {
+        self.0
+    }
+}
+
+impl<const BASE: u8, const BALANCED: bool> TryFrom<u8>
+    for Digit<BASE, BALANCED>
+{
+    type Error = &'static str;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        i8::try_from(value)
+            .ok()
+            .and_then(Self::new)
+            .ok_or("digit is out of range for its base")
+    }
+}
+
+impl<const BASE: u8> From<Digit<BASE, false>> for u8 {
+    fn from(value: Digit<BASE, false>) -> Self {
+        value.0 as u8
+    }
+}
+
+impl<const BASE: u8, const BALANCED: bool> TryFrom<char>
+    for Digit<BASE, BALANCED>
+{
+    type Error = &'static str;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        let v = match value {
+            '=' if BALANCED => Some(-2),
+            '-' if BALANCED => Some(-1),
+            c => c.to_digit(36).map(|v| v as i8),
+        };
+        v.and_then(Self::new)
+            .ok_or("char given was not a digit in this base")
+    }
+}
+
+impl<const BASE: u8, const BALANCED: bool> From<Digit<BASE, BALANCED>>
+    for char
+{
+    fn from(value: Digit<BASE, BALANCED>) -> Self {
+        match value.0 {
+            -2 => '=',
+            -1 => '-',
+            v => Self::from_digit(v as u32, 36).unwrap(),
+        }
+    }
+}
+
+impl<const BASE: u8, const BALANCED: bool> fmt::Display
+    for Digit<BASE, BALANCED>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_char((*self).into())
+    }
+}
+
+impl<const BASE: u8, const BALANCED: bool> FixedWidthDisplay
+    for Digit<BASE, BALANCED>
+{
+}
+
+/// A Numeral is an integer written out in digits of base BASE, as Digit. In
+/// ordinary bases a negative Numeral is written with a leading '-'; in balanced
+/// ones the digits carry the sign. Leading zeros are dropped, and zero is
+/// written "0".
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Numeral<const BASE: u8 = 10, const BALANCED: bool = false> {
+    /// The digits, least significant first, without leading zeros.
+    digits: Vec<Digit<BASE, BALANCED>>,
+    /// Whether the number is negative. Only used in ordinary bases.
+    negative: bool,
+}
+
+/// A SNAFU number: balanced base 5, e.g. "1=-0-2" is 1747.
+pub type Snafu = Numeral<5, true>;
+
+impl<const BASE: u8, const BALANCED: bool> Numeral<BASE, BALANCED> {
+    /// Returns the digits, least significant first. Zero has none.
+    pub fn digits(&self) -> &[Digit<BASE, BALANCED>] {
+        &self.digits
+    }
+
+    /// Returns whether the number is less than zero.
+    pub fn is_negative(&self) -> bool {
+        match self.digits.last() {
+            Some(d) if BALANCED => d.0 < 0,
+            _ => self.negative,
+        }
+    }
+
+    /// Writes out an integer of any Int type in this base.
+    pub fn from_int<T: Int>(n: &T) -> Self {
+        let base = T::from(BASE);
+        let negative = !BALANCED && *n < T::zero();
+        let mut n = n.clone();
+        let mut digits = Vec::new();
+
+        while !n.is_zero() {
+            let (q, r) = if BALANCED {
+                // Take the remainder in 0..BASE, then shift it into the
+                // digits' range. That's done on the digit rather than in T,
+                // which may be unsigned.
+                let (q, r) = n.div_mod_floor(&base);
+                let r = r.to_i8().unwrap();
+                if r > Digit::<BASE, BALANCED>::MAX {
+                    (q + T::one(), r - BASE as i8)
+                } else {
+                    (q, r)
+                }
+            } else {
+                // Truncating division gives the digits of a negative number's
+                // magnitude (negated), without having to negate it first.
+                let (q, r) = n.div_rem(&base);
+                (q, r.to_i8().unwrap().abs())
+            };
+            digits.push(Digit::new(r).unwrap());
+            n = q;
+        }
+
+        Numeral { digits, negative }
+    }
+
+    /// Converts the number to any Int type, failing if it doesn't fit.
+    pub fn to_int<T: Int>(&self) -> Result<T, ArithError> {
+        let base_less_one = T::from(BASE - 1);
+        self.digits.iter().rev().try_fold(T::zero(), |acc, d| {
+            // acc * BASE + d, but adding the last acc after the digit: in a
+            // balanced base the digit can pull the result back into range
+            // when acc * BASE alone would be out of it.
+            let scaled = acc.try_mul(&base_less_one)?;
+            let v = T::from(d.0.unsigned_abs());
+            let scaled = if (d.0 < 0) != self.negative {
+                scaled.try_sub(&v)?
+            } else {
+                scaled.try_add(&v)?
+            };
+            scaled.try_add(&acc)
+        })
+    }
+
+    /// Builds a Numeral from digit values, least significant first, which may
+    /// be outside the digits' range, by carrying between them.
+    fn from_sums(sums: impl Iterator<Item = i32>, negative: bool) -> Self {
+        let base = BASE as i32;
+        let min = Digit::<BASE, BALANCED>::MIN as i32;
+        let mut digits = Vec::new();
+        let mut carry = 0;
+
+        let mut sums = sums.fuse();
+        loop {
+            let s = match sums.next() {
+                Some(s) => s + carry,
+                None if carry != 0 => carry,
+                None => break,
+            };
+            carry = (s - min).div_euclid(base);
+            digits.push(Digit::new((s - carry * base) as i8).unwrap());
+        }
+
+        while digits.last() == Some(&Digit(0)) {
+            digits.pop();
+        }
+        let negative = negative && !digits.is_empty();
+        Numeral { digits, negative }
+    }
+
+    /// Compares the magnitudes of two numbers in an ordinary base.
+    fn cmp_magnitude(&self, other: &Self) -> Ordering {
+        self.digits.len().cmp(&other.digits.len()).then_with(|| {
+            self.digits.iter().rev().cmp(other.digits.iter().rev())
+        })
+    }
+}
+
+impl<const BASE: u8, const BALANCED: bool> FromStr for Numeral<BASE, BALANCED> {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, digits) = match s.strip_prefix('-') {
+            Some(rest) if !BALANCED => (true, rest),
+            _ => (false, s),
+        };
+        if digits.is_empty() {
+            return Err("no digits given");
+        }
+
+        let digits = digits
+            .chars()
+            .rev()
+            .map(Digit::<BASE, BALANCED>::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self::from_sums(
+            digits.into_iter().map(|d| d.0.into()),
+            negative,
+        ))
+    }
+}
+
+impl<const BASE: u8, const BALANCED: bool> fmt::Display
+    for Numeral<BASE, BALANCED>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.digits.is_empty() {
+            return f.write_char('0');
+        }
+        if self.negative {
+            f.write_char('-')?;
+        }
+        for d in self.digits.iter().rev() {
+            d.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl<const BASE: u8, const BALANCED: bool> Add for &Numeral<BASE, BALANCED> {
+    type Output = Numeral<BASE, BALANCED>;
+
+    /// Adds digit by digit, so never overflows.
+    fn add(self, rhs: Self) -> Self::Output {
+        let value = |n: Self, i: usize| -> i32 {
+            n.digits.get(i).map_or(0, |d| d.0.into())
+        };
+        let len = self.digits.len().max(rhs.digits.len());
+
+        if BALANCED || self.negative == rhs.negative {
+            let sums = (0..len).map(|i| value(self, i) + value(rhs, i));
+            return Numeral::from_sums(sums, self.negative);
+        }
+
+        // The signs differ, so subtract the smaller magnitude from the larger,
+        // and take the larger's sign.
+        let (big, small) = match self.cmp_magnitude(rhs) {
+            Ordering::Less => (rhs, self),
+            _ => (self, rhs),
+        };
+        let diffs = (0..len).map(|i| value(big, i) - value(small, i));
+        Numeral::from_sums(diffs, big.negative)
+    }
+}
+
+impl<const BASE: u8, const BALANCED: bool> Add for Numeral<BASE, BALANCED> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        &self + &rhs
+    }
+}
+
+impl<const BASE: u8, const BALANCED: bool> Sum for Numeral<BASE, BALANCED> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), |a, b| &a + &b)
+    }
+}
+
+impl<const BASE: u8, const BALANCED: bool> From<i64>
+    for Numeral<BASE, BALANCED>
+{
+    fn from(value: i64) -> Self {
+        Self::from_int(&value)
+    }
+}
+
+impl<const BASE: u8, const BALANCED: bool> From<&BigInt>
+    for Numeral<BASE, BALANCED>
+{
+    fn from(value: &BigInt) -> Self {
+        Self::from_int(value)
+    }
+}
+
+impl<const BASE: u8, const BALANCED: bool> TryFrom<&Numeral<BASE, BALANCED>>
+    for i64
+{
+    type Error = ArithError;
+
+    fn try_from(value: &Numeral<BASE, BALANCED>) -> Result<Self, Self::Error> {
+        value.to_int()
+    }
+}
+
+impl<const BASE: u8, const BALANCED: bool> From<&Numeral<BASE, BALANCED>>
+    for BigInt
+{
+    fn from(value: &Numeral<BASE, BALANCED>) -> Self {
+        value.to_int().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num::BigUint;
+
+    use super::*;
+
+    #[test]
+    fn test_digit() {
+        let d: Digit = '7'.try_into().unwrap();
+        assert_eq!(u8::from(d), 7);
+        assert_eq!(char::from(d), '7');
+        assert!(Digit::<10>::try_from('a').is_err());
+        assert!(Digit::<10>::try_from(10u8).is_err());
+
+        let d: Digit<16> = 'F'.try_into().unwrap();
+        assert_eq!(d.value(), 15);
+        assert_eq!(d.to_string(), "f");
+        assert!(Digit::<2>::try_from('2').is_err());
+
+        let d: SnafuDigit = '='.try_into().unwrap();
+        assert_eq!(d.value(), -2);
+        assert_eq!(SnafuDigit::new(3), None);
+        assert_eq!((SnafuDigit::MIN, SnafuDigit::MAX), (-2, 2));
+        assert_eq!((Digit::<3, true>::MIN, Digit::<3, true>::MAX), (-1, 1));
+    }
+
+    #[test]
+    fn test_snafu() {
+        // The examples from Advent of Code 2022 day 25.
+        let examples: [(i64, &str); 8] = [
+            (1, "1"),
+            (3, "1="),
+            (8, "2="),
+            (10, "20"),
+            (2022, "1=11-2"),
+            (12345, "1-0---0"),
+            (314159265, "1121-1110-1=0"),
+            (4890, "2=-1=0"),
+        ];
+        for (n, s) in examples {
+            let snafu: Snafu = s.parse().unwrap();
+            assert_eq!(i64::try_from(&snafu), Ok(n), "{s}");
+            assert_eq!(Snafu::from(n).to_string(), s);
+        }
+
+        let sum: Snafu = [
+            "1=-0-2", "12111", "2=0=", "21", "2=01", "111", "20012", "112",
+            "1=-1=", "1-12", "12", "1=", "122",
+        ]
+        .into_iter()
+        .map(|s| s.parse::<Snafu>().unwrap())
+        .sum();
+        assert_eq!(sum.to_string(), "2=-1=0");
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        assert_eq!("-ff".parse::<Numeral<16>>().unwrap().to_int(), Ok(-255));
+        assert_eq!("007".parse::<Numeral>().unwrap().to_string(), "7");
+        assert_eq!("-0".parse::<Numeral>().unwrap().to_string(), "0");
+        assert_eq!("--".parse::<Snafu>().unwrap().to_int(), Ok(-6));
+        assert!("".parse::<Numeral>().is_err());
+        assert!("-".parse::<Numeral>().is_err());
+        assert!("12".parse::<Numeral<2>>().is_err());
+        assert_eq!(Numeral::<36>::from(35).to_string(), "z");
+        assert_eq!(Numeral::<2>::from(-6).to_string(), "-110");
+    }
+
+    #[test]
+    fn test_round_trip() {
+        fn check<const B: u8, const BAL: bool>(n: i64) {
+            let numeral = Numeral::<B, BAL>::from(n);
+            assert_eq!(numeral.is_negative(), n < 0);
+            assert_eq!(i64::try_from(&numeral), Ok(n), "{numeral}");
+            let parsed: Numeral<B, BAL> = numeral.to_string().parse().unwrap();
+            assert_eq!(parsed, numeral);
+        }
+
+        for n in [0, 1, -1, 35, -36, 1 << 40, i64::MAX, i64::MIN] {
+            check::<2, false>(n);
+            check::<10, false>(n);
+            check::<36, false>(n);
+            check::<3, true>(n);
+            check::<5, true>(n);
+        }
+
+        // Unsigned types, where a balanced digit can't be worked out in T.
+        fn check_unsigned<const B: u8, const BAL: bool>(n: u64) {
+            let numeral = Numeral::<B, BAL>::from_int(&n);
+            assert_eq!(numeral.to_int::<u64>(), Ok(n), "{numeral}");
+            let big = BigUint::from(n);
+            assert_eq!(Numeral::<B, BAL>::from_int(&big), numeral);
+            assert_eq!(numeral.to_int::<BigUint>(), Ok(big));
+        }
+        for n in [0, 1, 3, 4, 35, 1 << 40, u64::MAX] {
+            check_unsigned::<2, false>(n);
+            check_unsigned::<10, false>(n);
+            check_unsigned::<3, true>(n);
+            check_unsigned::<5, true>(n);
+        }
+        assert_eq!(Snafu::from_int(&3u64).to_string(), "1=");
+
+        // i64::MIN's magnitude doesn't fit, but it can be reached by adding.
+        let min = Numeral::<10>::from(i64::MIN) + Numeral::from(-1);
+        assert_eq!(min.to_string(), "-9223372036854775809");
+        assert_eq!(i64::try_from(&min), Err(ArithError::Overflow));
+        let big = BigInt::from(&min);
+        assert_eq!(Numeral::<10>::from(&big), min);
+    }
+
+    #[test]
+    fn test_add() {
+        for a in -30..=30 {
+            for b in -30..=30 {
+                let dec = Numeral::<10>::from(a) + Numeral::from(b);
+                assert_eq!(dec.to_int(), Ok(a + b), "{a} + {b}");
+                let bin = Numeral::<2>::from(a) + Numeral::from(b);
+                assert_eq!(bin.to_int(), Ok(a + b), "{a} + {b}");
+                let snafu = Snafu::from(a) + Snafu::from(b);
+                assert_eq!(snafu.to_int(), Ok(a + b), "{a} + {b}");
+                assert_eq!(snafu, Snafu::from(a + b));
+            }
+        }
+
+        let big = Snafu::from(i64::MAX) + Snafu::from(i64::MAX);
+        assert_eq!(BigInt::from(&big), BigInt::from(i64::MAX) * 2);
+    }
+}
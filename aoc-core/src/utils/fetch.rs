@@ -0,0 +1,277 @@
+use std::env;
+use std::error::Error;
+use std::fs::{self, File};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use super::file::data_root;
+
+/// The environment variable holding the adventofcode.com session cookie.
+pub const SESSION_ENV_VAR: &str = "AOC_SESSION";
+
+/// The environment variable overriding the base URL inputs are fetched from.
+pub const BASE_URL_ENV_VAR: &str = "AOC_BASE_URL";
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const USER_AGENT: &str = "github.com/SeanGeb/AoC-2022 input fetcher";
+
+/// The name of the file, under the data root, whose modification time records
+/// when the last request was made. It's shared by every year, as the rate limit
+/// is per site rather than per event.
+const STAMP_FILE: &str = ".last-fetch";
+
+/// A Fetcher downloads puzzle inputs into the data root, under a directory for
+/// each year. Inputs already present are never downloaded again, and requests
+/// are spaced out by at least min_interval, including across separate runs.
+#[derive(Debug)]
+pub struct Fetcher {
+    base_url: String,
+    session: String,
+    data_root: PathBuf,
+    min_interval: Duration,
+}
+
+impl Fetcher {
+    /// Creates a Fetcher writing to the data root found by file::data_root,
+    /// using the session token in AOC_SESSION and the base URL in
+    /// AOC_BASE_URL, if set.
+    pub fn from_env() -> Result<Self, Box<dyn Error>> {
+        let session = env::var(SESSION_ENV_VAR)
+            .map_err(|e| format!("couldn't read {SESSION_ENV_VAR}: {e}"))?;
+        let base_url = env::var(BASE_URL_ENV_VAR)
+            .unwrap_or_else(|_| DEFAULT_BASE_URL.to_string());
+
+        Ok(Self::new(
+            base_url.as_str(),
+            session.as_str(),
+            &data_root()?,
+        ))
+    }
+
+    /// Creates a Fetcher with explicit settings, and a one minute rate limit.
+    pub fn new(base_url: &str, session: &str, data_root: &Path) -> Self {
+        Fetcher {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            data_root: data_root.to_path_buf(),
+            min_interval: Duration::from_secs(60),
+        }
+    }
+
+    /// Sets the minimum time between requests.
+    pub fn with_min_interval(mut self, min_interval: Duration) -> Self {
+        self.min_interval = min_interval;
+        self
+    }
+
+    /// Returns the path the input for the given year and day is stored at.
+    pub fn input_path(&self, year: u16, day: u8) -> PathBuf {
+        self.data_root
+            .join(year.to_string())
+            .join(format!("day{day:02}.txt"))
+    }
+
+    /// Ensures the input for the given day is present, downloading it if it
+    /// isn't. An empty file (e.g. as left by new-day) counts as missing.
+    /// Returns the input's path and whether it was downloaded.
+    pub fn fetch(
+        &self,
+        year: u16,
+        day: u8,
+    ) -> Result<(PathBuf, bool), Box<dyn Error>> {
+        if year < 2015 {
+            return Err(format!("no Advent of Code in {year}").into());
+        }
+        if !(1..=25).contains(&day) {
+            return Err(format!("day must be in 1..=25, got {day}").into());
+        }
+
+        let path = self.input_path(year, day);
+        match fs::metadata(&path) {
+            Ok(m) if m.len() > 0 => return Ok((path, false)),
+            Ok(_) => (),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => (),
+            Err(e) => return Err(e.into()),
+        }
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        self.wait_for_rate_limit()?;
+
+        let url = format!("{}/{year}/day/{day}/input", self.base_url);
+        let body = match ureq::get(url.as_str())
+            .set("Cookie", format!("session={}", self.session).as_str())
+            .set("User-Agent", USER_AGENT)
+            .call()
+        {
+            Ok(resp) => resp.into_string()?,
+            Err(ureq::Error::Status(code, _)) => {
+                return Err(format!("fetching {url} gave HTTP {code}").into())
+            },
+            Err(e) => return Err(e.into()),
+        };
+
+        if body.is_empty() {
+            return Err(format!("fetching {url} gave an empty input").into());
+        }
+
+        // Write to a temporary file first so an interrupted download can't
+        // leave a partial input to be mistaken for a cached one.
+        let tmp_path = path.with_extension("txt.part");
+        fs::write(&tmp_path, body)?;
+        fs::rename(&tmp_path, &path)?;
+
+        Ok((path, true))
+    }
+
+    /// Sleeps until min_interval has passed since the last request, then
+    /// records that a request is being made now.
+    fn wait_for_rate_limit(&self) -> Result<(), Box<dyn Error>> {
+        let stamp = self.data_root.join(STAMP_FILE);
+        match fs::metadata(&stamp) {
+            Ok(m) => {
+                // A last request time in the future (e.g. after the clock
+                // changes) is treated as having just happened.
+                let since = SystemTime::now()
+                    .duration_since(m.modified()?)
+                    .unwrap_or_default();
+                if let Some(wait) = self.min_interval.checked_sub(since) {
+                    thread::sleep(wait);
+                }
+            },
+            Err(e) if e.kind() == io::ErrorKind::NotFound => (),
+            Err(e) => return Err(e.into()),
+        }
+
+        File::create(&stamp)?.set_modified(SystemTime::now())?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc;
+    use std::time::Instant;
+
+    use super::*;
+
+    /// Starts an HTTP server on localhost answering every request with the
+    /// given status and body. Returns its base URL and a receiver of the
+    /// request lines and cookies it saw.
+    fn stub_server(
+        status: &'static str,
+        body: &'static str,
+    ) -> (String, mpsc::Receiver<(String, String)>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let (tx, rx) = mpsc::channel();
+
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                let mut cookie = String::new();
+                loop {
+                    let mut header = String::new();
+                    reader.read_line(&mut header).unwrap();
+                    if header.trim().is_empty() {
+                        break;
+                    }
+                    if let Some(v) = header.strip_prefix("Cookie: ") {
+                        cookie = v.trim().to_string();
+                    }
+                }
+
+                write!(
+                    stream,
+                    "HTTP/1.1 {status}\r\nContent-Length: {}\r\n\
+                    Connection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+                tx.send((request_line.trim().to_string(), cookie)).unwrap();
+            }
+        });
+
+        (url, rx)
+    }
+
+    fn temp_data_root(name: &str) -> PathBuf {
+        let dir = env::temp_dir()
+            .join(format!("aoc-fetch-test-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_fetch_and_cache() {
+        let (url, requests) = stub_server("200 OK", "1\n2\n3\n");
+        let data_root = temp_data_root("cache");
+        let fetcher = Fetcher::new(url.as_str(), "abc123", &data_root)
+            .with_min_interval(Duration::from_millis(200));
+
+        let (path, downloaded) = fetcher.fetch(2022, 1).unwrap();
+        assert!(downloaded);
+        assert_eq!(path, data_root.join("2022/day01.txt"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1\n2\n3\n");
+        assert_eq!(
+            requests.recv().unwrap(),
+            (
+                "GET /2022/day/1/input HTTP/1.1".to_string(),
+                "session=abc123".to_string()
+            )
+        );
+
+        // A second fetch must come from the cache.
+        assert_eq!(fetcher.fetch(2022, 1).unwrap(), (path, false));
+        assert!(requests.try_recv().is_err());
+
+        // An empty placeholder is replaced, but only after the rate limit.
+        let start = Instant::now();
+        fs::create_dir_all(data_root.join("2022")).unwrap();
+        fs::write(data_root.join("2022/day02.txt"), "").unwrap();
+        assert!(fetcher.fetch(2022, 2).unwrap().1);
+        assert!(start.elapsed() >= Duration::from_millis(150));
+        assert_eq!(
+            requests.recv().unwrap().0,
+            "GET /2022/day/2/input HTTP/1.1"
+        );
+
+        // Each year has its own inputs, but they share the rate limit.
+        let (path, downloaded) = fetcher.fetch(2021, 1).unwrap();
+        assert!(downloaded);
+        assert_eq!(path, data_root.join("2021/day01.txt"));
+        assert_eq!(
+            requests.recv().unwrap().0,
+            "GET /2021/day/1/input HTTP/1.1"
+        );
+
+        fs::remove_dir_all(&data_root).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_errors() {
+        let (url, _requests) = stub_server("404 Not Found", "no such puzzle");
+        let data_root = temp_data_root("errors");
+        let fetcher = Fetcher::new(url.as_str(), "abc123", &data_root)
+            .with_min_interval(Duration::ZERO);
+
+        let err = fetcher.fetch(2022, 3).unwrap_err();
+        assert!(err.to_string().contains("HTTP 404"), "{err}");
+        assert!(!data_root.join("2022/day03.txt").exists());
+
+        assert!(fetcher.fetch(2022, 0).is_err());
+        assert!(fetcher.fetch(2022, 26).is_err());
+        assert!(fetcher.fetch(2014, 1).is_err());
+
+        fs::remove_dir_all(&data_root).unwrap();
+    }
+}
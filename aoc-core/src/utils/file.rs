@@ -0,0 +1,204 @@
+use std::env;
+use std::fmt::Display;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader};
+use std::path::PathBuf;
+use std::str;
+
+use super::error::invalid_data_err;
+use super::iter::split_on;
+
+/// data_root returns the `data` directory holding every year's inputs: the one
+/// in the working directory or its nearest ancestor that has one. This lets
+/// binaries run from the workspace root and tests run from a crate's own
+/// directory find the same inputs.
+pub fn data_root() -> Result<PathBuf, io::Error> {
+    let cwd = env::current_dir()?;
+    cwd.ancestors()
+        .map(|dir| dir.join("data"))
+        .find(|data| data.is_dir())
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("no data directory in or above {}", cwd.display()),
+            )
+        })
+}
+
+/// latest_year returns the newest year with a directory under the data root,
+/// which tools use when no year is given.
+pub fn latest_year() -> Result<u16, io::Error> {
+    let mut latest = None;
+    for entry in fs::read_dir(data_root()?)? {
+        let entry = entry?;
+        if !entry.file_type()?.is_dir() {
+            continue;
+        }
+        if let Some(Ok(year)) = entry.file_name().to_str().map(str::parse) {
+            latest = latest.max(Some(year));
+        }
+    }
+
+    latest.ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::NotFound,
+            "no years in the data directory",
+        )
+    })
+}
+
+/// data_path returns the path of a file under `data/YEAR`, given without its
+/// extension, e.g. "day01" or "example/day01".
+pub fn data_path(year: u16, file: &str) -> Result<PathBuf, io::Error> {
+    Ok(data_root()?
+        .join(year.to_string())
+        .join(format!("{file}.txt")))
+}
+
+/// get_input_lines takes a file path under `data/YEAR`, minus extension, and
+/// returns an iterator to retrieve one line at a time.
+pub fn get_input_lines(
+    year: u16,
+    file: &str,
+) -> Result<impl Iterator<Item = Result<String, io::Error>>, io::Error> {
+    Ok(get_input_reader(year, file)?.lines())
+}
+
+/// get_input_reader takes a file path under `data/YEAR`, minus extension, and
+/// returns a buffered reader over it, for parsers that read into their own
+/// buffers rather than allocating a String per line.
+pub fn get_input_reader(
+    year: u16,
+    file: &str,
+) -> Result<BufReader<File>, io::Error> {
+    Ok(BufReader::new(File::open(data_path(year, file)?)?))
+}
+
+/// read_input reads the whole of a file under `data/YEAR`, as get_input_lines.
+pub fn read_input(year: u16, file: &str) -> Result<String, io::Error> {
+    fs::read_to_string(data_path(year, file)?)
+}
+
+/// get_str_lines splits a string into lines in the same way as
+/// get_input_lines, for passing generated or inline inputs to the same parsers.
+pub fn get_str_lines(
+    s: &str,
+) -> impl Iterator<Item = Result<String, io::Error>> + '_ {
+    s.lines().map(|line| Ok(line.to_string()))
+}
+
+/// An InputRecord is a group of consecutive lines of an input, e.g. one of the
+/// blank-line-separated records given by get_input_records, which remembers
+/// where in the input it came from so errors can say which line was bad.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InputRecord {
+    /// The 1-based line number of the first line.
+    first_line: usize,
+    lines: Vec<String>,
+}
+
+impl InputRecord {
+    pub fn lines(&self) -> &[String] {
+        &self.lines
+    }
+
+    pub fn into_lines(self) -> Vec<String> {
+        self.lines
+    }
+
+    /// Returns the 1-based line number in the input of the record's i'th line.
+    pub fn line_number(&self, i: usize) -> usize {
+        self.first_line + i
+    }
+
+    /// Returns the record's lines, each with its line number in the input.
+    pub fn numbered_lines(&self) -> impl Iterator<Item = (usize, &str)> {
+        let first_line = self.first_line;
+        self.lines
+            .iter()
+            .enumerate()
+            .map(move |(i, line)| (first_line + i, line.as_str()))
+    }
+
+    /// Returns an InvalidData error about the record's i'th line, which
+    /// includes its line number in the input.
+    pub fn line_err(&self, i: usize, e: impl Display) -> io::Error {
+        invalid_data_err(&format!("line {}: {e}", self.line_number(i)))
+    }
+
+    /// Returns an InvalidData error about the record as a whole, which
+    /// includes the line number it starts at.
+    pub fn err(&self, e: impl Display) -> io::Error {
+        invalid_data_err(&format!("record at line {}: {e}", self.first_line))
+    }
+}
+
+/// is_blank is the separator between records used by get_input_records: a line
+/// that's empty or all whitespace.
+pub fn is_blank(line: &str) -> bool {
+    line.trim().is_empty()
+}
+
+/// records splits lines into InputRecords separated by lines matching
+/// is_separator. Each separator ends a record, so consecutive separators give
+/// empty records, but a trailing one doesn't.
+pub fn records<E>(
+    lines: impl Iterator<Item = Result<String, E>>,
+    is_separator: impl Fn(&str) -> bool,
+) -> impl Iterator<Item = Result<InputRecord, E>> {
+    split_on(lines, is_separator).map(|group| {
+        group.map(|(i, lines)| InputRecord {
+            first_line: i + 1,
+            lines,
+        })
+    })
+}
+
+/// get_input_records takes a file path under `data/YEAR`, minus extension, and
+/// returns an iterator over its blank-line-separated records.
+pub fn get_input_records(
+    year: u16,
+    file: &str,
+) -> Result<impl Iterator<Item = Result<InputRecord, io::Error>>, io::Error> {
+    get_input_records_by(year, file, is_blank)
+}
+
+/// get_input_records_by is get_input_records with records separated by lines
+/// matching is_separator rather than blank lines.
+pub fn get_input_records_by(
+    year: u16,
+    file: &str,
+    is_separator: impl Fn(&str) -> bool,
+) -> Result<impl Iterator<Item = Result<InputRecord, io::Error>>, io::Error> {
+    Ok(records(get_input_lines(year, file)?, is_separator))
+}
+
+/// get_str_records splits a string into records in the same way as
+/// get_input_records.
+pub fn get_str_records(
+    s: &str,
+) -> impl Iterator<Item = Result<InputRecord, io::Error>> + '_ {
+    records(get_str_lines(s), is_blank)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_records() {
+        let blank: Vec<_> = get_str_records("a\nb\n\n \nc\n\n")
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(blank.len(), 3);
+        assert_eq!(blank[0].lines(), ["a", "b"]);
+        assert!(blank[1].lines().is_empty());
+        assert_eq!(blank[2].numbered_lines().collect::<Vec<_>>(), [(5, "c")]);
+        assert_eq!(blank[0].line_err(1, "bad").to_string(), "line 2: bad");
+        assert_eq!(blank[2].err("bad").to_string(), "record at line 5: bad");
+
+        let dashes = records(get_str_lines("a\n-\nb"), |l| l == "-");
+        let dashes: Vec<_> = dashes.map(|r| r.unwrap().into_lines()).collect();
+        assert_eq!(dashes, [["a"], ["b"]]);
+    }
+}
@@ -0,0 +1,258 @@
+//! Fuzzing for input parsers.
+//!
+//! The policy is that a parser given malformed input returns an error: it must
+//! not panic, overflow, or recurse without bound. Each parser of puzzle input
+//! has a `prop_parser_never_panics` proptest property checking this with
+//! parser_panics, on any text and on text matching a regex shaped like the
+//! parser's input. proptest shrinks any failure to a minimal input.
+//!
+//! Some parsers, e.g. days 5 and 13's, also have a `test_fuzz` test using
+//! assert_parser_never_panics, which tries structure-aware mutations of a
+//! corpus of valid inputs (usually the example files), reaching inputs a regex
+//! can't easily describe.
+//!
+//! Anything a parser accepts is fair game for its solver to reject, e.g. a move
+//! from a stack that doesn't exist: the policy only covers parsing.
+//!
+//! Neither is coverage-guided, so they find shallow bugs well but deep ones
+//! slowly. A cargo-fuzz target would only need to call parser_panics on the
+//! input it's given, and fuzz_input gives the same inputs as the tests here,
+//! e.g. to seed a corpus.
+
+use std::env;
+use std::panic::AssertUnwindSafe;
+
+use crate::utils::rng::Rng;
+use crate::utils::test::catch_unwind_silent;
+
+/// The environment variable setting how many inputs fuzz tests try per parser.
+/// Raise it for a slower but more thorough run.
+pub const FUZZ_CASES_ENV_VAR: &str = "AOC_FUZZ_CASES";
+
+const DEFAULT_FUZZ_CASES: u64 = 500;
+
+/// A regex matching any text, newlines included, for use as a proptest
+/// strategy alongside one shaped like a parser's input.
+pub const ANY_TEXT: &str = r"[\PC\n]*";
+
+/// Strings spliced into inputs: boundary numbers, brackets, separators and
+/// chars that are easy to mishandle.
+const INTERESTING: [&str; 20] = [
+    "0",
+    "-",
+    "-0",
+    "255",
+    "256",
+    "-129",
+    "65536",
+    "4294967296",
+    "18446744073709551616",
+    "-9223372036854775809",
+    "340282366920938463463374607431768211456",
+    "[",
+    "]",
+    ",",
+    " ",
+    "\n",
+    "\n\n",
+    "\t",
+    "\u{e9}",
+    "\u{1f980}",
+];
+
+/// Returns up to max_len random bytes, decoded as UTF-8 with invalid sequences
+/// replaced, as parsers only see valid strings.
+pub fn arbitrary(rng: &mut Rng, max_len: usize) -> String {
+    let bytes: Vec<u8> = (0..rng.range_usize(0..=max_len))
+        .map(|_| match rng.chance(3, 4) {
+            // Mostly printable ASCII, which is what parsers look for.
+            true => rng.range(0x20..=0x7e) as u8,
+            false => rng.below(256) as u8,
+        })
+        .collect();
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+/// Returns input after a few random mutations that keep most of its structure:
+/// deleting, duplicating, repeating or swapping spans and lines, splicing in
+/// interesting strings, and replacing numbers.
+pub fn mutate(rng: &mut Rng, input: &str) -> String {
+    let mut chars: Vec<char> = input.chars().collect();
+
+    for _ in 0..rng.range_usize(1..=4) {
+        let len = chars.len();
+        let (a, b) = span(rng, len);
+        match rng.below(7) {
+            // Delete a span.
+            0 => {
+                chars.drain(a..b);
+            },
+            // Copy a span elsewhere.
+            1 => {
+                let copy: Vec<char> = chars[a..b].to_vec();
+                let at = rng.range_usize(0..=len);
+                chars.splice(at..at, copy);
+            },
+            // Repeat a span many times, to find unbounded recursion.
+            2 => {
+                let repeats =
+                    rng.range_usize(2..=2000).min(20_000 / (b - a + 1));
+                let copy: Vec<char> = chars[a..b].repeat(repeats);
+                chars.splice(a..b, copy);
+            },
+            // Splice in an interesting string.
+            3 => {
+                let at = rng.range_usize(0..=len);
+                chars.splice(at..at, rng.choose(&INTERESTING).chars());
+            },
+            // Replace a number with an interesting string.
+            4 => {
+                let digits: Vec<usize> =
+                    (0..len).filter(|i| chars[*i].is_ascii_digit()).collect();
+                if let Some(&start) =
+                    digits.get(rng.below(digits.len().max(1) as u64) as usize)
+                {
+                    let end = (start..len)
+                        .find(|i| !chars[*i].is_ascii_digit())
+                        .unwrap_or(len);
+                    chars.splice(start..end, rng.choose(&INTERESTING).chars());
+                }
+            },
+            // Shuffle the lines.
+            5 => {
+                let s: String = chars.iter().collect();
+                let mut lines: Vec<&str> = s.split('\n').collect();
+                rng.shuffle(&mut lines);
+                chars = lines.join("\n").chars().collect();
+            },
+            // Truncate.
+            _ => chars.truncate(a),
+        }
+    }
+
+    chars.into_iter().collect()
+}
+
+/// Returns a random span a..b of 0..len, biased towards short spans.
+fn span(rng: &mut Rng, len: usize) -> (usize, usize) {
+    let a = rng.range_usize(0..=len);
+    let max_width = match rng.chance(3, 4) {
+        true => 8,
+        false => len,
+    };
+    let b = rng.range_usize(a..=(a + max_width).min(len));
+    (a, b)
+}
+
+/// Returns whether parse panics on input, rather than returning a value or an
+/// error.
+pub fn parser_panics<T, E>(
+    parse: impl Fn(&str) -> Result<T, E>,
+    input: &str,
+) -> bool {
+    catch_unwind_silent(AssertUnwindSafe(|| {
+        let _ = parse(input);
+    }))
+    .is_err()
+}
+
+/// Returns the input for fuzz case number case: arbitrary bytes, or a mutation
+/// of one of the items in corpus, taking turns.
+pub fn fuzz_input(case: u64, corpus: &[&str]) -> String {
+    let mut rng = Rng::new(case);
+    match (case as usize) % (corpus.len() + 1) {
+        0 => arbitrary(&mut rng, 256),
+        i => mutate(&mut rng, corpus[i - 1]),
+    }
+}
+
+/// Runs parse on FUZZ_CASES_ENV_VAR inputs from fuzz_input. If it ever panics,
+/// panics in turn with the smallest input found that still makes it panic.
+pub fn assert_parser_never_panics<T, E>(
+    name: &str,
+    corpus: &[&str],
+    parse: impl Fn(&str) -> Result<T, E>,
+) {
+    let cases = match env::var(FUZZ_CASES_ENV_VAR) {
+        Ok(s) => s.parse().expect("AOC_FUZZ_CASES should be a number"),
+        Err(_) => DEFAULT_FUZZ_CASES,
+    };
+    let panics = |input: &str| parser_panics(&parse, input);
+
+    for case in 0..cases {
+        let input = fuzz_input(case, corpus);
+        if panics(&input) {
+            let input = shrink(input, panics);
+            panic!("{name} panicked on fuzz case {case}, input: {input:?}");
+        }
+    }
+}
+
+/// Shrinks input for as long as it still fails, by deleting ever smaller
+/// chunks of it.
+fn shrink(input: String, fails: impl Fn(&str) -> bool) -> String {
+    let mut chars: Vec<char> = input.chars().collect();
+    let mut chunk = chars.len() / 2;
+    while chunk > 0 {
+        let mut start = 0;
+        while start < chars.len() {
+            let end = (start + chunk).min(chars.len());
+            let smaller: String =
+                chars[..start].iter().chain(&chars[end..]).collect();
+            if fails(&smaller) {
+                chars.drain(start..end);
+            } else {
+                start += chunk;
+            }
+        }
+        chunk /= 2;
+    }
+    chars.into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mutate_deterministic() {
+        let input = "[1,2,[3]]\n[4]\n";
+        for seed in 0..100 {
+            assert_eq!(
+                mutate(&mut Rng::new(seed), input),
+                mutate(&mut Rng::new(seed), input)
+            );
+        }
+        assert!(
+            (0..100).any(|seed| mutate(&mut Rng::new(seed), input) != input)
+        );
+    }
+
+    #[test]
+    fn test_parser_panics() {
+        let parse = |s: &str| s.parse::<u8>();
+        assert!(!parser_panics(parse, "300"));
+        assert!(parser_panics(|s: &str| Ok::<u8, ()>(s.as_bytes()[0]), ""));
+        assert_eq!(fuzz_input(3, &["12"]), fuzz_input(3, &["12"]));
+        assert_eq!(
+            fuzz_input(0, &[]).len(),
+            arbitrary(&mut Rng::new(0), 256).len()
+        );
+    }
+
+    #[test]
+    fn test_assert_parser_never_panics() {
+        let parse = |s: &str| s.trim().parse::<u8>();
+        assert_parser_never_panics("u8", &["12"], parse);
+
+        // Finds the panic, and shrinks the input to a single char.
+        let result = catch_unwind_silent(|| {
+            assert_parser_never_panics("bad", &["12"], |s: &str| {
+                assert!(!s.contains('-'));
+                Ok::<_, ()>(())
+            })
+        });
+        let msg = *result.unwrap_err().downcast::<String>().unwrap();
+        assert!(msg.ends_with("input: \"-\""), "{msg}");
+    }
+}
@@ -0,0 +1,167 @@
+//! Integer arithmetic that solvers can be generic over, so they can run with
+//! fixed-width integers or, when answers or inputs don't fit in those, num's
+//! arbitrary-precision BigInt and BigUint.
+//!
+//! Fixed-width arithmetic through Int is checked: overflow is returned as an
+//! ArithError rather than panicking, and its message suggests setting
+//! AOC_BIGINT, which days supporting it read with bigint_mode to pick which
+//! type to solve with.
+
+use std::error::Error;
+use std::fmt::{self, Debug, Display};
+use std::hash::Hash;
+use std::str::FromStr;
+
+use num::{
+    CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, FromPrimitive, Integer,
+    ToPrimitive,
+};
+
+/// The environment variable that, when set to anything but "" or "0", makes
+/// days solve with arbitrary-precision integers.
+pub const BIGINT_ENV_VAR: &str = "AOC_BIGINT";
+
+/// Returns whether AOC_BIGINT asks for arbitrary-precision integers.
+pub fn bigint_mode() -> bool {
+    match std::env::var(BIGINT_ENV_VAR) {
+        Ok(s) => !s.is_empty() && s != "0",
+        Err(_) => false,
+    }
+}
+
+/// An ArithError is an arithmetic operation with no result in the type used.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ArithError {
+    Overflow,
+    DivideByZero,
+}
+
+impl Display for ArithError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Overflow => write!(
+                f,
+                "arithmetic overflow (set {BIGINT_ENV_VAR}=1 to use \
+                arbitrary-precision integers)"
+            ),
+            Self::DivideByZero => f.write_str("division by zero"),
+        }
+    }
+}
+
+impl Error for ArithError {}
+
+/// Int is implemented by the primitive integer types from u8 and i16 upwards,
+/// and by num's BigInt and BigUint.
+pub trait Int:
+    Clone
+    + Debug
+    + Display
+    + Hash
+    + Integer
+    + CheckedAdd
+    + CheckedSub
+    + CheckedMul
+    + CheckedDiv
+    + FromPrimitive
+    + ToPrimitive
+    + From<u8>
+{
+    /// Parses a number, with any error as a message.
+    fn parse_int(s: &str) -> Result<Self, String>;
+
+    /// Converts an i64, failing if it doesn't fit.
+    fn try_from_i64(n: i64) -> Result<Self, ArithError> {
+        Self::from_i64(n).ok_or(ArithError::Overflow)
+    }
+
+    /// Converts a usize, failing if it doesn't fit.
+    fn try_from_usize(n: usize) -> Result<Self, ArithError> {
+        Self::from_usize(n).ok_or(ArithError::Overflow)
+    }
+
+    fn try_add(&self, rhs: &Self) -> Result<Self, ArithError> {
+        self.checked_add(rhs).ok_or(ArithError::Overflow)
+    }
+
+    fn try_sub(&self, rhs: &Self) -> Result<Self, ArithError> {
+        self.checked_sub(rhs).ok_or(ArithError::Overflow)
+    }
+
+    fn try_mul(&self, rhs: &Self) -> Result<Self, ArithError> {
+        self.checked_mul(rhs).ok_or(ArithError::Overflow)
+    }
+
+    /// Divides, truncating towards zero.
+    fn try_div(&self, rhs: &Self) -> Result<Self, ArithError> {
+        if rhs.is_zero() {
+            return Err(ArithError::DivideByZero);
+        }
+        self.checked_div(rhs).ok_or(ArithError::Overflow)
+    }
+}
+
+impl<T> Int for T
+where
+    T: Clone
+        + Debug
+        + Display
+        + Hash
+        + Integer
+        + CheckedAdd
+        + CheckedSub
+        + CheckedMul
+        + CheckedDiv
+        + FromPrimitive
+        + ToPrimitive
+        + From<u8>
+        + FromStr,
+    <T as FromStr>::Err: Display,
+{
+    fn parse_int(s: &str) -> Result<Self, String> {
+        s.parse().map_err(|e: <T as FromStr>::Err| e.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num::{BigInt, BigUint};
+
+    use super::*;
+
+    #[test]
+    fn test_fixed_width() {
+        assert_eq!(2i64.try_add(&3), Ok(5));
+        assert_eq!(i64::MAX.try_add(&1), Err(ArithError::Overflow));
+        assert_eq!(0u64.try_sub(&1), Err(ArithError::Overflow));
+        assert_eq!(u128::MAX.try_mul(&2), Err(ArithError::Overflow));
+        assert_eq!(7i64.try_div(&-2), Ok(-3));
+        assert_eq!(7i64.try_div(&0), Err(ArithError::DivideByZero));
+        assert_eq!(i64::MIN.try_div(&-1), Err(ArithError::Overflow));
+
+        assert_eq!(u8::try_from_i64(-1), Err(ArithError::Overflow));
+        assert_eq!(i16::parse_int("-12"), Ok(-12));
+        assert!(i16::parse_int("40000").is_err());
+    }
+
+    #[test]
+    fn test_bigint() {
+        let big = BigInt::try_from_i64(i64::MAX).unwrap();
+        let square = big.try_mul(&big).unwrap();
+        assert_eq!(square.try_div(&big), Ok(big.clone()));
+        assert_eq!(
+            square,
+            BigInt::parse_int("85070591730234615847396907784232501249")
+                .unwrap()
+        );
+        assert_eq!(
+            big.try_div(&BigInt::from(0u8)),
+            Err(ArithError::DivideByZero)
+        );
+
+        assert_eq!(
+            BigUint::from(1u8).try_sub(&BigUint::from(2u8)),
+            Err(ArithError::Overflow)
+        );
+    }
+}
@@ -0,0 +1,417 @@
+use std::cmp::{max, Reverse};
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::iter;
+
+use itertools::Itertools;
+
+/// Returns a boolean indicating if the elements of this iter are in strictly
+/// increasing order.
+pub fn is_strictly_increasing<T: Clone + PartialOrd>(
+    iter: impl Iterator<Item = T>,
+) -> bool {
+    iter.tuple_windows().all(|(a, b)| a < b)
+}
+
+/// Maps an iterator to an iterator of booleans, where each item is true iff
+/// the iterator has no larger values before that point, using the value derived
+/// by applying extract_fn. Returns an iterator combining a bool to indicate
+/// largest-so-far order, and the original value.
+pub fn map_is_largest_so_far_f<'a, T, U: Clone + Ord + 'a>(
+    iter: impl Iterator<Item = T> + 'a,
+    extract_fn: impl Fn(&T) -> U + 'a,
+) -> impl Iterator<Item = (bool, T)> + 'a {
+    iter.scan((true, None::<U>), move |(prev_ok, largest_so_far), val| {
+        let cmp_val = extract_fn(&val);
+        Some({
+            let (ok, cur_largest) = match &*largest_so_far {
+                None => (true, cmp_val),
+                Some(prev_largest) => (
+                    *prev_largest < cmp_val,
+                    max(prev_largest.to_owned(), cmp_val),
+                ),
+            };
+            *prev_ok = ok;
+            *largest_so_far = Some(cur_largest);
+            (ok, val)
+        })
+    })
+}
+
+/// Returns the k largest items of iter, largest first. Takes O(n log k) time
+/// and O(k) space, so suits long iterators with small k.
+pub fn top_k<T: Ord>(iter: impl Iterator<Item = T>, k: usize) -> Vec<T> {
+    bottom_k(iter.map(Reverse), k)
+        .into_iter()
+        .map(|Reverse(v)| v)
+        .collect()
+}
+
+/// Returns the k smallest items of iter, smallest first. Takes O(n log k) time
+/// and O(k) space.
+pub fn bottom_k<T: Ord>(iter: impl Iterator<Item = T>, k: usize) -> Vec<T> {
+    if k == 0 {
+        return Vec::new();
+    }
+
+    // A max-heap of the k smallest so far, so the largest of them is the one
+    // to drop when a smaller item comes along.
+    let mut heap = BinaryHeap::with_capacity(k + 1);
+    for v in iter {
+        heap.push(v);
+        if heap.len() > k {
+            heap.pop();
+        }
+    }
+    heap.into_sorted_vec()
+}
+
+/// Maps an iterator to an iterator of booleans, where each item is true iff
+/// the window of the last n items ending at it is full and has no duplicates.
+/// Keeps a count of each value in the window and of how many are repeated, so
+/// each step takes O(1) time however large n is.
+pub fn distinct_windows<T: Clone + Eq + Hash>(
+    iter: impl Iterator<Item = T>,
+    n: usize,
+) -> impl Iterator<Item = bool> {
+    let mut window = VecDeque::with_capacity(n + 1);
+    let mut counts: HashMap<T, usize> = HashMap::new();
+    let mut repeated = 0;
+
+    iter.map(move |v| {
+        let count = counts.entry(v.clone()).or_default();
+        *count += 1;
+        if *count == 2 {
+            repeated += 1;
+        }
+        window.push_back(v);
+
+        if window.len() > n {
+            let old = window.pop_front().unwrap();
+            let count = counts.get_mut(&old).unwrap();
+            *count -= 1;
+            match *count {
+                0 => {
+                    counts.remove(&old);
+                },
+                1 => repeated -= 1,
+                _ => (),
+            }
+        }
+
+        window.len() == n && repeated == 0
+    })
+}
+
+/// Splits lines into groups separated by blank lines, which aren't included.
+/// Each blank line ends a group, so consecutive blank lines give empty groups,
+/// but a trailing blank line doesn't. An error is passed through in place of
+/// the group it was found in.
+pub fn split_on_blank<E>(
+    lines: impl Iterator<Item = Result<String, E>>,
+) -> impl Iterator<Item = Result<Vec<String>, E>> {
+    split_on(lines, |line| line.trim().is_empty())
+        .map(|group| group.map(|(_, lines)| lines))
+}
+
+/// Splits lines into groups separated by lines matching is_separator, as
+/// split_on_blank, giving each group with the 0-based index of its first line
+/// (or, for an empty group, of the separator ending it).
+pub fn split_on<E>(
+    mut lines: impl Iterator<Item = Result<String, E>>,
+    is_separator: impl Fn(&str) -> bool,
+) -> impl Iterator<Item = Result<(usize, Vec<String>), E>> {
+    let mut next_index = 0;
+    let mut done = false;
+    iter::from_fn(move || {
+        if done {
+            return None;
+        }
+        let start = next_index;
+        let mut group = Vec::new();
+        loop {
+            let line = lines.next();
+            next_index += 1;
+            match line {
+                Some(Ok(line)) if is_separator(&line) => {
+                    return Some(Ok((start, group)))
+                },
+                Some(Ok(line)) => group.push(line),
+                Some(Err(e)) => return Some(Err(e)),
+                None => {
+                    done = true;
+                    return (!group.is_empty()).then_some(Ok((start, group)));
+                },
+            }
+        }
+    })
+}
+
+/// Returns the multiset intersection of groups, i.e. each value found in every
+/// group, with the fewest times it's found in any one of them. No groups gives
+/// an empty intersection.
+pub fn multiset_intersection<T: Eq + Hash>(
+    groups: impl IntoIterator<Item = impl IntoIterator<Item = T>>,
+) -> HashMap<T, usize> {
+    let counts = |group: &mut dyn Iterator<Item = T>| {
+        let mut counts = HashMap::new();
+        for v in group {
+            *counts.entry(v).or_default() += 1;
+        }
+        counts
+    };
+
+    let mut groups = groups.into_iter();
+    let mut common: HashMap<T, usize> = match groups.next() {
+        Some(group) => counts(&mut group.into_iter()),
+        None => return HashMap::new(),
+    };
+    for group in groups {
+        if common.is_empty() {
+            break;
+        }
+        let group = counts(&mut group.into_iter());
+        common.retain(|v, count| match group.get(v) {
+            Some(n) => {
+                *count = (*count).min(*n);
+                true
+            },
+            None => false,
+        });
+    }
+    common
+}
+
+/// Maps an iterator to the largest item seen so far at each point, comparing
+/// by the key given by key_fn. Ties keep the earlier item.
+pub fn running_max_by_key<T: Clone, K: Ord>(
+    iter: impl Iterator<Item = T>,
+    key_fn: impl Fn(&T) -> K,
+) -> impl Iterator<Item = T> {
+    iter.scan(None::<(K, T)>, move |best, v| {
+        let k = key_fn(&v);
+        match best {
+            Some((best_k, _)) if *best_k >= k => (),
+            _ => *best = Some((k, v)),
+        }
+        best.as_ref().map(|(_, v)| v.clone())
+    })
+}
+
+/// Maps an iterator to the smallest item seen so far at each point, comparing
+/// by the key given by key_fn. Ties keep the earlier item.
+pub fn running_min_by_key<T: Clone, K: Ord>(
+    iter: impl Iterator<Item = T>,
+    key_fn: impl Fn(&T) -> K,
+) -> impl Iterator<Item = T> {
+    running_max_by_key(iter, move |v| Reverse(key_fn(v)))
+}
+
+/// Maps an iterator to the largest item seen so far at each point.
+pub fn running_max<T: Clone + Ord>(
+    iter: impl Iterator<Item = T>,
+) -> impl Iterator<Item = T> {
+    running_max_by_key(iter, T::clone)
+}
+
+/// Returns, for each item of values, the index of the first later item that
+/// is greater than it, if any. Uses a monotonic stack, so takes O(n) time.
+pub fn next_greater<T: PartialOrd>(values: &[T]) -> Vec<Option<usize>> {
+    next_by(values, |v, later| later > v)
+}
+
+/// Returns, for each item of values, the index of the first later item that
+/// is at least as large as it, if any, in O(n) time. E.g. for tree heights,
+/// the tree blocking each one's view.
+pub fn next_greater_or_equal<T: PartialOrd>(
+    values: &[T],
+) -> Vec<Option<usize>> {
+    next_by(values, |v, later| later >= v)
+}
+
+/// Returns, for each item of values, the index of the first later item for
+/// which found(item, later) holds. found must be a strict or non-strict
+/// ordering, so that an item found for one item is found for every item still
+/// waiting on the stack below it.
+fn next_by<T>(
+    values: &[T],
+    found: impl Fn(&T, &T) -> bool,
+) -> Vec<Option<usize>> {
+    let mut next = vec![None; values.len()];
+    // The indices of items with nothing found yet, whose values decrease
+    // going up the stack.
+    let mut stack: Vec<usize> = Vec::new();
+    for (i, v) in values.iter().enumerate() {
+        while let Some(&top) = stack.last() {
+            if !found(&values[top], v) {
+                break;
+            }
+            next[top] = Some(i);
+            stack.pop();
+        }
+        stack.push(i);
+    }
+    next
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_strictly_increasing() {
+        assert!(is_strictly_increasing(vec![1, 2, 3].into_iter()));
+        assert!(!is_strictly_increasing(vec![1, 1, 1].into_iter()));
+        assert!(!is_strictly_increasing(vec![3, 2, 1].into_iter()));
+    }
+
+    fn map_is_largest_so_far<'a, T: Clone + Ord + 'a>(
+        iter: impl Iterator<Item = T> + 'a,
+    ) -> impl Iterator<Item = (bool, T)> + 'a {
+        map_is_largest_so_far_f(iter, |x| x.to_owned())
+    }
+
+    #[test]
+    fn test_map_is_largest_so_far() {
+        assert_eq!(
+            map_is_largest_so_far(vec![0u64; 0].into_iter()).collect_vec(),
+            vec![]
+        );
+        assert_eq!(
+            map_is_largest_so_far(vec![1].into_iter()).collect_vec(),
+            vec![(true, 1)]
+        );
+        assert_eq!(
+            map_is_largest_so_far(vec![1, 2].into_iter()).collect_vec(),
+            vec![(true, 1), (true, 2)]
+        );
+        assert_eq!(
+            map_is_largest_so_far(vec![1, 1].into_iter()).collect_vec(),
+            vec![(true, 1), (false, 1)]
+        );
+        assert_eq!(
+            map_is_largest_so_far(vec![1, 0].into_iter()).collect_vec(),
+            vec![(true, 1), (false, 0)]
+        );
+
+        assert_eq!(
+            map_is_largest_so_far_f(vec![1, 2, 1, 3, 2, 4].into_iter(), |x| *x)
+                .collect_vec(),
+            vec![
+                (true, 1),
+                (true, 2),
+                (false, 1),
+                (true, 3),
+                (false, 2),
+                (true, 4)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_top_k() {
+        let v = [5, 1, 4, 1, 5, 9, 2, 6];
+        assert_eq!(top_k(v.into_iter(), 3), vec![9, 6, 5]);
+        assert_eq!(bottom_k(v.into_iter(), 3), vec![1, 1, 2]);
+        assert_eq!(top_k(v.into_iter(), 0), vec![]);
+        assert_eq!(top_k(v.into_iter(), 20).len(), v.len());
+        assert_eq!(bottom_k(iter::empty::<u8>(), 2), vec![]);
+    }
+
+    #[test]
+    fn test_distinct_windows() {
+        let windows = |s: &str, n| distinct_windows(s.chars(), n).collect_vec();
+        assert_eq!(windows("abca", 3), vec![false, false, true, true]);
+        assert_eq!(windows("aabb", 2), vec![false, false, true, false]);
+        assert_eq!(windows("ab", 3), vec![false, false]);
+        assert_eq!(windows("abab", 1), vec![true; 4]);
+
+        // Check against the naive approach.
+        let s = "mjqjpqmgbljsphdztnvjfqwrcgsmlbmmmzzzabcdefghijklmn";
+        for n in 1..16 {
+            let want = (0..s.len())
+                .map(|i| i + 1 >= n && s[i + 1 - n..=i].chars().all_unique())
+                .collect_vec();
+            assert_eq!(windows(s, n), want, "n = {n}");
+        }
+    }
+
+    #[test]
+    fn test_split_on_blank() {
+        let split = |s: &str| {
+            split_on_blank(s.lines().map(|l| Ok::<_, ()>(l.to_string())))
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap()
+        };
+        assert_eq!(split("a\nb\n\nc"), vec![vec!["a", "b"], vec!["c"]]);
+        assert_eq!(split("a\n\n\nb\n"), vec![vec!["a"], vec![], vec!["b"]]);
+        assert_eq!(split("a\n\n"), vec![vec!["a"]]);
+        assert_eq!(split(""), Vec::<Vec<String>>::new());
+
+        let lines = [Ok("a".to_string()), Err("bad"), Ok("b".to_string())];
+        assert_eq!(
+            split_on_blank(lines.into_iter()).collect_vec(),
+            vec![Err("bad"), Ok(vec!["b".to_string()])]
+        );
+    }
+
+    #[test]
+    fn test_split_on() {
+        let lines = ["a", "--", "b", "c", "--", "--", "d"];
+        let groups = split_on(
+            lines.map(|l| Ok::<_, ()>(l.to_string())).into_iter(),
+            |l| l == "--",
+        )
+        .map(Result::unwrap)
+        .collect_vec();
+        assert_eq!(
+            groups,
+            vec![
+                (0, vec!["a".to_string()]),
+                (2, vec!["b".to_string(), "c".to_string()]),
+                (5, vec![]),
+                (6, vec!["d".to_string()]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_multiset_intersection() {
+        let common =
+            multiset_intersection(["aabbc", "abbbd", "bbax"].map(str::chars));
+        assert_eq!(common, HashMap::from([('a', 1), ('b', 2)]));
+        assert!(multiset_intersection(["ab", "cd"].map(str::chars)).is_empty());
+        assert!(multiset_intersection(Vec::<Vec<u8>>::new()).is_empty());
+    }
+
+    #[test]
+    fn test_running_max_by_key() {
+        let v = [(1, 'a'), (3, 'b'), (2, 'c'), (3, 'd'), (4, 'e')];
+        assert_eq!(
+            running_max_by_key(v.into_iter(), |p| p.0).collect_vec(),
+            vec![(1, 'a'), (3, 'b'), (3, 'b'), (3, 'b'), (4, 'e')]
+        );
+        assert_eq!(
+            running_min_by_key(v.into_iter(), |p| p.0).collect_vec(),
+            vec![(1, 'a'); 5]
+        );
+        assert_eq!(
+            running_max([2, 1, 5, 3].into_iter()).collect_vec(),
+            vec![2, 2, 5, 5]
+        );
+    }
+
+    #[test]
+    fn test_next_greater() {
+        let v = [3, 0, 3, 5, 3];
+        assert_eq!(
+            next_greater(&v),
+            vec![Some(3), Some(2), Some(3), None, None]
+        );
+        assert_eq!(
+            next_greater_or_equal(&v),
+            vec![Some(2), Some(2), Some(3), None, None]
+        );
+        assert_eq!(next_greater::<u8>(&[]), vec![]);
+    }
+}
@@ -0,0 +1,362 @@
+//! Memoisation for solvers that compute the same thing over and over, such as
+//! recursive searches that reach the same state by different routes.
+//!
+//! A Memo caches a function's results keyed by its (hashable) arguments. It
+//! wraps the function at the call site rather than the function itself, so a
+//! recursive function takes the Memo as an argument and passes it on:
+//!
+//! ```
+//! use aoc_core::utils::memo::Memo;
+//!
+//! fn fib(n: u64, memo: &mut Memo<u64, u64>) -> u64 {
+//!     memo.get_or_insert_with(n, |memo| match n {
+//!         0 | 1 => n,
+//!         _ => fib(n - 1, memo) + fib(n - 2, memo),
+//!     })
+//! }
+//!
+//! let mut memo = Memo::new();
+//! assert_eq!(fib(90, &mut memo), 2880067194370816120);
+//! assert_eq!(memo.stats().misses, 91);
+//! ```
+//!
+//! A Memo can be limited in size, either by caching nothing more once full or
+//! by evicting the least recently used result, and counts its hits and misses
+//! so the benefit can be checked. When a result is cheap to compute, hashing
+//! its key with the default SipHash can cost more than computing it again, so
+//! a Memo can use the faster FxHasher instead.
+
+use std::collections::hash_map::RandomState;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::hash::{BuildHasher, BuildHasherDefault, Hash, Hasher};
+
+/// A Limit is how many results a Memo may hold, and what it does when full.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Limit {
+    Unbounded,
+    /// Holds up to this many results, caching no more once full.
+    Max(usize),
+    /// Holds up to this many results, evicting the least recently used to
+    /// make room for a new one.
+    Lru(usize),
+}
+
+/// Counts of how a Memo has been used.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Stats {
+    /// Lookups that found a cached result.
+    pub hits: u64,
+    /// Lookups that didn't.
+    pub misses: u64,
+    /// Results evicted to make room for others.
+    pub evictions: u64,
+    /// Results not cached because the Memo was full.
+    pub dropped: u64,
+    /// Results currently held.
+    pub len: usize,
+}
+
+impl Stats {
+    /// Returns the fraction of lookups that were hits, or 0 if there were none.
+    pub fn hit_rate(&self) -> f64 {
+        match self.hits + self.misses {
+            0 => 0.0,
+            n => self.hits as f64 / n as f64,
+        }
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} hits, {} misses ({:.1}% hit rate), {} evictions, {} dropped, \
+            {} held",
+            self.hits,
+            self.misses,
+            100.0 * self.hit_rate(),
+            self.evictions,
+            self.dropped,
+            self.len,
+        )
+    }
+}
+
+/// A Memo caches values of type V keyed by K, hashing keys with S.
+#[derive(Clone, Debug)]
+pub struct Memo<K, V, S = RandomState> {
+    /// Each result, with when it was last used.
+    entries: HashMap<K, (V, u64), S>,
+    /// The keys of the results by when they were last used, kept only when
+    /// evicting the least recently used.
+    by_use: BTreeMap<u64, K>,
+    clock: u64,
+    limit: Limit,
+    stats: Stats,
+}
+
+impl<K, V, S> Memo<K, V, S>
+where
+    K: Clone + Eq + Hash,
+    V: Clone,
+    S: BuildHasher + Default,
+{
+    /// Creates a Memo that holds every result.
+    pub fn new() -> Self {
+        Self::with_limit(Limit::Unbounded)
+    }
+
+    /// Creates a Memo that holds results up to the given limit.
+    pub fn with_limit(limit: Limit) -> Self {
+        Memo {
+            entries: HashMap::default(),
+            by_use: BTreeMap::new(),
+            clock: 0,
+            limit,
+            stats: Stats::default(),
+        }
+    }
+
+    /// Returns the cached result for key, if there is one.
+    pub fn get(&mut self, key: &K) -> Option<V> {
+        self.clock += 1;
+        let (value, last_used) = match self.entries.get_mut(key) {
+            Some(entry) => entry,
+            None => {
+                self.stats.misses += 1;
+                return None;
+            },
+        };
+        self.stats.hits += 1;
+
+        if let Limit::Lru(_) = self.limit {
+            let key = self.by_use.remove(last_used).unwrap();
+            self.by_use.insert(self.clock, key);
+        }
+        *last_used = self.clock;
+        Some(value.clone())
+    }
+
+    /// Caches a result for key, if the limit allows.
+    pub fn insert(&mut self, key: K, value: V) {
+        self.clock += 1;
+        if let Some((old, last_used)) = self.entries.get_mut(&key) {
+            *old = value;
+            if let Limit::Lru(_) = self.limit {
+                self.by_use.remove(last_used);
+                self.by_use.insert(self.clock, key);
+            }
+            *last_used = self.clock;
+            return;
+        }
+
+        match self.limit {
+            Limit::Unbounded => (),
+            Limit::Max(max) => {
+                if self.entries.len() >= max {
+                    self.stats.dropped += 1;
+                    return;
+                }
+            },
+            Limit::Lru(max) => {
+                if max == 0 {
+                    self.stats.dropped += 1;
+                    return;
+                }
+                while self.entries.len() >= max {
+                    let (_, oldest) = self.by_use.pop_first().unwrap();
+                    self.entries.remove(&oldest);
+                    self.stats.evictions += 1;
+                }
+                self.by_use.insert(self.clock, key.clone());
+            },
+        }
+        self.entries.insert(key, (value, self.clock));
+    }
+
+    /// Returns the cached result for key, or computes it with f and caches it.
+    /// f is given the Memo, so it can make recursive calls through it.
+    pub fn get_or_insert_with(
+        &mut self,
+        key: K,
+        f: impl FnOnce(&mut Self) -> V,
+    ) -> V {
+        if let Some(value) = self.get(&key) {
+            return value;
+        }
+        let value = f(self);
+        self.insert(key, value.clone());
+        value
+    }
+
+    /// Returns how the Memo has been used so far.
+    pub fn stats(&self) -> Stats {
+        Stats {
+            len: self.entries.len(),
+            ..self.stats
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Drops every cached result, keeping the stats.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.by_use.clear();
+    }
+}
+
+impl<K, V, S> Default for Memo<K, V, S>
+where
+    K: Clone + Eq + Hash,
+    V: Clone,
+    S: BuildHasher + Default,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// FxHasher is the fast, non-cryptographic hash rustc uses for its own tables.
+/// It suits small keys hashed very often, but unlike SipHash gives no
+/// protection against keys chosen to collide.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct FxHasher(u64);
+
+/// Builds FxHashers, for use as a Memo's or HashMap's S.
+pub type FxBuildHasher = BuildHasherDefault<FxHasher>;
+
+impl FxHasher {
+    fn add(&mut self, word: u64) {
+        self.0 =
+            (self.0.rotate_left(5) ^ word).wrapping_mul(0x517cc1b727220a95);
+    }
+}
+
+impl Hasher for FxHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for chunk in bytes.chunks(8) {
+            let mut word = [0; 8];
+            word[..chunk.len()].copy_from_slice(chunk);
+            self.add(u64::from_le_bytes(word));
+        }
+    }
+
+    fn write_u8(&mut self, i: u8) {
+        self.add(i.into());
+    }
+
+    fn write_u16(&mut self, i: u16) {
+        self.add(i.into());
+    }
+
+    fn write_u32(&mut self, i: u32) {
+        self.add(i.into());
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.add(i);
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        self.add(i as u64);
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recursive() {
+        fn paths(r: u8, c: u8, memo: &mut Memo<(u8, u8), u64>) -> u64 {
+            memo.get_or_insert_with((r, c), |memo| match (r, c) {
+                (0, _) | (_, 0) => 1,
+                _ => paths(r - 1, c, memo) + paths(r, c - 1, memo),
+            })
+        }
+
+        let mut memo = Memo::new();
+        assert_eq!(paths(16, 16, &mut memo), 601080390);
+        let stats = memo.stats();
+        assert_eq!(stats.misses, 17 * 17 - 1);
+        assert_eq!(stats.len, 17 * 17 - 1);
+        assert_eq!(stats.hits, 15 * 15);
+        assert_eq!(stats.evictions, 0);
+    }
+
+    #[test]
+    fn test_max() {
+        let mut memo: Memo<_, _> = Memo::with_limit(Limit::Max(2));
+        for k in 0..4 {
+            memo.insert(k, k * 10);
+        }
+        assert_eq!(memo.get(&0), Some(0));
+        assert_eq!(memo.get(&1), Some(10));
+        assert_eq!(memo.get(&2), None);
+        assert_eq!(memo.stats().dropped, 2);
+
+        // Updating a result already held is always allowed.
+        memo.insert(1, 11);
+        assert_eq!(memo.get(&1), Some(11));
+    }
+
+    #[test]
+    fn test_lru() {
+        let mut memo: Memo<_, _> = Memo::with_limit(Limit::Lru(2));
+        memo.insert('a', 1);
+        memo.insert('b', 2);
+        assert_eq!(memo.get(&'a'), Some(1));
+        // b is now the least recently used, so is evicted.
+        memo.insert('c', 3);
+        assert_eq!(memo.get(&'b'), None);
+        assert_eq!(memo.get(&'a'), Some(1));
+        assert_eq!(memo.get(&'c'), Some(3));
+        // As is a, after c was updated.
+        memo.insert('c', 4);
+        memo.insert('d', 5);
+        assert_eq!(memo.get(&'a'), None);
+        assert_eq!(memo.get(&'c'), Some(4));
+
+        let stats = memo.stats();
+        assert_eq!((stats.hits, stats.misses), (4, 2));
+        assert_eq!((stats.evictions, stats.len), (2, 2));
+        assert_eq!(
+            stats.to_string(),
+            "4 hits, 2 misses (66.7% hit rate), 2 evictions, 0 dropped, 2 held"
+        );
+
+        let mut none: Memo<_, _> = Memo::with_limit(Limit::Lru(0));
+        assert_eq!(none.get_or_insert_with(1, |_| 2), 2);
+        assert!(none.is_empty());
+    }
+
+    #[test]
+    fn test_fx_hasher() {
+        let mut memo: Memo<(u16, u16), u32, FxBuildHasher> = Memo::new();
+        for i in 0..100 {
+            memo.insert((i, i + 1), i.into());
+        }
+        assert_eq!(memo.get(&(42, 43)), Some(42));
+        assert_eq!(memo.get(&(43, 42)), None);
+
+        let hash = |s: &str| {
+            let mut h = FxHasher::default();
+            s.hash(&mut h);
+            h.finish()
+        };
+        assert_eq!(hash("valve"), hash("valve"));
+        assert_ne!(hash("valve"), hash("value"));
+    }
+}
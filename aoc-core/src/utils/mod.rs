@@ -2,7 +2,16 @@
 
 pub mod cmp;
 pub mod error;
+#[cfg(feature = "fetch")]
+pub mod fetch;
 pub mod file;
+pub mod fuzz;
+pub mod int;
 pub mod iter;
+pub mod memo;
+pub mod ocr;
 pub mod parse;
+pub mod pool;
+pub mod report;
+pub mod rng;
 pub mod test;
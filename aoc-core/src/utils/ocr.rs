@@ -0,0 +1,319 @@
+use std::error;
+use std::fmt;
+
+use itertools::Itertools;
+
+use crate::types::grid::Grid;
+
+/// The 4x6 block-letter font (5 wide for Y, 3 wide for I) drawn by most AoC
+/// puzzles with a screen, e.g. 2022 day 10.
+const FONT_6: &[(char, [&str; 6])] = &[
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', ["###", ".#.", ".#.", ".#.", ".#.", "###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', ["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// The larger 6x10 block-letter font, as used by e.g. 2018 day 10.
+const FONT_10: &[(char, [&str; 10])] = &[
+    (
+        'A',
+        [
+            "..##..", ".#..#.", "#....#", "#....#", "#....#", "######",
+            "#....#", "#....#", "#....#", "#....#",
+        ],
+    ),
+    (
+        'B',
+        [
+            "#####.", "#....#", "#....#", "#....#", "#####.", "#....#",
+            "#....#", "#....#", "#....#", "#####.",
+        ],
+    ),
+    (
+        'C',
+        [
+            ".####.", "#....#", "#.....", "#.....", "#.....", "#.....",
+            "#.....", "#.....", "#....#", ".####.",
+        ],
+    ),
+    (
+        'E',
+        [
+            "######", "#.....", "#.....", "#.....", "#####.", "#.....",
+            "#.....", "#.....", "#.....", "######",
+        ],
+    ),
+    (
+        'F',
+        [
+            "######", "#.....", "#.....", "#.....", "#####.", "#.....",
+            "#.....", "#.....", "#.....", "#.....",
+        ],
+    ),
+    (
+        'G',
+        [
+            ".####.", "#....#", "#.....", "#.....", "#.....", "#..###",
+            "#....#", "#....#", "#...##", ".###.#",
+        ],
+    ),
+    (
+        'H',
+        [
+            "#....#", "#....#", "#....#", "#....#", "######", "#....#",
+            "#....#", "#....#", "#....#", "#....#",
+        ],
+    ),
+    (
+        'J',
+        [
+            "...###", "....#.", "....#.", "....#.", "....#.", "....#.",
+            "....#.", "#...#.", "#...#.", ".###..",
+        ],
+    ),
+    (
+        'K',
+        [
+            "#....#", "#...#.", "#..#..", "#.#...", "##....", "##....",
+            "#.#...", "#..#..", "#...#.", "#....#",
+        ],
+    ),
+    (
+        'L',
+        [
+            "#.....", "#.....", "#.....", "#.....", "#.....", "#.....",
+            "#.....", "#.....", "#.....", "######",
+        ],
+    ),
+    (
+        'N',
+        [
+            "#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#",
+            "#..#.#", "#...##", "#...##", "#....#",
+        ],
+    ),
+    (
+        'P',
+        [
+            "#####.", "#....#", "#....#", "#....#", "#####.", "#.....",
+            "#.....", "#.....", "#.....", "#.....",
+        ],
+    ),
+    (
+        'R',
+        [
+            "#####.", "#....#", "#....#", "#....#", "#####.", "#..#..",
+            "#...#.", "#...#.", "#....#", "#....#",
+        ],
+    ),
+    (
+        'X',
+        [
+            "#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..",
+            ".#..#.", ".#..#.", "#....#", "#....#",
+        ],
+    ),
+    (
+        'Z',
+        [
+            "######", ".....#", ".....#", "....#.", "...#..", "..#...",
+            ".#....", "#.....", "#.....", "######",
+        ],
+    ),
+];
+
+/// An OcrError describes why some input could not be read as text.
+#[derive(Debug, Eq, PartialEq)]
+pub enum OcrError {
+    /// The input wasn't 6 or 10 rows high, so matches no known font.
+    UnsupportedHeight(usize),
+    /// A string input contained a char other than '#' or '.', or had rows of
+    /// differing lengths.
+    BadInput(String),
+    /// A glyph was found that isn't in the font. Holds the glyph as rows of
+    /// '#' and '.'.
+    UnknownGlyph(String),
+}
+
+impl fmt::Display for OcrError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedHeight(h) => {
+                write!(f, "no font is {h} rows high: expected 6 or 10")
+            },
+            Self::BadInput(msg) => write!(f, "bad OCR input: {msg}"),
+            Self::UnknownGlyph(glyph) => {
+                write!(f, "unrecognised glyph:\n{glyph}")
+            },
+        }
+    }
+}
+
+impl error::Error for OcrError {}
+
+/// Reads the letters drawn in a Grid<bool>, where true is a lit pixel. Letters
+/// are split apart on fully unlit columns, so need not be on a fixed pitch.
+pub fn recognise(grid: &Grid<bool>) -> Result<String, OcrError> {
+    let height = grid.height();
+    if height != 6 && height != 10 {
+        return Err(OcrError::UnsupportedHeight(height));
+    }
+
+    // Render each column as a string of its pixels, then group adjacent lit
+    // columns into glyphs.
+    let cols = grid
+        .iter_cols()
+        .map(|col| col.map(|(_, lit)| *lit).collect_vec())
+        .collect_vec();
+
+    let mut text = String::new();
+    for (is_blank, glyph_cols) in
+        &cols.into_iter().group_by(|col| col.iter().all(|lit| !lit))
+    {
+        if is_blank {
+            continue;
+        }
+
+        let glyph_cols = glyph_cols.collect_vec();
+        let rows = (0..height)
+            .map(|y| {
+                glyph_cols
+                    .iter()
+                    .map(|col| if col[y] { '#' } else { '.' })
+                    .collect::<String>()
+            })
+            .collect_vec();
+
+        text.push(
+            lookup(&rows)
+                .ok_or_else(|| OcrError::UnknownGlyph(rows.join("\n")))?,
+        );
+    }
+
+    Ok(text)
+}
+
+/// As recognise, but takes a string of '#' (lit) and '.' (unlit) rows split by
+/// newlines. Leading and trailing blank lines are ignored.
+pub fn recognise_str(s: &str) -> Result<String, OcrError> {
+    let rows = s
+        .trim_matches('\n')
+        .lines()
+        .map(|line| {
+            line.chars()
+                .map(|c| match c {
+                    '#' => Ok(true),
+                    '.' => Ok(false),
+                    _ => Err(OcrError::BadInput(format!(
+                        "unexpected char {c:?}"
+                    ))),
+                })
+                .collect::<Result<Vec<bool>, OcrError>>()
+        })
+        .collect::<Result<Vec<Vec<bool>>, OcrError>>()?;
+
+    let grid: Grid<bool> = rows
+        .try_into()
+        .map_err(|e: &str| OcrError::BadInput(e.to_string()))?;
+
+    recognise(&grid)
+}
+
+/// Finds the char drawn by the given rows of '#' and '.', if any.
+fn lookup(rows: &[String]) -> Option<char> {
+    match rows.len() {
+        6 => FONT_6
+            .iter()
+            .find(|(_, glyph)| glyph.iter().eq(rows.iter()))
+            .map(|(c, _)| *c),
+        10 => FONT_10
+            .iter()
+            .find(|(_, glyph)| glyph.iter().eq(rows.iter()))
+            .map(|(c, _)| *c),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recognise_6() {
+        let screen = "\
+###..#....#..#.#....#..#.###..####.#..#.
+#..#.#....#..#.#....#.#..#..#....#.#..#.
+#..#.#....#..#.#....##...###....#..####.
+###..#....#..#.#....#.#..#..#..#...#..#.
+#....#....#..#.#....#.#..#..#.#....#..#.
+#....####..##..####.#..#.###..####.#..#.
+";
+        assert_eq!(recognise_str(screen), Ok("PLULKBZH".to_string()));
+
+        // Narrow and wide glyphs next to one another.
+        let screen = "\
+###..#...#.####
+.#...#...#....#
+.#....#.#....#.
+.#.....#....#..
+.#.....#...#...
+###....#...####
+";
+        assert_eq!(recognise_str(screen), Ok("IYZ".to_string()));
+    }
+
+    #[test]
+    fn test_recognise_10() {
+        let screen = "\
+#....#..######...####.
+#....#.......#..#....#
+#....#.......#..#.....
+#....#......#...#.....
+######.....#....#.....
+#....#....#.....#..###
+#....#...#......#....#
+#....#..#.......#....#
+#....#..#.......#...##
+#....#..######...###.#
+";
+        assert_eq!(recognise_str(screen), Ok("HZG".to_string()));
+    }
+
+    #[test]
+    fn test_recognise_errors() {
+        assert_eq!(
+            recognise_str("#..#\n####\n"),
+            Err(OcrError::UnsupportedHeight(2))
+        );
+        assert!(matches!(
+            recognise_str("#..#\n#..#\n#..#\n#..#\n#..#\n#..x\n"),
+            Err(OcrError::BadInput(_))
+        ));
+        assert!(matches!(
+            recognise_str("#..#\n#..#\n#..#\n#..#\n#..#\n#..\n"),
+            Err(OcrError::BadInput(_))
+        ));
+
+        let err = recognise_str("##\n##\n##\n##\n##\n##\n").unwrap_err();
+        assert_eq!(
+            err,
+            OcrError::UnknownGlyph("##\n##\n##\n##\n##\n##".to_string())
+        );
+        assert!(err.to_string().ends_with("##\n##\n##\n##\n##\n##"));
+    }
+}
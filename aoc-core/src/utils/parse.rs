@@ -0,0 +1,302 @@
+use std::iter::Peekable;
+use std::str::Chars;
+
+use crate::utils::error::{parse_error, ParseError};
+
+/// A Parser is a convenience object for parsing a string input. It returns a
+/// ParseError when it cannot parse as required by a given call, after which
+/// its position in the input is unspecified.
+pub struct Parser<'a>(Peekable<Chars<'a>>);
+
+/// Produces a next_num function for the given type and unsigned number type.
+macro_rules! consume_unsigned {
+    ($fun: ident, $Ty: ty) => {
+        /// Consumes a
+        #[doc = stringify!($Ty)]
+        /// from the underlying iterator.
+        pub fn $fun(&mut self) -> Result<$Ty, ParseError> {
+            let mut peeked = *self.0.peek().ok_or_else(|| {
+                parse_error("expected number (input ended early)")
+            })?;
+
+            if !peeked.is_ascii_digit() {
+                return Err(parse_error("expected a number"));
+            }
+
+            let mut acc: $Ty = 0;
+            while let Some(digit) = peeked.to_digit(10) {
+                self.0.next();
+
+                acc = acc
+                    .checked_mul(10)
+                    .and_then(|acc| acc.checked_add(digit as $Ty))
+                    .ok_or_else(|| {
+                        parse_error(concat!(
+                            "number overflowed ",
+                            stringify!($Ty)
+                        ))
+                    })?;
+
+                peeked = match self.0.peek() {
+                    None => break,
+                    Some(p) => *p,
+                };
+            }
+
+            Ok(acc)
+        }
+    };
+}
+
+/// As consume_unsigned, but for an unsigned integer.
+macro_rules! consume_signed {
+    ($fun: ident, $Ty: ty) => {
+        /// Consumes a
+        #[doc = stringify!($Ty)]
+        /// from the underlying iterator.
+        pub fn $fun(&mut self) -> Result<$Ty, ParseError> {
+            let is_negative = self.optional_char('-');
+            let mut peeked = *self.0.peek().ok_or_else(|| {
+                parse_error("expected number (input ended early)")
+            })?;
+
+            if !peeked.is_ascii_digit() {
+                return Err(parse_error("expected a number"));
+            }
+
+            // Accumulate negative numbers as negative, so the minimum value
+            // doesn't overflow.
+            let mut acc: $Ty = 0;
+            while let Some(digit) = peeked.to_digit(10) {
+                self.0.next();
+
+                let digit = digit as $Ty;
+                acc = acc
+                    .checked_mul(10)
+                    .and_then(|acc| match is_negative {
+                        true => acc.checked_sub(digit),
+                        false => acc.checked_add(digit),
+                    })
+                    .ok_or_else(|| {
+                        parse_error(concat!(
+                            "number overflowed ",
+                            stringify!($Ty)
+                        ))
+                    })?;
+
+                peeked = match self.0.peek() {
+                    None => break,
+                    Some(p) => *p,
+                };
+            }
+
+            Ok(acc)
+        }
+    };
+}
+
+impl<'a> From<&'a str> for Parser<'a> {
+    fn from(value: &'a str) -> Self {
+        Parser(value.chars().peekable())
+    }
+}
+
+#[allow(dead_code)]
+impl<'a> Parser<'a> {
+    /// Collects the remaining unconsumed elements of the internal iterator to a
+    /// String, and returns that String.
+    pub fn remainder(self) -> String {
+        self.0.collect()
+    }
+
+    /// Checks that the entire input string has been consumed.
+    pub fn empty(mut self) -> Result<(), ParseError> {
+        match self.is_empty() {
+            true => Ok(()),
+            false => Err(parse_error(
+                format!("expected empty, found {:?}", self.remainder())
+                    .as_str(),
+            )),
+        }
+    }
+
+    pub fn is_empty(&mut self) -> bool {
+        self.0.peek().is_none()
+    }
+
+    /// Consumes whitespace from the input, returning the number of whitespace
+    /// characters returned.
+    pub fn whitespace(&mut self) -> usize {
+        let mut consumed = 0;
+        while self.0.next_if(|c| c.is_whitespace()).is_some() {
+            consumed += 1;
+        }
+        consumed
+    }
+
+    /// If the next char matches the one provided, consume it, and indicate if
+    /// one was consumed.
+    pub fn optional_char(&mut self, c: char) -> bool {
+        self.0.next_if_eq(&c).is_some()
+    }
+
+    /// Consumes the given prefix.
+    pub fn str(&mut self, prefix: &str) -> Result<(), ParseError> {
+        for c in prefix.chars() {
+            match self.0.next() {
+                Some(x) if x == c => (),
+                Some(_) => {
+                    return Err(parse_error(
+                        format!("didn't match prefix {prefix:?}").as_str(),
+                    ))
+                },
+                None => {
+                    return Err(parse_error(
+                        format!("input ended early, expected {prefix:?}")
+                            .as_str(),
+                    ))
+                },
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the next n chars as a String
+    pub fn read_n(&mut self, n: usize) -> Result<String, ParseError> {
+        let mut s = String::new();
+        for _ in 0..n {
+            s.push(
+                self.0
+                    .next()
+                    .ok_or_else(|| parse_error("input ended early"))?,
+            );
+        }
+        Ok(s)
+    }
+
+    consume_unsigned!(u8, u8);
+    consume_unsigned!(u16, u16);
+    consume_unsigned!(u32, u32);
+    consume_unsigned!(u64, u64);
+    consume_unsigned!(u128, u128);
+    consume_unsigned!(usize, usize);
+    consume_signed!(i8, i8);
+    consume_signed!(i16, i16);
+    consume_signed!(i32, i32);
+    consume_signed!(i64, i64);
+    consume_signed!(i128, i128);
+    consume_signed!(isize, isize);
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::utils::fuzz::{
+        assert_parser_never_panics, parser_panics, ANY_TEXT,
+    };
+
+    /// Parses "<name>: <i64>, <u8>..." using every method.
+    fn parse_example(s: &str) -> Result<(String, i64, Vec<u8>), ParseError> {
+        let mut p: Parser = s.into();
+        p.whitespace();
+        let name = p.read_n(3)?;
+        p.str(": ")?;
+        let n = p.i64()?;
+        let mut bytes = Vec::new();
+        while p.optional_char(',') {
+            p.whitespace();
+            bytes.push(p.u8()?);
+        }
+        p.empty()?;
+        Ok((name, n, bytes))
+    }
+
+    #[test]
+    fn test_next_unsigned() {
+        let mut iter: Parser = "12345   1234".into();
+        assert_eq!(iter.u32().unwrap(), 12345);
+        assert_eq!(iter.whitespace(), 3);
+        assert_eq!(iter.u32().unwrap(), 1234);
+        iter.empty().unwrap();
+    }
+
+    #[test]
+    fn test_next_signed() {
+        let mut iter: Parser = "-12345 12345 -1234 1234".into();
+        assert_eq!(iter.i32().unwrap(), -12345);
+        assert_eq!(iter.whitespace(), 1);
+        assert_eq!(iter.i32().unwrap(), 12345);
+        assert_eq!(iter.whitespace(), 1);
+        assert_eq!(iter.i32().unwrap(), -1234);
+        assert_eq!(iter.whitespace(), 1);
+        assert_eq!(iter.i32().unwrap(), 1234);
+        iter.empty().unwrap();
+    }
+
+    #[test]
+    fn test_number_errors() {
+        let mut iter: Parser = "255 256 -128 -129 - x".into();
+        assert_eq!(iter.u8().unwrap(), 255);
+        iter.whitespace();
+        assert!(iter.u8().is_err());
+        iter.whitespace();
+        assert_eq!(iter.i8().unwrap(), -128);
+        iter.whitespace();
+        assert!(iter.i8().is_err());
+        iter.whitespace();
+        assert!(iter.i8().is_err());
+        iter.whitespace();
+        assert!(iter.u8().is_err());
+        assert!(Parser::from("").u8().is_err());
+    }
+
+    #[test]
+    fn test_prefix() {
+        let mut iter: Parser = "abcdefgh".into();
+        iter.str("abc").unwrap();
+        iter.str("def").unwrap();
+        assert_eq!(iter.remainder().as_str(), "gh");
+
+        assert!(Parser::from("abc").str("abd").is_err());
+        assert!(Parser::from("abc").str("abcd").is_err());
+        assert!(Parser::from("abc").read_n(4).is_err());
+    }
+
+    #[test]
+    fn test_empty() {
+        let mut iter: Parser = "abc".into();
+        assert!(!iter.is_empty());
+        iter.str("abc").unwrap();
+        assert!(iter.is_empty());
+        iter.empty().unwrap();
+        assert!(Parser::from("abc").empty().is_err());
+    }
+
+    #[test]
+    fn test_fuzz() {
+        assert_eq!(
+            parse_example("abc: -12, 3, 4").unwrap(),
+            ("abc".to_string(), -12, vec![3, 4])
+        );
+        assert_parser_never_panics(
+            "Parser",
+            &["abc: -12, 3, 255"],
+            parse_example,
+        );
+    }
+
+    /// A regex for inputs shaped like parse_example's, as a proptest strategy.
+    const EXAMPLE_LIKE: &str =
+        r"\s*\PC{0,4}(: ?)?-?[0-9]{0,21}(, ?-?[0-9]{0,4}){0,4}";
+
+    proptest! {
+        #[test]
+        fn prop_parser_never_panics(
+            input in prop_oneof![ANY_TEXT, EXAMPLE_LIKE],
+        ) {
+            prop_assert!(!parser_panics(parse_example, &input));
+        }
+    }
+}
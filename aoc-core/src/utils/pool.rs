@@ -0,0 +1,44 @@
+//! The thread pool policy shared by the runner and the days it runs.
+//!
+//! Days parallelise with rayon's global pool, never one of their own, so that
+//! its size can be set from outside through RAYON_NUM_THREADS. Run alone, a day
+//! gets a thread per CPU. When the runner runs several days at once, it splits
+//! the threads between them with threads_per_job, rather than letting every
+//! day start a thread per CPU and oversubscribe the machine.
+
+use std::env;
+use std::num::NonZeroUsize;
+use std::thread;
+
+/// The environment variable rayon reads the size of its global pool from.
+pub const THREADS_ENV_VAR: &str = "RAYON_NUM_THREADS";
+
+/// Returns how many threads may run at once: RAYON_NUM_THREADS if it's set to
+/// a positive number, and otherwise the number of CPUs available.
+pub fn available_threads() -> usize {
+    match env::var(THREADS_ENV_VAR).map(|s| s.parse()) {
+        Ok(Ok(n)) if n > 0 => n,
+        _ => thread::available_parallelism().map_or(1, NonZeroUsize::get),
+    }
+}
+
+/// Returns how many threads each of `jobs` concurrent jobs may use, sharing
+/// `threads` between them. Every job gets at least one.
+pub fn threads_per_job(threads: usize, jobs: usize) -> usize {
+    (threads / jobs.max(1)).max(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_threads_per_job() {
+        assert_eq!(threads_per_job(8, 1), 8);
+        assert_eq!(threads_per_job(8, 2), 4);
+        assert_eq!(threads_per_job(8, 3), 2);
+        assert_eq!(threads_per_job(8, 16), 1);
+        assert_eq!(threads_per_job(8, 0), 8);
+        assert_eq!(threads_per_job(0, 4), 1);
+    }
+}
@@ -0,0 +1,343 @@
+use std::env;
+use std::error::Error;
+use std::fmt::{self, Display, Write};
+use std::fs;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use crate::utils::file::data_path;
+
+/// The environment variable read to choose the output Format.
+pub const FORMAT_ENV_VAR: &str = "AOC_FORMAT";
+
+/// The environment variable which, when set, asks days for extra statistics
+/// or breakdowns on stderr, beyond their answers.
+pub const STATS_ENV_VAR: &str = "AOC_STATS";
+
+/// Returns whether AOC_STATS is set.
+pub fn stats_wanted() -> bool {
+    env::var_os(STATS_ENV_VAR).is_some()
+}
+
+/// A Format describes how a Report writes out each answer.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Format {
+    /// Human-readable lines of the form "part one: 1234".
+    Text,
+    /// One JSON object per answer, on its own line.
+    Json,
+    /// One tab-separated row per answer, with the columns year, day, part,
+    /// answer, parse_ns, solve_ns and input_hash. No header row is written.
+    Tsv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            "tsv" => Ok(Self::Tsv),
+            _ => Err(format!("unknown output format {s:?}")),
+        }
+    }
+}
+
+impl Format {
+    /// Reads the Format from AOC_FORMAT, defaulting to Text if it's unset.
+    pub fn from_env() -> Result<Self, String> {
+        match env::var(FORMAT_ENV_VAR) {
+            Ok(s) => s.parse(),
+            Err(env::VarError::NotPresent) => Ok(Self::Text),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+}
+
+/// A Report times a day's parsing and solving, and writes each answer to
+/// stdout in the chosen Format. Anything else a day wants to print should go to
+/// stderr so structured output isn't interleaved with debug output.
+#[derive(Debug)]
+pub struct Report {
+    year: u16,
+    day: u8,
+    format: Format,
+    input_hash: u64,
+    parse_time: Option<Duration>,
+}
+
+impl Report {
+    /// Creates a Report for the given year and day, using the Format given by
+    /// AOC_FORMAT and hashing the day's input under `data/YEAR`.
+    pub fn new(year: u16, day: u8) -> Result<Self, Box<dyn Error>> {
+        Self::with_format(year, day, Format::from_env()?)
+    }
+
+    /// As new, but with an explicitly chosen Format.
+    pub fn with_format(
+        year: u16,
+        day: u8,
+        format: Format,
+    ) -> Result<Self, Box<dyn Error>> {
+        let input = fs::read(data_path(year, &format!("day{day:02}"))?)?;
+        Ok(Report {
+            year,
+            day,
+            format,
+            input_hash: fnv1a(&input),
+            parse_time: None,
+        })
+    }
+
+    /// Runs f, recording the time it took as parse time. If called more than
+    /// once, the times are summed.
+    pub fn parse<T, E>(
+        &mut self,
+        f: impl FnOnce() -> Result<T, E>,
+    ) -> Result<T, E> {
+        let start = Instant::now();
+        let res = f();
+        let elapsed = start.elapsed();
+        self.parse_time = Some(self.parse_time.unwrap_or_default() + elapsed);
+        res
+    }
+
+    /// Runs f, recording the time it took as the solve time for the given part,
+    /// and writes out the answer it returns.
+    pub fn part<T: Display>(&mut self, part: u8, f: impl FnOnce() -> T) {
+        let start = Instant::now();
+        let answer = f();
+        let elapsed = start.elapsed();
+        println!("{}", self.format_answer(part, &answer, elapsed));
+    }
+
+    /// As part, but for a fallible f. Nothing is written out if f fails.
+    pub fn try_part<T: Display, E>(
+        &mut self,
+        part: u8,
+        f: impl FnOnce() -> Result<T, E>,
+    ) -> Result<(), E> {
+        let start = Instant::now();
+        let answer = f()?;
+        let elapsed = start.elapsed();
+        println!("{}", self.format_answer(part, &answer, elapsed));
+        Ok(())
+    }
+
+    /// Formats a line describing the answer to a part, without a newline.
+    fn format_answer(
+        &self,
+        part: u8,
+        answer: &impl Display,
+        solve_time: Duration,
+    ) -> String {
+        let answer = answer.to_string();
+        let parse_ns = self.parse_time.map(|d| d.as_nanos());
+        let solve_ns = solve_time.as_nanos();
+        let hash = format!("{:016x}", self.input_hash);
+
+        match self.format {
+            Format::Text => format!("part {}: {answer}", PartName(part)),
+            Format::Json => format!(
+                concat!(
+                    r#"{{"year":{},"day":{},"part":{},"answer":{},"#,
+                    r#""parse_ns":{},"solve_ns":{},"input_hash":"{}"}}"#
+                ),
+                self.year,
+                self.day,
+                part,
+                json_str(&answer),
+                match parse_ns {
+                    None => "null".to_string(),
+                    Some(ns) => ns.to_string(),
+                },
+                solve_ns,
+                hash,
+            ),
+            Format::Tsv => format!(
+                "{}\t{}\t{}\t{}\t{}\t{}\t{}",
+                self.year,
+                self.day,
+                part,
+                answer.replace(['\t', '\n'], " "),
+                match parse_ns {
+                    None => String::new(),
+                    Some(ns) => ns.to_string(),
+                },
+                solve_ns,
+                hash,
+            ),
+        }
+    }
+}
+
+/// A Record is an answer as written by a Report in the Tsv format, read back
+/// in, e.g. by the runner or from a file of recorded answers.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub parse_ns: Option<u128>,
+    pub solve_ns: u128,
+    pub input_hash: u64,
+}
+
+impl FromStr for Record {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fields: Vec<_> = s.split('\t').collect();
+        let (year, day, part, answer, parse_ns, solve_ns, input_hash) =
+            match fields[..] {
+                [y, d, p, a, pn, sn, h] => (y, d, p, a, pn, sn, h),
+                _ => {
+                    return Err(format!(
+                        "expected 7 fields, got {}",
+                        fields.len()
+                    ))
+                },
+            };
+        let num_err = |e| format!("bad number in {s:?}: {e}");
+
+        Ok(Record {
+            year: year.parse().map_err(num_err)?,
+            day: day.parse().map_err(num_err)?,
+            part: part.parse().map_err(num_err)?,
+            answer: answer.to_string(),
+            parse_ns: match parse_ns {
+                "" => None,
+                ns => Some(ns.parse().map_err(num_err)?),
+            },
+            solve_ns: solve_ns.parse().map_err(num_err)?,
+            input_hash: u64::from_str_radix(input_hash, 16).map_err(num_err)?,
+        })
+    }
+}
+
+/// Writes a part number as a word, e.g. "one".
+struct PartName(u8);
+
+impl Display for PartName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            1 => f.write_str("one"),
+            2 => f.write_str("two"),
+            n => n.fmt(f),
+        }
+    }
+}
+
+/// Quotes and escapes a string for use as a JSON string.
+fn json_str(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                write!(out, "\\u{:04x}", c as u32).unwrap()
+            },
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// The 64-bit FNV-1a hash. Used over std's hashers as it's stable between
+/// builds, so input hashes can be compared across runs.
+pub fn fnv1a(data: &[u8]) -> u64 {
+    data.iter().fold(0xcbf29ce484222325, |h, b| {
+        (h ^ (*b as u64)).wrapping_mul(0x100000001b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(format: Format) -> Report {
+        Report {
+            year: 2022,
+            day: 1,
+            format,
+            input_hash: 0xabc,
+            parse_time: None,
+        }
+    }
+
+    #[test]
+    fn test_format_answer() {
+        let r = report(Format::Text);
+        let t = Duration::from_nanos(1234);
+        assert_eq!(r.format_answer(1, &24000, t), "part one: 24000");
+        assert_eq!(r.format_answer(2, &"AB", t), "part two: AB");
+
+        let mut r = report(Format::Json);
+        assert_eq!(
+            r.format_answer(1, &"a\"b", t),
+            concat!(
+                r#"{"year":2022,"day":1,"part":1,"answer":"a\"b","#,
+                r#""parse_ns":null,"solve_ns":1234,"#,
+                r#""input_hash":"0000000000000abc"}"#
+            )
+        );
+        r.parse_time = Some(Duration::from_nanos(5));
+        assert!(r.format_answer(1, &1, t).contains(r#""parse_ns":5,"#));
+
+        let mut r = report(Format::Tsv);
+        assert_eq!(
+            r.format_answer(2, &45000, t),
+            "2022\t1\t2\t45000\t\t1234\t0000000000000abc"
+        );
+        r.parse_time = Some(Duration::from_nanos(5));
+        assert_eq!(
+            r.format_answer(2, &"a\tb", t),
+            "2022\t1\t2\ta b\t5\t1234\t0000000000000abc"
+        );
+    }
+
+    #[test]
+    fn test_parse_record() {
+        let mut r = report(Format::Tsv);
+        r.parse_time = Some(Duration::from_nanos(5));
+        let line = r.format_answer(2, &"AB", Duration::from_nanos(1234));
+        assert_eq!(
+            line.parse(),
+            Ok(Record {
+                year: 2022,
+                day: 1,
+                part: 2,
+                answer: "AB".to_string(),
+                parse_ns: Some(5),
+                solve_ns: 1234,
+                input_hash: 0xabc,
+            })
+        );
+
+        let r: Record = "2022\t1\t1\t7\t\t9\tff".parse().unwrap();
+        assert_eq!(r.parse_ns, None);
+        assert!("2022\t1\t1\t7".parse::<Record>().is_err());
+        assert!("2022\tx\t1\t7\t\t9\tff".parse::<Record>().is_err());
+    }
+
+    #[test]
+    fn test_parse_format() {
+        assert_eq!("json".parse(), Ok(Format::Json));
+        assert_eq!("TSV".parse(), Ok(Format::Tsv));
+        assert_eq!("text".parse(), Ok(Format::Text));
+        assert!("xml".parse::<Format>().is_err());
+    }
+
+    #[test]
+    fn test_fnv1a() {
+        assert_eq!(fnv1a(b""), 0xcbf29ce484222325);
+        assert_eq!(fnv1a(b"a"), 0xaf63dc4c8601ec8c);
+    }
+}
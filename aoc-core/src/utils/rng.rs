@@ -0,0 +1,114 @@
+use std::ops::RangeInclusive;
+
+/// A small, seedable pseudo-random number generator (SplitMix64). It is not
+/// suitable for anything security-sensitive, but is fast, and produces the same
+/// sequence for a given seed on every platform, so generated inputs can be
+/// reproduced from their seed.
+#[derive(Clone, Debug)]
+pub struct Rng(u64);
+
+impl Rng {
+    /// Creates a new Rng from the given seed.
+    pub fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    /// Returns the next 64 random bits.
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a value in 0..n. Panics if n is zero.
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert_ne!(n, 0, "cannot pick a value below zero");
+        // Widening multiply maps the 64 random bits onto 0..n with negligible
+        // bias, without the cost of a division.
+        ((self.next_u64() as u128 * n as u128) >> 64) as u64
+    }
+
+    /// Returns a value in the given inclusive range. Panics if it's empty.
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (lo, hi) = range.into_inner();
+        assert!(lo <= hi, "cannot pick from an empty range");
+        let width = hi.abs_diff(lo);
+        if width == u64::MAX {
+            return self.next_u64() as i64;
+        }
+        lo.wrapping_add(self.below(width + 1) as i64)
+    }
+
+    /// As range, but for usizes.
+    pub fn range_usize(&mut self, range: RangeInclusive<usize>) -> usize {
+        let (lo, hi) = range.into_inner();
+        assert!(lo <= hi, "cannot pick from an empty range");
+        lo + self.below((hi - lo) as u64 + 1) as usize
+    }
+
+    /// Returns true with probability num/den.
+    pub fn chance(&mut self, num: u64, den: u64) -> bool {
+        self.below(den) < num
+    }
+
+    /// Returns a reference to a random item of the slice. Panics if empty.
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len() as u64) as usize]
+    }
+
+    /// Shuffles the slice in place (Fisher-Yates).
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.below(i as u64 + 1) as usize;
+            items.swap(i, j);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deterministic() {
+        let a: Vec<u64> = {
+            let mut r = Rng::new(42);
+            (0..10).map(|_| r.next_u64()).collect()
+        };
+        let b: Vec<u64> = {
+            let mut r = Rng::new(42);
+            (0..10).map(|_| r.next_u64()).collect()
+        };
+        assert_eq!(a, b);
+        assert_ne!(Rng::new(1).next_u64(), Rng::new(2).next_u64());
+
+        // Reference value for SplitMix64 seeded with 0.
+        assert_eq!(Rng::new(0).next_u64(), 0xe220a8397b1dcdaf);
+    }
+
+    #[test]
+    fn test_ranges() {
+        let mut r = Rng::new(7);
+        let mut seen = [false; 7];
+        for _ in 0..1000 {
+            let v = r.range(-3..=3);
+            assert!((-3..=3).contains(&v));
+            seen[(v + 3) as usize] = true;
+        }
+        assert!(seen.iter().all(|s| *s));
+
+        for _ in 0..1000 {
+            assert!((5..=6).contains(&r.range_usize(5..=6)));
+            assert!(r.below(3) < 3);
+        }
+        assert_eq!(r.range(4..=4), 4);
+        r.range(i64::MIN..=i64::MAX);
+
+        let mut v: Vec<u32> = (0..20).collect();
+        r.shuffle(&mut v);
+        v.sort();
+        assert_eq!(v, (0..20).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,254 @@
+use std::collections::{hash_map::Entry, HashMap};
+use std::env;
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::num::NonZeroUsize;
+use std::panic::{self, AssertUnwindSafe};
+
+/// The environment variable setting how many seeds differential tests try at
+/// each size. Raise it for a slower but more thorough run.
+pub const DIFF_SEEDS_ENV_VAR: &str = "AOC_DIFF_SEEDS";
+
+const DEFAULT_DIFF_SEEDS: u64 = 10;
+
+pub fn catch_unwind_silent<F: FnOnce() -> R + panic::UnwindSafe, R>(
+    f: F,
+) -> std::thread::Result<R> {
+    let prev_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let result = panic::catch_unwind(f);
+    panic::set_hook(prev_hook);
+    result
+}
+
+/// Compares two vecs as multisets, asserting both contain the same elements (in
+/// any order, but equal number).
+pub fn assert_vec_eq_multiset<T: Debug + Eq + Hash>(a: Vec<T>, b: Vec<T>) {
+    if a.len() != b.len() {
+        panic!(
+            "vecs are different lengths: {} vs {}\nlhs = {a:?}\nrhs = {b:?}",
+            a.len(),
+            b.len()
+        );
+    }
+
+    let mut a_mset: HashMap<&T, NonZeroUsize> = HashMap::new();
+    for a_item in a.iter() {
+        a_mset
+            .entry(a_item)
+            .and_modify(|x| *x = x.checked_add(1).unwrap())
+            .or_insert_with(|| 1.try_into().unwrap());
+    }
+
+    for b_item in b.iter() {
+        match a_mset.entry(b_item) {
+            Entry::Occupied(mut e) => {
+                // Decrement e, removing it if this would change it to zero.
+                let v: usize = (*e.get()).into();
+                if v == 1 {
+                    e.remove();
+                } else {
+                    *e.get_mut() = (v - 1).try_into().unwrap()
+                }
+            },
+            Entry::Vacant(e) => panic!(
+                "rhs contained value {:?} not in lhs\nlhs = {a:?}\nrhs = {b:?}",
+                e.key()
+            ),
+        }
+    }
+
+    // Theoretically, this is redundant: either we've seen everything in a_mset
+    // in b, or otherwise failed, so a_mset should be empty.
+    assert!(a_mset.is_empty());
+}
+
+/// A Divergence is an input on which a reference and an optimised solver
+/// disagree.
+#[derive(Debug, Eq, PartialEq)]
+pub struct Divergence<T> {
+    /// The seed and size the input was generated from, before shrinking.
+    pub seed: u64,
+    pub size: usize,
+    pub input: String,
+    pub reference: T,
+    /// The optimised solver's answer, or None if it panicked.
+    pub optimised: Option<T>,
+}
+
+/// Runs a reference and an optimised solver on inputs made by generate, a
+/// function of a seed and a size, trying AOC_DIFF_SEEDS seeds at each of sizes
+/// in turn. Returns the first
+/// input they disagree on, shrunk by deleting lines and then chars for as long
+/// as they still disagree, or None if they always agree.
+///
+/// Inputs the reference panics on are skipped, so a reference may panic to
+/// reject inputs it can't handle, e.g. ones too large for brute force.
+pub fn find_divergence<T: PartialEq>(
+    generate: impl Fn(u64, usize) -> String,
+    sizes: &[usize],
+    reference: impl Fn(&str) -> T,
+    optimised: impl Fn(&str) -> T,
+) -> Option<Divergence<T>> {
+    let seeds = match env::var(DIFF_SEEDS_ENV_VAR) {
+        Ok(s) => s.parse().expect("AOC_DIFF_SEEDS should be a number"),
+        Err(_) => DEFAULT_DIFF_SEEDS,
+    };
+
+    // Returns the answers if the solvers disagree on input.
+    let diverges = |input: &str| -> Option<(T, Option<T>)> {
+        let r = catch_unwind_silent(AssertUnwindSafe(|| reference(input)));
+        let o = catch_unwind_silent(AssertUnwindSafe(|| optimised(input)));
+        match (r, o) {
+            (Ok(r), Ok(o)) if r == o => None,
+            (Ok(r), o) => Some((r, o.ok())),
+            (Err(_), _) => None,
+        }
+    };
+
+    for &size in sizes {
+        for seed in 0..seeds {
+            let mut input = generate(seed, size);
+            let mut answers = match diverges(&input) {
+                Some(answers) => answers,
+                None => continue,
+            };
+
+            loop {
+                let smaller = shrink(&input).find_map(|s| {
+                    let answers = diverges(&s)?;
+                    Some((s, answers))
+                });
+                match smaller {
+                    Some(smaller) => (input, answers) = smaller,
+                    None => break,
+                }
+            }
+
+            return Some(Divergence {
+                seed,
+                size,
+                input,
+                reference: answers.0,
+                optimised: answers.1,
+            });
+        }
+    }
+
+    None
+}
+
+/// As find_divergence, but panics with the divergence if one is found.
+pub fn assert_no_divergence<T: Debug + PartialEq>(
+    generate: impl Fn(u64, usize) -> String,
+    sizes: &[usize],
+    reference: impl Fn(&str) -> T,
+    optimised: impl Fn(&str) -> T,
+) {
+    if let Some(d) = find_divergence(generate, sizes, reference, optimised) {
+        panic!(
+            "solvers diverge (seed {}, size {}): reference gave \
+            {:?}, optimised gave {}, on input:\n{}",
+            d.seed,
+            d.size,
+            d.reference,
+            match d.optimised {
+                Some(o) => format!("{o:?}"),
+                None => "a panic".to_string(),
+            },
+            d.input,
+        );
+    }
+}
+
+/// Returns the inputs one step smaller than input: each with one line deleted,
+/// then each with one char deleted.
+fn shrink(input: &str) -> impl Iterator<Item = String> + '_ {
+    let lines: Vec<&str> = input.lines().collect();
+    let without_line = (0..lines.len()).map(move |i| {
+        lines
+            .iter()
+            .enumerate()
+            .filter(|(j, _)| *j != i)
+            .map(|(_, l)| format!("{l}\n"))
+            .collect()
+    });
+
+    let without_char = input
+        .char_indices()
+        .filter(|(_, c)| *c != '\n')
+        .map(|(i, c)| format!("{}{}", &input[..i], &input[i + c.len_utf8()..]));
+
+    without_line.chain(without_char)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::rng::Rng;
+
+    #[test]
+    fn test_vec_eq_set() {
+        assert_vec_eq_multiset::<u8>(vec![1, 2, 3], vec![3, 2, 1]);
+        assert_vec_eq_multiset::<u8>(vec![1, 2, 3], vec![1, 2, 3]);
+        assert_vec_eq_multiset::<u8>(vec![1, 2, 2], vec![2, 1, 2]);
+
+        assert!(catch_unwind_silent(|| assert_vec_eq_multiset::<u8>(
+            vec![1, 2, 3],
+            vec![3, 2]
+        ))
+        .is_err());
+        assert!(catch_unwind_silent(|| assert_vec_eq_multiset::<u8>(
+            vec![3, 2],
+            vec![1, 2, 3]
+        ))
+        .is_err());
+        assert!(catch_unwind_silent(|| assert_vec_eq_multiset::<u8>(
+            vec![],
+            vec![1, 2, 3]
+        ))
+        .is_err());
+        assert!(catch_unwind_silent(|| assert_vec_eq_multiset::<u8>(
+            vec![2, 2],
+            vec![2, 2, 2]
+        ))
+        .is_err());
+        assert!(catch_unwind_silent(|| assert_vec_eq_multiset::<u8>(
+            vec![2, 3, 3],
+            vec![2, 2, 3]
+        ))
+        .is_err());
+    }
+
+    #[test]
+    fn test_find_divergence() {
+        // Inputs are size lines of "R n"; these solvers disagree whenever
+        // there's a line with n > 15, so the minimal input is one such line.
+        let generate = |seed, size| {
+            let mut rng = Rng::new(seed);
+            (0..size)
+                .map(|_| format!("R {}\n", rng.range(1..=20)))
+                .collect::<String>()
+        };
+        let total = |input: &str| -> u32 {
+            input.lines().map(|l| l[2..].parse::<u32>().unwrap()).sum()
+        };
+        let capped = |input: &str| -> u32 {
+            input
+                .lines()
+                .map(|l| l[2..].parse::<u32>().unwrap().min(15))
+                .sum()
+        };
+
+        assert_eq!(find_divergence(generate, &[1, 5, 10], total, total), None);
+
+        let d = find_divergence(generate, &[1, 5, 10], total, capped).unwrap();
+        assert_eq!(d.input.lines().count(), 1);
+        assert!(d.reference > 15);
+        assert_eq!(d.optimised, Some(15));
+
+        // A panic in the optimised solver is a divergence too.
+        let d = find_divergence(generate, &[1], total, |_| -> u32 { panic!() });
+        assert_eq!(d.unwrap().optimised, None);
+    }
+}
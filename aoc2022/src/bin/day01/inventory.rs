@@ -0,0 +1,384 @@
+use std::cmp::Reverse;
+use std::{error, fmt, io};
+
+use aoc2022::utils::file::InputRecord;
+use aoc2022::utils::iter::top_k;
+
+/// An Inventory holds the total calories carried by each elf, in the order the
+/// elves appear in the input.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Inventory {
+    totals: Vec<u64>,
+}
+
+/// An ElfTotal is the calories carried by one elf, with the elf's 0-based
+/// index in the input.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ElfTotal {
+    pub index: usize,
+    pub calories: u64,
+}
+
+/// A Bucket of a histogram, counting the elves carrying start..end calories.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Bucket {
+    pub start: u64,
+    pub end: u64,
+    pub count: usize,
+}
+
+/// An InventoryError describes why a query of an Inventory has no answer.
+#[derive(Clone, Debug, PartialEq)]
+pub enum InventoryError {
+    /// The query needs at least one elf, but there are none.
+    Empty,
+    /// More elves were asked for than there are.
+    TooFewElves { wanted: usize, found: usize },
+    /// More elves were asked for than a Summary kept.
+    NotKept { wanted: usize, kept: usize },
+    /// A percentage outside 0..=100 was given.
+    BadPercentage(f64),
+    /// A histogram of no buckets was asked for.
+    NoBuckets,
+    /// The total of all the elves' calories doesn't fit in a u64.
+    Overflow,
+}
+
+impl fmt::Display for InventoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "the inventory has no elves"),
+            Self::TooFewElves { wanted, found } => {
+                write!(f, "wanted {wanted} elves, but there are only {found}")
+            },
+            Self::NotKept { wanted, kept } => {
+                write!(f, "wanted {wanted} elves, but only {kept} were kept")
+            },
+            Self::BadPercentage(p) => {
+                write!(f, "{p} is not a percentage in 0..=100")
+            },
+            Self::NoBuckets => write!(f, "a histogram needs at least 1 bucket"),
+            Self::Overflow => write!(f, "total calories overflowed"),
+        }
+    }
+}
+
+impl error::Error for InventoryError {}
+
+impl Inventory {
+    /// Creates an Inventory from each elf's total, in input order.
+    pub fn from_totals(totals: Vec<u64>) -> Self {
+        Inventory { totals }
+    }
+
+    /// Reads an Inventory from records of numbers, one record per elf.
+    pub fn from_records(
+        records: impl Iterator<Item = Result<InputRecord, io::Error>>,
+    ) -> Result<Self, io::Error> {
+        let totals = records
+            .map(|record| record_sum(&record?))
+            .collect::<Result<_, _>>()?;
+        Ok(Self::from_totals(totals))
+    }
+
+    /// Returns each elf's total, in input order.
+    pub fn totals(&self) -> &[u64] {
+        &self.totals
+    }
+
+    pub fn len(&self) -> usize {
+        self.totals.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.totals.is_empty()
+    }
+
+    /// Returns each elf's total with its index, in input order.
+    pub fn elves(&self) -> impl Iterator<Item = ElfTotal> + '_ {
+        self.totals()
+            .iter()
+            .enumerate()
+            .map(|(index, &calories)| ElfTotal { index, calories })
+    }
+
+    /// Returns the n elves carrying the most calories, most first. Elves
+    /// carrying the same are ordered by index.
+    pub fn top_n(&self, n: usize) -> Result<Vec<ElfTotal>, InventoryError> {
+        if n > self.len() {
+            return Err(InventoryError::TooFewElves {
+                wanted: n,
+                found: self.len(),
+            });
+        }
+        Ok(
+            top_k(self.elves().map(|e| (e.calories, Reverse(e.index))), n)
+                .into_iter()
+                .map(|(calories, Reverse(index))| ElfTotal { index, calories })
+                .collect(),
+        )
+    }
+
+    /// Returns the total calories carried by all the elves.
+    pub fn sum(&self) -> Result<u64, InventoryError> {
+        self.totals
+            .iter()
+            .try_fold(0u64, |sum, &t| sum.checked_add(t))
+            .ok_or(InventoryError::Overflow)
+    }
+
+    /// Returns the mean calories carried per elf.
+    pub fn mean(&self) -> Result<f64, InventoryError> {
+        if self.is_empty() {
+            return Err(InventoryError::Empty);
+        }
+        Ok(self.sum()? as f64 / self.len() as f64)
+    }
+
+    /// Returns the median calories carried per elf: the mean of the middle two
+    /// if there are an even number of elves.
+    pub fn median(&self) -> Result<f64, InventoryError> {
+        let sorted = self.sorted();
+        let mid = sorted.len() / 2;
+        match sorted.len() {
+            0 => Err(InventoryError::Empty),
+            n if n % 2 == 1 => Ok(sorted[mid] as f64),
+            _ => Ok((sorted[mid - 1] as f64 + sorted[mid] as f64) / 2.0),
+        }
+    }
+
+    /// Returns the p'th percentile of calories carried, by the nearest-rank
+    /// method: the smallest total at least p% of elves carry no more than.
+    pub fn percentile(&self, p: f64) -> Result<u64, InventoryError> {
+        if !(0.0..=100.0).contains(&p) {
+            return Err(InventoryError::BadPercentage(p));
+        }
+        let sorted = self.sorted();
+        if sorted.is_empty() {
+            return Err(InventoryError::Empty);
+        }
+        let rank = (p / 100.0 * sorted.len() as f64).ceil() as usize;
+        Ok(sorted[rank.clamp(1, sorted.len()) - 1])
+    }
+
+    /// Returns a histogram of calories carried, in the given number of
+    /// equal-width buckets spanning the smallest to the largest total.
+    pub fn histogram(
+        &self,
+        buckets: usize,
+    ) -> Result<Vec<Bucket>, InventoryError> {
+        if buckets == 0 {
+            return Err(InventoryError::NoBuckets);
+        }
+        let (min, max) =
+            match (self.totals.iter().min(), self.totals.iter().max()) {
+                (Some(&min), Some(&max)) => (min, max),
+                _ => return Err(InventoryError::Empty),
+            };
+
+        // Round the width up, so the last bucket includes max.
+        let width = (max - min) / buckets as u64 + 1;
+        let mut histogram: Vec<_> = (0..buckets as u64)
+            .map(|i| Bucket {
+                start: min.saturating_add(i.saturating_mul(width)),
+                end: min.saturating_add((i + 1).saturating_mul(width)),
+                count: 0,
+            })
+            .collect();
+        for t in &self.totals {
+            histogram[((t - min) / width) as usize].count += 1;
+        }
+        Ok(histogram)
+    }
+
+    /// Returns the fewest elves that together carry at least percent% of all
+    /// the calories, most first.
+    pub fn carrying_share(
+        &self,
+        percent: f64,
+    ) -> Result<Vec<ElfTotal>, InventoryError> {
+        if !(0.0..=100.0).contains(&percent) {
+            return Err(InventoryError::BadPercentage(percent));
+        }
+        let target = percent / 100.0 * self.sum()? as f64;
+
+        let mut carried = 0;
+        let mut elves = Vec::new();
+        for elf in self.top_n(self.len())? {
+            if carried as f64 >= target {
+                break;
+            }
+            carried += elf.calories;
+            elves.push(elf);
+        }
+        Ok(elves)
+    }
+
+    fn sorted(&self) -> Vec<u64> {
+        let mut sorted = self.totals.clone();
+        sorted.sort_unstable();
+        sorted
+    }
+}
+
+/// Writes an Inventory as a calorie list in which each elf carries one item of
+/// its total, so it parses back to the same Inventory.
+impl fmt::Display for Inventory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, total) in self.totals.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            writeln!(f, "{total}")?;
+        }
+        Ok(())
+    }
+}
+
+/// record_sum adds up the numbers in a record, one per line.
+pub fn record_sum(record: &InputRecord) -> Result<u64, io::Error> {
+    let mut sum: u64 = 0;
+    for (i, line) in record.lines().iter().enumerate() {
+        let val: u64 = line.parse().map_err(|e| record.line_err(i, e))?;
+        sum = sum
+            .checked_add(val)
+            .ok_or_else(|| record.line_err(i, "sum overflowed"))?;
+    }
+    Ok(sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc2022::utils::file::{get_input_records, get_str_records};
+    use aoc2022::YEAR;
+
+    use super::*;
+
+    fn example() -> Inventory {
+        let records = get_input_records(YEAR, "example/day01").unwrap();
+        Inventory::from_records(records).unwrap()
+    }
+
+    #[test]
+    fn test_from_records() {
+        assert_eq!(example().totals(), [6000, 4000, 11000, 24000, 10000]);
+
+        let err = Inventory::from_records(get_str_records("1\n2\n\n3\nx\n"));
+        assert_eq!(
+            err.unwrap_err().to_string(),
+            format!("line 5: {}", "x".parse::<u64>().unwrap_err())
+        );
+    }
+
+    #[test]
+    fn test_top_n() {
+        let inventory = example();
+        let top = inventory.top_n(2).unwrap();
+        assert_eq!(
+            top,
+            [
+                ElfTotal {
+                    index: 3,
+                    calories: 24000
+                },
+                ElfTotal {
+                    index: 2,
+                    calories: 11000
+                },
+            ]
+        );
+        assert_eq!(inventory.top_n(0).unwrap(), []);
+        assert_eq!(inventory.top_n(5).unwrap().len(), 5);
+        assert_eq!(
+            inventory.top_n(6),
+            Err(InventoryError::TooFewElves {
+                wanted: 6,
+                found: 5
+            })
+        );
+
+        // Ties go to the earlier elf.
+        let tied = Inventory::from_totals(vec![5, 7, 7, 5]);
+        let indices: Vec<_> =
+            tied.top_n(3).unwrap().iter().map(|e| e.index).collect();
+        assert_eq!(indices, [1, 2, 0]);
+    }
+
+    #[test]
+    fn test_stats() {
+        let inventory = example();
+        assert_eq!(inventory.sum(), Ok(55000));
+        assert_eq!(inventory.mean(), Ok(11000.0));
+        assert_eq!(inventory.median(), Ok(10000.0));
+        assert_eq!(inventory.percentile(0.0), Ok(4000));
+        assert_eq!(inventory.percentile(50.0), Ok(10000));
+        assert_eq!(inventory.percentile(80.0), Ok(11000));
+        assert_eq!(inventory.percentile(81.0), Ok(24000));
+        assert_eq!(inventory.percentile(100.0), Ok(24000));
+        assert_eq!(
+            inventory.percentile(101.0),
+            Err(InventoryError::BadPercentage(101.0))
+        );
+        assert_eq!(Inventory::from_totals(vec![1, 4]).median(), Ok(2.5));
+
+        let empty = Inventory::default();
+        assert_eq!(empty.sum(), Ok(0));
+        assert_eq!(empty.mean(), Err(InventoryError::Empty));
+        assert_eq!(empty.median(), Err(InventoryError::Empty));
+        assert_eq!(empty.percentile(50.0), Err(InventoryError::Empty));
+
+        let huge = Inventory::from_totals(vec![u64::MAX, 1]);
+        assert_eq!(huge.mean(), Err(InventoryError::Overflow));
+    }
+
+    #[test]
+    fn test_histogram() {
+        let histogram = example().histogram(2).unwrap();
+        assert_eq!(
+            histogram,
+            [
+                Bucket {
+                    start: 4000,
+                    end: 14001,
+                    count: 4
+                },
+                Bucket {
+                    start: 14001,
+                    end: 24002,
+                    count: 1
+                },
+            ]
+        );
+
+        let same = Inventory::from_totals(vec![3, 3]).histogram(3).unwrap();
+        assert_eq!(same.iter().map(|b| b.count).collect::<Vec<_>>(), [2, 0, 0]);
+
+        let spread = Inventory::from_totals(vec![0, u64::MAX]);
+        let histogram = spread.histogram(4).unwrap();
+        assert_eq!(histogram.iter().map(|b| b.count).sum::<usize>(), 2);
+        assert_eq!(histogram[3].count, 1);
+
+        assert_eq!(example().histogram(0), Err(InventoryError::NoBuckets));
+        assert_eq!(
+            Inventory::default().histogram(1),
+            Err(InventoryError::Empty)
+        );
+    }
+
+    #[test]
+    fn test_carrying_share() {
+        let inventory = example();
+        let indices = |percent| -> Vec<usize> {
+            let elves = inventory.carrying_share(percent).unwrap();
+            elves.iter().map(|e| e.index).collect()
+        };
+        assert_eq!(indices(0.0), []);
+        assert_eq!(indices(40.0), [3]);
+        assert_eq!(indices(50.0), [3, 2]);
+        assert_eq!(indices(100.0), [3, 2, 4, 0, 1]);
+        assert_eq!(
+            inventory.carrying_share(-1.0),
+            Err(InventoryError::BadPercentage(-1.0))
+        );
+    }
+}
@@ -0,0 +1,62 @@
+mod inventory;
+mod part1;
+mod part2;
+mod stream;
+
+use std::error::Error;
+use std::io;
+
+use aoc2022::utils::file::{get_input_reader, get_input_records};
+use aoc2022::utils::report::{stats_wanted, Report};
+use aoc2022::YEAR;
+
+use inventory::Inventory;
+use stream::{elf_totals, Summary};
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let mut report = Report::new(YEAR, 1)?;
+
+    // Both parts and the statistics come from one pass over the input, which
+    // without AOC_STATS holds only one elf at a time.
+    let (summary, inventory) = report.parse(|| -> Result<_, io::Error> {
+        if !stats_wanted() {
+            let totals = elf_totals(get_input_reader(YEAR, "day01")?);
+            return Ok((Summary::from_totals(totals, 3)?, None));
+        }
+        let records = get_input_records(YEAR, "day01")?;
+        let inventory = Inventory::from_records(records)?;
+        let totals = inventory.totals().iter().map(|&total| Ok(total));
+        Ok((Summary::from_totals(totals, 3)?, Some(inventory)))
+    })?;
+    report.try_part(1, || part1::solve(&summary))?;
+    report.try_part(2, || part2::solve(&summary))?;
+
+    eprintln!(
+        "{} elves carry {} calories: mean {:.1}, min {}, max {}",
+        summary.elves(),
+        summary.sum(),
+        summary.mean()?,
+        summary.min()?,
+        summary.max()?,
+    );
+    if let Some(inventory) = inventory {
+        print_stats(&inventory)?;
+    }
+    Ok(())
+}
+
+/// Writes the statistics that need every elf's total to stderr.
+fn print_stats(inventory: &Inventory) -> Result<(), Box<dyn Error>> {
+    eprintln!(
+        "median {} (mean {:.1}), 90th percentile {}",
+        inventory.median()?,
+        inventory.mean()?,
+        inventory.percentile(90.0)?,
+    );
+    let half = inventory.carrying_share(50.0)?;
+    eprintln!("{} elves carry half the calories", half.len());
+    for bucket in inventory.histogram(5)? {
+        eprintln!("{:>6}..{:<6} {}", bucket.start, bucket.end, bucket.count);
+    }
+    Ok(())
+}
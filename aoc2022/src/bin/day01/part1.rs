@@ -0,0 +1,38 @@
+use crate::inventory::InventoryError;
+use crate::stream::Summary;
+
+pub fn solve(summary: &Summary) -> Result<u64, InventoryError> {
+    Ok(summary.top_n(1)?[0].calories)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc2022::gen;
+    use aoc2022::utils::file::get_input_reader;
+    use aoc2022::YEAR;
+
+    use crate::stream::elf_totals;
+
+    #[test]
+    fn test_solve() {
+        let reader = get_input_reader(YEAR, "example/day01").unwrap();
+        let summary = Summary::from_totals(elf_totals(reader), 1).unwrap();
+        assert_eq!(solve(&summary).unwrap(), 24000);
+    }
+
+    #[test]
+    fn test_generated() {
+        gen::for_each_input(1, gen::TEST_SIZES, |input| {
+            let totals = elf_totals(input.as_bytes());
+            let summary = Summary::from_totals(totals, 1).unwrap();
+            let res = solve(&summary).unwrap();
+            let biggest_item = input
+                .lines()
+                .filter_map(|l| l.parse::<u64>().ok())
+                .max()
+                .unwrap_or(0);
+            assert!(res >= biggest_item);
+        });
+    }
+}
@@ -0,0 +1,40 @@
+use crate::inventory::InventoryError;
+use crate::stream::Summary;
+
+pub fn solve(summary: &Summary) -> Result<u64, InventoryError> {
+    Ok(summary.top_n(3)?.iter().map(|elf| elf.calories).sum())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc2022::gen;
+    use aoc2022::utils::file::get_input_reader;
+    use aoc2022::YEAR;
+
+    use crate::stream::elf_totals;
+
+    #[test]
+    fn test_solve() {
+        let reader = get_input_reader(YEAR, "example/day01").unwrap();
+        let summary = Summary::from_totals(elf_totals(reader), 3).unwrap();
+        assert_eq!(solve(&summary).unwrap(), 45000);
+
+        let two = Summary::from_totals(elf_totals(&b"1\n\n2\n"[..]), 3);
+        assert_eq!(
+            solve(&two.unwrap()).unwrap_err().to_string(),
+            "wanted 3 elves, but there are only 2"
+        );
+    }
+
+    #[test]
+    fn test_generated() {
+        gen::for_each_input(1, gen::TEST_SIZES, |input| {
+            let totals = elf_totals(input.as_bytes());
+            let summary = Summary::from_totals(totals, 3).unwrap();
+            let top = solve(&summary).unwrap();
+            let best = crate::part1::solve(&summary).unwrap();
+            assert!((best..=3 * best).contains(&top));
+        });
+    }
+}
@@ -0,0 +1,353 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::io::{self, BufRead};
+
+use aoc2022::utils::error::invalid_data_err_from;
+use aoc2022::utils::file::{is_blank, records};
+
+use crate::inventory::{record_sum, ElfTotal, InventoryError};
+
+/// elf_totals reads calorie lists from reader, one number per line with elves
+/// separated by blank lines, and returns each elf's total as soon as it's read.
+/// Only the current elf's lines are held, so memory use doesn't grow with the
+/// input.
+pub fn elf_totals(
+    reader: impl BufRead,
+) -> impl Iterator<Item = Result<u64, io::Error>> {
+    records(reader.lines(), is_blank).map(|record| record_sum(&record?))
+}
+
+/// A Summary takes elves' totals one at a time, keeping only their count, sum,
+/// extremes and the n largest, so its size doesn't grow with the number of
+/// elves.
+#[derive(Clone, Debug)]
+pub struct Summary {
+    n: usize,
+    /// The n largest totals so far with their elves' indices, as a min-heap
+    /// so the smallest is the one to replace. Of equal totals, the later elf
+    /// is the smaller.
+    top: BinaryHeap<Reverse<(u64, Reverse<usize>)>>,
+    elves: usize,
+    sum: u64,
+    min: Option<u64>,
+    max: Option<u64>,
+}
+
+impl Summary {
+    /// Creates an empty Summary that keeps the n largest totals.
+    pub fn new(n: usize) -> Self {
+        Summary {
+            n,
+            top: BinaryHeap::with_capacity(n + 1),
+            elves: 0,
+            sum: 0,
+            min: None,
+            max: None,
+        }
+    }
+
+    /// Summarises totals as read by elf_totals, keeping the n largest.
+    pub fn from_totals(
+        totals: impl IntoIterator<Item = Result<u64, io::Error>>,
+        n: usize,
+    ) -> Result<Self, io::Error> {
+        let mut summary = Summary::new(n);
+        for total in totals {
+            summary.add(total?).map_err(invalid_data_err_from)?;
+        }
+        Ok(summary)
+    }
+
+    /// Adds the next elf's total.
+    pub fn add(&mut self, calories: u64) -> Result<(), InventoryError> {
+        self.sum = self
+            .sum
+            .checked_add(calories)
+            .ok_or(InventoryError::Overflow)?;
+        self.min = Some(self.min.map_or(calories, |min| min.min(calories)));
+        self.max = Some(self.max.map_or(calories, |max| max.max(calories)));
+
+        self.top.push(Reverse((calories, Reverse(self.elves))));
+        if self.top.len() > self.n {
+            self.top.pop();
+        }
+        self.elves += 1;
+        Ok(())
+    }
+
+    /// Returns the number of elves added.
+    pub fn elves(&self) -> usize {
+        self.elves
+    }
+
+    /// Returns the total calories carried by all the elves.
+    pub fn sum(&self) -> u64 {
+        self.sum
+    }
+
+    /// Returns the fewest calories any elf carries.
+    pub fn min(&self) -> Result<u64, InventoryError> {
+        self.min.ok_or(InventoryError::Empty)
+    }
+
+    /// Returns the most calories any elf carries.
+    pub fn max(&self) -> Result<u64, InventoryError> {
+        self.max.ok_or(InventoryError::Empty)
+    }
+
+    /// Returns the mean calories carried per elf.
+    pub fn mean(&self) -> Result<f64, InventoryError> {
+        match self.elves {
+            0 => Err(InventoryError::Empty),
+            elves => Ok(self.sum as f64 / elves as f64),
+        }
+    }
+
+    /// Returns the n elves carrying the most calories, most first, as
+    /// Inventory::top_n. n can be no more than the number kept.
+    pub fn top_n(&self, n: usize) -> Result<Vec<ElfTotal>, InventoryError> {
+        if n > self.elves {
+            return Err(InventoryError::TooFewElves {
+                wanted: n,
+                found: self.elves,
+            });
+        }
+        if n > self.n {
+            return Err(InventoryError::NotKept {
+                wanted: n,
+                kept: self.n,
+            });
+        }
+        let mut top: Vec<_> = self
+            .top
+            .iter()
+            .map(|&Reverse((calories, Reverse(index)))| ElfTotal {
+                index,
+                calories,
+            })
+            .collect();
+        top.sort_unstable_by_key(|e| (Reverse(e.calories), e.index));
+        top.truncate(n);
+        Ok(top)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+    use std::io::{BufReader, Read};
+    use std::time::Instant;
+
+    use aoc2022::gen;
+    use aoc2022::utils::file::{get_input_reader, get_str_records};
+    use aoc2022::utils::fuzz::{parser_panics, ANY_TEXT};
+    use aoc2022::YEAR;
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::inventory::Inventory;
+
+    #[test]
+    fn test_elf_totals() {
+        let reader = get_input_reader(YEAR, "example/day01").unwrap();
+        let totals: Vec<_> = elf_totals(reader).map(Result::unwrap).collect();
+        assert_eq!(totals, [6000, 4000, 11000, 24000, 10000]);
+
+        let totals = |s: &str| -> Vec<u64> {
+            elf_totals(s.as_bytes()).map(Result::unwrap).collect()
+        };
+        assert_eq!(totals("1\r\n2\r\n\r\n\n3"), [3, 0, 3]);
+        assert_eq!(totals("\n1\n\n"), [0, 1]);
+        assert!(totals("").is_empty());
+
+        let errs = |s: &str| -> Vec<String> {
+            elf_totals(s.as_bytes())
+                .filter_map(|total| total.err())
+                .map(|e| e.to_string())
+                .collect()
+        };
+        assert_eq!(
+            errs("1\n2\n\n3\nx\n\n4\n"),
+            [format!("line 5: {}", "x".parse::<u64>().unwrap_err())]
+        );
+        assert_eq!(
+            errs(&format!("1\n{}\n", u64::MAX)),
+            ["line 2: sum overflowed"]
+        );
+        assert_eq!(
+            elf_totals(&b"1\n\xff\n"[..])
+                .next()
+                .unwrap()
+                .unwrap_err()
+                .kind(),
+            io::ErrorKind::InvalidData
+        );
+    }
+
+    #[test]
+    fn test_summary() {
+        let reader = get_input_reader(YEAR, "example/day01").unwrap();
+        let summary = Summary::from_totals(elf_totals(reader), 3).unwrap();
+        assert_eq!(summary.elves(), 5);
+        assert_eq!(summary.sum(), 55000);
+        assert_eq!((summary.min(), summary.max()), (Ok(4000), Ok(24000)));
+        assert_eq!(summary.mean(), Ok(11000.0));
+        let top: Vec<_> = summary
+            .top_n(3)
+            .unwrap()
+            .iter()
+            .map(|e| (e.index, e.calories))
+            .collect();
+        assert_eq!(top, [(3, 24000), (2, 11000), (4, 10000)]);
+        assert_eq!(
+            summary.top_n(4),
+            Err(InventoryError::NotKept { wanted: 4, kept: 3 })
+        );
+
+        let mut tied = Summary::new(2);
+        for calories in [5, 7, 7, 5] {
+            tied.add(calories).unwrap();
+        }
+        let indices: Vec<_> =
+            tied.top_n(2).unwrap().iter().map(|e| e.index).collect();
+        assert_eq!(indices, [1, 2]);
+        assert_eq!(tied.add(u64::MAX), Err(InventoryError::Overflow));
+
+        let empty = Summary::new(1);
+        assert_eq!(empty.max(), Err(InventoryError::Empty));
+        assert_eq!(empty.mean(), Err(InventoryError::Empty));
+        assert_eq!(
+            empty.top_n(1),
+            Err(InventoryError::TooFewElves {
+                wanted: 1,
+                found: 0
+            })
+        );
+    }
+
+    #[test]
+    fn test_generated() {
+        gen::for_each_input(1, gen::TEST_SIZES, |input| {
+            let totals = elf_totals(input.as_bytes());
+            let summary = Summary::from_totals(totals, 3).unwrap();
+            let records = get_str_records(input);
+            let inventory = Inventory::from_records(records).unwrap();
+            assert_eq!(summary.elves(), inventory.len());
+            assert_eq!(Ok(summary.sum()), inventory.sum());
+            assert_eq!(summary.top_n(3), inventory.top_n(3));
+            assert_eq!(summary.mean(), inventory.mean());
+
+            let printed = inventory.to_string();
+            let records = get_str_records(&printed);
+            assert_eq!(Inventory::from_records(records).unwrap(), inventory);
+            let totals: Vec<_> =
+                elf_totals(printed.as_bytes()).map(Result::unwrap).collect();
+            assert_eq!(totals, inventory.totals());
+        });
+    }
+
+    /// A reader of the same input over and over.
+    struct Repeat {
+        input: Vec<u8>,
+        times: u64,
+        pos: usize,
+    }
+
+    impl Read for Repeat {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            if self.pos == self.input.len() {
+                if self.times == 0 {
+                    return Ok(0);
+                }
+                self.times -= 1;
+                self.pos = 0;
+            }
+            let n = buf.len().min(self.input.len() - self.pos);
+            buf[..n].copy_from_slice(&self.input[self.pos..self.pos + n]);
+            self.pos += n;
+            Ok(n)
+        }
+    }
+
+    /// Returns the process's peak resident memory in kB, where /proc has it.
+    fn peak_rss_kb() -> Option<u64> {
+        let status = fs::read_to_string("/proc/self/status").ok()?;
+        let line = status.lines().find(|l| l.starts_with("VmHWM:"))?;
+        line.split_whitespace().nth(1)?.parse().ok()
+    }
+
+    /// Streams a generated input of AOC_BENCH_BYTES (default 4 GiB) through
+    /// elf_totals and a Summary, checking the answers and that peak memory
+    /// use doesn't grow with it. Ignored by default; run it alone with:
+    ///
+    /// `cargo test --release -p aoc2022 --bin day01 -- --ignored
+    /// --test-threads=1 --nocapture`
+    #[test]
+    #[ignore]
+    fn bench_flat_memory() {
+        let bytes: u64 = match env::var("AOC_BENCH_BYTES") {
+            Ok(n) => n.parse().unwrap(),
+            Err(_) => 4 << 30,
+        };
+
+        // A blank line after each copy ends its last elf, so every copy gives
+        // the same elves.
+        let mut input = gen::generate(1, 0, 10_000).unwrap().into_bytes();
+        input.push(b'\n');
+        let inventory = Inventory::from_totals(
+            elf_totals(&input[..]).map(Result::unwrap).collect(),
+        );
+        let times = (bytes / input.len() as u64).max(1);
+        let read = times * input.len() as u64;
+
+        let rss_before = peak_rss_kb();
+        let start = Instant::now();
+        let reader = BufReader::new(Repeat {
+            input,
+            times: times - 1,
+            pos: 0,
+        });
+        let summary = Summary::from_totals(elf_totals(reader), 3).unwrap();
+        let elapsed = start.elapsed();
+        let rss_after = peak_rss_kb();
+
+        assert_eq!(summary.elves() as u64, times * inventory.len() as u64);
+        assert_eq!(summary.sum(), times * inventory.sum().unwrap());
+        assert_eq!(
+            summary.top_n(1).unwrap()[0].calories,
+            inventory.top_n(1).unwrap()[0].calories
+        );
+
+        eprintln!(
+            "read {:.2} GiB in {elapsed:.2?} ({:.0} MiB/s)",
+            read as f64 / (1 << 30) as f64,
+            read as f64 / (1 << 20) as f64 / elapsed.as_secs_f64(),
+        );
+        if let (Some(before), Some(after)) = (rss_before, rss_after) {
+            eprintln!("peak RSS {before} kB before, {after} kB after");
+            assert!(
+                after.saturating_sub(before) < 16 << 10,
+                "peak RSS grew by over 16 MiB"
+            );
+        }
+    }
+
+    /// A regex for inputs shaped like calorie lists.
+    const INPUT_LIKE: &str = r"(-?[0-9]{0,21}\n{1,3}){0,20}";
+
+    proptest! {
+        #[test]
+        fn prop_parser_never_panics(
+            input in prop_oneof![ANY_TEXT, INPUT_LIKE],
+        ) {
+            let records =
+                |s: &str| Inventory::from_records(get_str_records(s));
+            prop_assert!(!parser_panics(records, &input));
+            let totals = |s: &str| {
+                elf_totals(s.as_bytes()).collect::<Result<Vec<_>, _>>()
+            };
+            prop_assert!(!parser_panics(totals, &input));
+        }
+    }
+}
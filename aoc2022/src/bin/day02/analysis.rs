@@ -0,0 +1,332 @@
+use std::cmp::Reverse;
+use std::fmt;
+use std::io;
+
+use aoc2022::utils::error::invalid_data_err;
+use itertools::Itertools;
+
+use crate::dt::{split_line, unrecognised, Game, Move, MoveType, ResultType};
+
+/// A GuideRound is a line of a strategy guide before deciding what our letter
+/// means: the opponent's move, and our letter as written.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct GuideRound {
+    pub opp_played: MoveType,
+    pub letter: char,
+}
+
+/// A Reading is what our letters in a strategy guide are taken to mean.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Reading {
+    /// Each of the game's letters for our moves stands for the move at the
+    /// same position here.
+    Moves(Vec<MoveType>),
+    /// Our letters stand for the result we want.
+    Results,
+}
+
+impl Reading {
+    /// Describes the Reading, such as "X=rock Y=paper Z=scissors".
+    pub fn describe(&self, game: &Game) -> String {
+        match self {
+            Reading::Moves(moves) => game
+                .our_letters()
+                .iter()
+                .zip(moves)
+                .map(|(c, &m)| format!("{c}={}", game.name(m)))
+                .join(" "),
+            Reading::Results => "letters as results".to_string(),
+        }
+    }
+}
+
+/// The Outcome of one round of a strategy guide, under some Reading.
+#[derive(Debug, Eq, PartialEq)]
+pub struct Outcome {
+    pub played: Move,
+    pub result: ResultType,
+    pub score: u64,
+}
+
+/// A Tally counts the results of a strategy guide and adds up its score.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Tally {
+    pub wins: usize,
+    pub draws: usize,
+    pub losses: usize,
+    pub score: u64,
+}
+
+impl Tally {
+    pub fn of<'a>(outcomes: impl IntoIterator<Item = &'a Outcome>) -> Self {
+        let mut tally = Tally::default();
+        for o in outcomes {
+            match o.result {
+                ResultType::Win => tally.wins += 1,
+                ResultType::Draw => tally.draws += 1,
+                ResultType::Loss => tally.losses += 1,
+            }
+            tally.score += o.score;
+        }
+        tally
+    }
+}
+
+impl fmt::Display for Tally {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "score {} ({} wins, {} draws, {} losses)",
+            self.score, self.wins, self.draws, self.losses
+        )
+    }
+}
+
+/// Parses the lines of a strategy guide, checking the opponent's letters but
+/// leaving ours to be read later.
+pub fn parse_guide(
+    game: &Game,
+    lines: impl Iterator<Item = Result<String, io::Error>>,
+) -> Result<Vec<GuideRound>, io::Error> {
+    lines
+        .map(|line| {
+            let (opp, letter) = split_line(&line?)?;
+            Ok(GuideRound {
+                opp_played: game
+                    .opp_move(opp)
+                    .ok_or_else(|| unrecognised(opp))?,
+                letter,
+            })
+        })
+        .collect()
+}
+
+/// Plays a strategy guide under a Reading, returning each round's Outcome.
+pub fn play(
+    game: &Game,
+    rounds: &[GuideRound],
+    reading: &Reading,
+) -> Result<Vec<Outcome>, io::Error> {
+    if let Reading::Moves(moves) = reading {
+        if moves.len() != game.len() {
+            return Err(invalid_data_err(&format!(
+                "a reading needs {} moves, got {}",
+                game.len(),
+                moves.len()
+            )));
+        }
+    }
+
+    rounds
+        .iter()
+        .enumerate()
+        .map(|(i, r)| {
+            let played = match reading {
+                Reading::Moves(moves) => {
+                    game.our_move(r.letter).map(|m| Move {
+                        opp_played: r.opp_played,
+                        we_played: moves[m.0],
+                    })
+                },
+                Reading::Results => game
+                    .wanted(r.letter)
+                    .map(|wanted| game.resolve(r.opp_played, wanted)),
+            };
+            let played = played.ok_or_else(|| {
+                invalid_data_err(&format!(
+                    "round {}: {}",
+                    i + 1,
+                    unrecognised(r.letter)
+                ))
+            })?;
+            Ok(outcome(game, played))
+        })
+        .collect()
+}
+
+fn outcome(game: &Game, played: Move) -> Outcome {
+    Outcome {
+        result: game.get_result(&played),
+        score: game.score_move(&played),
+        played,
+    }
+}
+
+/// Returns every Reading of our letters as moves: one per permutation of the
+/// game's moves.
+pub fn move_readings(game: &Game) -> impl Iterator<Item = Reading> {
+    game.moves().permutations(game.len()).map(Reading::Moves)
+}
+
+/// Tallies a strategy guide under every Reading of our letters as moves.
+pub fn tally_move_readings(
+    game: &Game,
+    rounds: &[GuideRound],
+) -> Result<Vec<(Reading, Tally)>, io::Error> {
+    move_readings(game)
+        .map(|reading| {
+            let tally = Tally::of(&play(game, rounds, &reading)?);
+            Ok((reading, tally))
+        })
+        .collect()
+}
+
+/// Returns the Reading of our letters as moves scoring most, and its Tally.
+/// Of readings scoring the same, the first permutation is chosen.
+pub fn best_move_reading(
+    game: &Game,
+    rounds: &[GuideRound],
+) -> Result<(Reading, Tally), io::Error> {
+    let readings = tally_move_readings(game, rounds)?;
+    let best = readings
+        .into_iter()
+        .rev()
+        .max_by_key(|(_, tally)| tally.score)
+        .expect("a game has at least one reading");
+    Ok(best)
+}
+
+/// Returns the Outcomes of ignoring our letters and playing the best move
+/// each round, knowing the opponent's: the highest score possible.
+pub fn optimal_play(game: &Game, rounds: &[GuideRound]) -> Vec<Outcome> {
+    play_by(game, rounds, |score| score)
+}
+
+/// Returns the Outcomes of ignoring our letters and playing the worst move
+/// each round, as if an adversary chose for us: the lowest score possible.
+pub fn adversarial_play(game: &Game, rounds: &[GuideRound]) -> Vec<Outcome> {
+    play_by(game, rounds, |score| u64::MAX - score)
+}
+
+/// Plays the move maximising key(score) each round, the first in the cycle if
+/// several do.
+fn play_by(
+    game: &Game,
+    rounds: &[GuideRound],
+    key: impl Fn(u64) -> u64,
+) -> Vec<Outcome> {
+    rounds
+        .iter()
+        .map(|r| {
+            let best = game
+                .moves()
+                .map(|we_played| {
+                    outcome(
+                        game,
+                        Move {
+                            opp_played: r.opp_played,
+                            we_played,
+                        },
+                    )
+                })
+                .min_by_key(|o| Reverse(key(o.score)));
+            best.expect("a game has at least one move")
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc2022::gen;
+    use aoc2022::utils::file::{get_input_lines, get_str_lines};
+    use aoc2022::utils::fuzz::{parser_panics, ANY_TEXT};
+    use aoc2022::YEAR;
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn example() -> (Game, Vec<GuideRound>) {
+        let game = Game::rock_paper_scissors();
+        let lines = get_input_lines(YEAR, "example/day02").unwrap();
+        let rounds = parse_guide(&game, lines).unwrap();
+        (game, rounds)
+    }
+
+    #[test]
+    fn test_play() {
+        let (game, rounds) = example();
+        let literal = Reading::Moves(game.moves().collect());
+        let outcomes = play(&game, &rounds, &literal).unwrap();
+        let breakdown: Vec<_> =
+            outcomes.iter().map(|o| (o.result, o.score)).collect();
+        assert_eq!(
+            breakdown,
+            [
+                (ResultType::Win, 8),
+                (ResultType::Loss, 1),
+                (ResultType::Draw, 6)
+            ]
+        );
+        assert_eq!(
+            Tally::of(&outcomes).to_string(),
+            "score 15 (1 wins, 1 draws, 1 losses)"
+        );
+
+        let outcomes = play(&game, &rounds, &Reading::Results).unwrap();
+        assert_eq!(Tally::of(&outcomes).score, 12);
+        assert!(outcomes.iter().all(|o| o.played.we_played == MoveType(0)));
+
+        let short = Reading::Moves(vec![MoveType(0)]);
+        assert!(play(&game, &rounds, &short).is_err());
+        let bad = parse_guide(&game, get_str_lines("A W\n")).unwrap();
+        assert_eq!(
+            play(&game, &bad, &literal).unwrap_err().to_string(),
+            "round 1: unrecognised char W"
+        );
+        assert!(parse_guide(&game, get_str_lines("D X\n")).is_err());
+    }
+
+    #[test]
+    fn test_move_readings() {
+        let (game, rounds) = example();
+        let tallies = tally_move_readings(&game, &rounds).unwrap();
+        let scores: Vec<_> = tallies.iter().map(|(_, t)| t.score).collect();
+        assert_eq!(scores, [15, 6, 15, 15, 15, 24]);
+
+        let (reading, tally) = best_move_reading(&game, &rounds).unwrap();
+        assert_eq!(
+            reading,
+            Reading::Moves(vec![MoveType(2), MoveType(1), MoveType(0)])
+        );
+        assert_eq!(tally.wins, 3);
+        assert_eq!(reading.describe(&game), "X=scissors Y=paper Z=rock");
+    }
+
+    #[test]
+    fn test_optimal_and_adversarial() {
+        let (game, rounds) = example();
+        let best = Tally::of(&optimal_play(&game, &rounds));
+        assert_eq!((best.score, best.wins), (24, 3));
+        let worst = Tally::of(&adversarial_play(&game, &rounds));
+        assert_eq!((worst.score, worst.losses), (6, 3));
+    }
+
+    #[test]
+    fn test_generated() {
+        let game = Game::rock_paper_scissors();
+        gen::for_each_input(2, gen::TEST_SIZES, |input| {
+            let rounds = parse_guide(&game, get_str_lines(input)).unwrap();
+            let best = Tally::of(&optimal_play(&game, &rounds)).score;
+            let worst = Tally::of(&adversarial_play(&game, &rounds)).score;
+            let results = play(&game, &rounds, &Reading::Results).unwrap();
+            for (_, tally) in tally_move_readings(&game, &rounds).unwrap() {
+                assert!((worst..=best).contains(&tally.score));
+            }
+            assert!((worst..=best).contains(&Tally::of(&results).score));
+        });
+    }
+
+    /// A regex for inputs shaped like strategy guides.
+    const INPUT_LIKE: &str = r"([A-DX-Z]{0,2} {0,2}[A-DX-Z]{0,2}\n){0,10}";
+
+    proptest! {
+        #[test]
+        fn prop_parser_never_panics(
+            input in prop_oneof![ANY_TEXT, INPUT_LIKE],
+        ) {
+            let game = Game::rock_paper_scissors();
+            let guide = |s: &str| parse_guide(&game, get_str_lines(s));
+            prop_assert!(!parser_panics(guide, &input));
+        }
+    }
+}
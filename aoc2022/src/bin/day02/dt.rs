@@ -0,0 +1,541 @@
+use std::cmp::Reverse;
+use std::collections::HashSet;
+use std::{error, fmt, io};
+
+use aoc2022::utils::{error::invalid_data_err, file::get_input_lines};
+use aoc2022::YEAR;
+
+/// A MoveType is one of a Game's moves, by its position in the game's cycle.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct MoveType(pub usize);
+
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum ResultType {
+    Loss,
+    Draw,
+    Win,
+}
+
+impl ResultType {
+    pub const ALL: [ResultType; 3] =
+        [ResultType::Loss, ResultType::Draw, ResultType::Win];
+
+    /// Returns where this result's entry is in arrays of one per result,
+    /// which are ordered loss, draw, win.
+    pub fn index(self) -> usize {
+        match self {
+            ResultType::Loss => 0,
+            ResultType::Draw => 1,
+            ResultType::Win => 2,
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct Move {
+    pub opp_played: MoveType,
+    pub we_played: MoveType,
+}
+
+/// A Game is a cyclic-dominance game like rock-paper-scissors, of an odd number
+/// of moves arranged in a cycle. Each move beats the half of the other moves
+/// before it in the cycle, and loses to the half after it: in rock, paper,
+/// scissors, paper beats rock, and rock beats scissors.
+///
+/// Each line of a strategy guide is an opponent's letter, a space, and one of
+/// ours, which stands either for a move or for the result we want.
+#[derive(Clone, Debug)]
+pub struct Game {
+    names: Vec<String>,
+    move_scores: Vec<u64>,
+    /// Scores for a loss, draw and win, in that order.
+    result_scores: [u64; 3],
+    opp_letters: Vec<char>,
+    our_letters: Vec<char>,
+    /// Letters for wanting a loss, draw and win, in that order.
+    result_letters: [char; 3],
+}
+
+/// A GameError describes why a Game can't be set up as asked.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum GameError {
+    /// Games need an odd number of moves, so every pair has a winner, and
+    /// at least three, so every result is possible. There are only letters
+    /// for up to 25.
+    MoveCount(usize),
+    /// A list given for each move was the wrong length.
+    WrongLength {
+        what: &'static str,
+        expected: usize,
+        got: usize,
+    },
+    /// The same letter was given for two moves or results.
+    DuplicateLetter(char),
+}
+
+impl fmt::Display for GameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MoveCount(n) => write!(
+                f,
+                "a game needs an odd number of moves from 3 to 25, not {n}"
+            ),
+            Self::WrongLength {
+                what,
+                expected,
+                got,
+            } => write!(f, "expected {expected} {what}, got {got}"),
+            Self::DuplicateLetter(c) => write!(f, "letter {c:?} used twice"),
+        }
+    }
+}
+
+impl error::Error for GameError {}
+
+impl Game {
+    /// Creates a Game with the given moves in cycle order. Each move scores
+    /// its 1-based position, a loss 0, a draw 3 and a win 6. The opponent's
+    /// letters run from A, ours end at Z, and X, Y and Z want a loss, draw and
+    /// win.
+    pub fn new(names: &[&str]) -> Result<Self, GameError> {
+        let n = names.len();
+        if n % 2 != 1 || !(3..=25).contains(&n) {
+            return Err(GameError::MoveCount(n));
+        }
+        let letters = |first: u8| (first..first + n as u8).map(char::from);
+        Ok(Game {
+            names: names.iter().map(|s| s.to_string()).collect(),
+            move_scores: (1..=n as u64).collect(),
+            result_scores: [0, 3, 6],
+            opp_letters: letters(b'A').collect(),
+            our_letters: letters(b'Z' + 1 - n as u8).collect(),
+            result_letters: ['X', 'Y', 'Z'],
+        })
+    }
+
+    /// Creates the Game of the puzzle: rock, paper, scissors, with the
+    /// puzzle's letters and scores.
+    pub fn rock_paper_scissors() -> Self {
+        Self::new(&["rock", "paper", "scissors"])
+            .and_then(|game| game.with_letters("ABC", "XYZ"))
+            .and_then(|game| game.with_result_letters(['X', 'Y', 'Z']))
+            .and_then(|game| game.with_scores(&[1, 2, 3], [0, 3, 6]))
+            .unwrap()
+    }
+
+    /// Sets the letters standing for each move, for the opponent and for us.
+    pub fn with_letters(
+        mut self,
+        opp: &str,
+        ours: &str,
+    ) -> Result<Self, GameError> {
+        self.opp_letters = self.move_letters("opponent letters", opp)?;
+        self.our_letters = self.move_letters("letters", ours)?;
+        Ok(self)
+    }
+
+    /// Sets the letters standing for wanting a loss, draw and win.
+    pub fn with_result_letters(
+        mut self,
+        letters: [char; 3],
+    ) -> Result<Self, GameError> {
+        check_unique(letters.iter())?;
+        self.result_letters = letters;
+        Ok(self)
+    }
+
+    /// Sets the score for playing each move, and for a loss, draw and win.
+    pub fn with_scores(
+        mut self,
+        move_scores: &[u64],
+        result_scores: [u64; 3],
+    ) -> Result<Self, GameError> {
+        if move_scores.len() != self.len() {
+            return Err(GameError::WrongLength {
+                what: "move scores",
+                expected: self.len(),
+                got: move_scores.len(),
+            });
+        }
+        self.move_scores = move_scores.to_vec();
+        self.result_scores = result_scores;
+        Ok(self)
+    }
+
+    fn move_letters(
+        &self,
+        what: &'static str,
+        letters: &str,
+    ) -> Result<Vec<char>, GameError> {
+        let letters: Vec<char> = letters.chars().collect();
+        if letters.len() != self.len() {
+            return Err(GameError::WrongLength {
+                what,
+                expected: self.len(),
+                got: letters.len(),
+            });
+        }
+        check_unique(letters.iter())?;
+        Ok(letters)
+    }
+
+    /// Returns the number of moves.
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    /// Returns every move, in cycle order.
+    pub fn moves(&self) -> impl Iterator<Item = MoveType> {
+        (0..self.len()).map(MoveType)
+    }
+
+    /// Returns the result of a round for us.
+    pub fn get_result(&self, m: &Move) -> ResultType {
+        let n = self.len();
+        match (m.we_played.0 + n - m.opp_played.0) % n {
+            0 => ResultType::Draw,
+            d if d <= n / 2 => ResultType::Win,
+            _ => ResultType::Loss,
+        }
+    }
+
+    /// Returns our score for a round: that for our move plus that for the
+    /// result.
+    pub fn score_move(&self, m: &Move) -> u64 {
+        self.move_scores[m.we_played.0]
+            + self.result_scores[self.get_result(m).index()]
+    }
+
+    /// Returns the move we should play against opp_played for the wanted
+    /// result. If several moves give it, the one scoring most is chosen, or
+    /// of those the first in the cycle.
+    pub fn resolve(&self, opp_played: MoveType, wanted: ResultType) -> Move {
+        let we_played = self
+            .moves()
+            .map(|we_played| Move {
+                opp_played,
+                we_played,
+            })
+            .filter(|m| self.get_result(m) == wanted)
+            .min_by_key(|m| Reverse(self.move_scores[m.we_played.0]))
+            .expect("every result is possible")
+            .we_played;
+        Move {
+            opp_played,
+            we_played,
+        }
+    }
+
+    /// Returns the name of a move.
+    pub fn name(&self, m: MoveType) -> &str {
+        &self.names[m.0]
+    }
+
+    /// Returns the letters standing for our moves, in cycle order.
+    pub fn our_letters(&self) -> &[char] {
+        &self.our_letters
+    }
+
+    /// Returns the move the opponent's letter c stands for.
+    pub fn opp_move(&self, c: char) -> Option<MoveType> {
+        self.opp_letters.iter().position(|&l| l == c).map(MoveType)
+    }
+
+    /// Returns the move our letter c stands for.
+    pub fn our_move(&self, c: char) -> Option<MoveType> {
+        self.our_letters.iter().position(|&l| l == c).map(MoveType)
+    }
+
+    /// Returns the result our letter c wants.
+    pub fn wanted(&self, c: char) -> Option<ResultType> {
+        let i = self.result_letters.iter().position(|&l| l == c)?;
+        Some(ResultType::ALL[i])
+    }
+
+    /// Parses a line of a strategy guide as the opponent's move and ours.
+    pub fn parse_move(&self, line: &str) -> Result<Move, io::Error> {
+        let (opp, ours) = split_line(line)?;
+        Ok(Move {
+            opp_played: self.opp_move(opp).ok_or_else(|| unrecognised(opp))?,
+            we_played: self.our_move(ours).ok_or_else(|| unrecognised(ours))?,
+        })
+    }
+
+    /// Parses a line of a strategy guide as the opponent's move and the result
+    /// we want.
+    pub fn parse_wanted(
+        &self,
+        line: &str,
+    ) -> Result<(MoveType, ResultType), io::Error> {
+        let (opp, ours) = split_line(line)?;
+        Ok((
+            self.opp_move(opp).ok_or_else(|| unrecognised(opp))?,
+            self.wanted(ours).ok_or_else(|| unrecognised(ours))?,
+        ))
+    }
+
+    pub fn parse_moves_from_file(
+        &self,
+        from: &str,
+    ) -> Result<impl Iterator<Item = Result<Move, io::Error>> + '_, io::Error>
+    {
+        let lines = get_input_lines(YEAR, from)?;
+        Ok(lines.map(|l| self.parse_move(&l?)))
+    }
+
+    pub fn parse_wanted_from_file(
+        &self,
+        from: &str,
+    ) -> Result<
+        impl Iterator<Item = Result<(MoveType, ResultType), io::Error>> + '_,
+        io::Error,
+    > {
+        let lines = get_input_lines(YEAR, from)?;
+        Ok(lines.map(|l| self.parse_wanted(&l?)))
+    }
+}
+
+fn check_unique<'a>(
+    letters: impl Iterator<Item = &'a char>,
+) -> Result<(), GameError> {
+    let mut seen = HashSet::new();
+    for &c in letters {
+        if !seen.insert(c) {
+            return Err(GameError::DuplicateLetter(c));
+        }
+    }
+    Ok(())
+}
+
+/// Splits a line of a strategy guide into the opponent's letter and ours.
+pub fn split_line(line: &str) -> Result<(char, char), io::Error> {
+    let chars: Vec<char> = line.chars().collect();
+    match chars[..] {
+        [opp, ' ', ours] => Ok((opp, ours)),
+        _ => Err(invalid_data_err(&format!(
+            "expected a letter, a space and a letter: got {line:?}"
+        ))),
+    }
+}
+
+pub fn unrecognised(c: char) -> io::Error {
+    invalid_data_err(&format!("unrecognised char {}", c))
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc2022::gen;
+    use aoc2022::utils::fuzz::{parser_panics, ANY_TEXT};
+    use proptest::prelude::*;
+
+    use super::*;
+
+    const ROCK: MoveType = MoveType(0);
+    const PAPER: MoveType = MoveType(1);
+    const SCISSORS: MoveType = MoveType(2);
+
+    fn rpsls() -> Game {
+        Game::new(&["rock", "spock", "paper", "lizard", "scissors"]).unwrap()
+    }
+
+    #[test]
+    fn test_parse_moves() {
+        let game = Game::rock_paper_scissors();
+        let moves: Vec<_> = game
+            .parse_moves_from_file("example/day02")
+            .unwrap()
+            .map(Result::unwrap)
+            .collect();
+        let rounds = [(ROCK, PAPER), (PAPER, ROCK), (SCISSORS, SCISSORS)];
+        assert_eq!(
+            moves,
+            rounds.map(|(opp_played, we_played)| Move {
+                opp_played,
+                we_played
+            })
+        );
+
+        for bad in ["A", "A  X", "AX ", "D X", "A W"] {
+            assert!(game.parse_move(bad).is_err(), "{bad:?}");
+        }
+    }
+
+    #[test]
+    fn test_score_moves() {
+        let game = Game::rock_paper_scissors();
+        let mut moves = game.parse_moves_from_file("example/day02").unwrap();
+        assert_eq!(game.score_move(&moves.next().unwrap().unwrap()), 8);
+        assert_eq!(game.score_move(&moves.next().unwrap().unwrap()), 1);
+        assert_eq!(game.score_move(&moves.next().unwrap().unwrap()), 6);
+        assert!(&moves.next().is_none());
+    }
+
+    #[test]
+    fn test_score_all_moves() {
+        let game = Game::rock_paper_scissors();
+        let moves = game.parse_moves_from_file("example/day02").unwrap();
+        let res: Result<u64, io::Error> = moves
+            .map(|m| -> Result<u64, io::Error> { Ok(game.score_move(&m?)) })
+            .sum();
+        assert_eq!(res.unwrap(), 15);
+
+        // Each result scores its own entry, in loss, draw, win order.
+        let game = game.with_scores(&[1, 2, 3], [10, 20, 30]).unwrap();
+        for (i, &result) in ResultType::ALL.iter().enumerate() {
+            assert_eq!(result.index(), i);
+        }
+        let m = game.resolve(MoveType(0), ResultType::Loss);
+        assert_eq!(game.score_move(&m), 10 + 3);
+    }
+
+    #[test]
+    fn test_cycle() {
+        for game in [Game::rock_paper_scissors(), rpsls()] {
+            for opp_played in game.moves() {
+                let results: Vec<_> = game
+                    .moves()
+                    .map(|we_played| {
+                        game.get_result(&Move {
+                            opp_played,
+                            we_played,
+                        })
+                    })
+                    .collect();
+                let count = |r| results.iter().filter(|&&x| x == r).count();
+                assert_eq!(count(ResultType::Draw), 1);
+                assert_eq!(count(ResultType::Win), game.len() / 2);
+                assert_eq!(count(ResultType::Loss), game.len() / 2);
+            }
+        }
+
+        let game = rpsls();
+        let beats = |ours: &str, theirs: &str| {
+            let find = |name| game.moves().find(|&m| game.name(m) == name);
+            let m = Move {
+                opp_played: find(theirs).unwrap(),
+                we_played: find(ours).unwrap(),
+            };
+            game.get_result(&m) == ResultType::Win
+        };
+        assert!(beats("spock", "scissors"));
+        assert!(beats("lizard", "spock"));
+        assert!(beats("rock", "lizard"));
+        assert!(!beats("paper", "lizard"));
+    }
+
+    #[test]
+    fn test_resolve() {
+        let game = Game::rock_paper_scissors();
+        let wanted: Vec<_> = game
+            .parse_wanted_from_file("example/day02")
+            .unwrap()
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(
+            wanted,
+            [
+                (ROCK, ResultType::Draw),
+                (PAPER, ResultType::Loss),
+                (SCISSORS, ResultType::Win)
+            ]
+        );
+        let played: Vec<_> = wanted
+            .iter()
+            .map(|&(opp, result)| game.resolve(opp, result).we_played)
+            .collect();
+        assert_eq!(played, [ROCK, ROCK, ROCK]);
+
+        // Of the two moves beating rock, paper scores more.
+        let game = rpsls();
+        assert_eq!(game.resolve(ROCK, ResultType::Win).we_played, MoveType(2));
+        let game = game.with_scores(&[1, 5, 1, 1, 1], [0, 3, 6]).unwrap();
+        assert_eq!(game.resolve(ROCK, ResultType::Win).we_played, MoveType(1));
+    }
+
+    #[test]
+    fn test_config() {
+        assert_eq!(
+            Game::new(&["a", "b"]).unwrap_err(),
+            GameError::MoveCount(2)
+        );
+        assert!(Game::new(&["a"]).is_err());
+
+        let game = rpsls();
+        let m = game.parse_move("A Z").unwrap();
+        assert_eq!(m.we_played, MoveType(4));
+        assert!(game.parse_move("F V").is_err());
+        assert_eq!(game.parse_move("E V").unwrap().we_played, MoveType(0));
+
+        let game = game.with_letters("RKPLS", "rkpls").unwrap();
+        let m = game.parse_move("K s").unwrap();
+        assert_eq!(game.name(m.opp_played), "spock");
+        assert_eq!(game.name(m.we_played), "scissors");
+        assert_eq!(game.score_move(&m), 5);
+
+        let game = game.with_result_letters(['l', 'd', 'w']).unwrap();
+        assert_eq!(game.parse_wanted("R w").unwrap(), (ROCK, ResultType::Win));
+
+        assert_eq!(
+            rpsls()
+                .with_letters("ABC", "VWXYZ")
+                .unwrap_err()
+                .to_string(),
+            "expected 5 opponent letters, got 3"
+        );
+        assert_eq!(
+            rpsls().with_letters("ABCDE", "VWXYV").unwrap_err(),
+            GameError::DuplicateLetter('V')
+        );
+        assert!(rpsls().with_scores(&[1, 2, 3], [0, 3, 6]).is_err());
+    }
+
+    #[test]
+    fn test_generated() {
+        let game = Game::rock_paper_scissors();
+        gen::for_each_input(2, gen::TEST_SIZES, |input| {
+            for line in input.lines() {
+                // Both readings of the line write back to it.
+                let m = game.parse_move(line).unwrap();
+                let opp = game.opp_letters[m.opp_played.0];
+                let ours = game.our_letters[m.we_played.0];
+                assert_eq!(format!("{opp} {ours}"), line);
+                assert!((1..=9).contains(&game.score_move(&m)), "{line}");
+
+                let (opp, wanted) = game.parse_wanted(line).unwrap();
+                let letters = (
+                    game.opp_letters[opp.0],
+                    game.result_letters[wanted.index()],
+                );
+                assert_eq!(format!("{} {}", letters.0, letters.1), line);
+                let m = game.resolve(opp, wanted);
+                assert_eq!(game.get_result(&m), wanted, "{line}");
+            }
+        });
+    }
+
+    /// A regex for inputs shaped like strategy guides, with letters from
+    /// either game.
+    const INPUT_LIKE: &str = r"([A-EV-Z]{0,2} {0,2}[A-EV-Z]{0,2}\n){0,10}";
+
+    proptest! {
+        #[test]
+        fn prop_parser_never_panics(
+            input in prop_oneof![ANY_TEXT, INPUT_LIKE],
+        ) {
+            for game in [Game::rock_paper_scissors(), rpsls()] {
+                let moves = |s: &str| {
+                    s.lines()
+                        .map(|l| game.parse_move(l))
+                        .collect::<Result<Vec<_>, _>>()
+                };
+                prop_assert!(!parser_panics(moves, &input));
+                let wanted = |s: &str| {
+                    s.lines()
+                        .map(|l| game.parse_wanted(l))
+                        .collect::<Result<Vec<_>, _>>()
+                };
+                prop_assert!(!parser_panics(wanted, &input));
+            }
+        }
+    }
+}
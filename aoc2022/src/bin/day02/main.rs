@@ -0,0 +1,63 @@
+mod analysis;
+mod dt;
+mod part1;
+mod part2;
+
+use std::error::Error;
+
+use aoc2022::utils::file::get_input_lines;
+use aoc2022::utils::report::{stats_wanted, Report};
+use aoc2022::YEAR;
+
+use analysis::{Reading, Tally};
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let mut report = Report::new(YEAR, 2)?;
+
+    // Each line is of the form [ABC] [XYZ]. A/X beats B/Y beats C/Z beats A/X.
+    let game = dt::Game::rock_paper_scissors();
+    let moves = game.parse_moves_from_file("day02")?;
+    report.try_part(1, || part1::score_all_moves(&game, moves))?;
+
+    let wanted = game.parse_wanted_from_file("day02")?;
+    report.try_part(2, || {
+        part1::score_all_moves(&game, part2::resolve_moves(&game, wanted))
+    })?;
+
+    print_analysis(&game)?;
+    Ok(())
+}
+
+/// Writes to stderr how the guide would score under other readings, and
+/// at best and worst. With AOC_STATS, also writes each round of the readings
+/// the puzzle uses.
+fn print_analysis(game: &dt::Game) -> Result<(), Box<dyn Error>> {
+    let rounds = analysis::parse_guide(game, get_input_lines(YEAR, "day02")?)?;
+
+    let (best, tally) = analysis::best_move_reading(game, &rounds)?;
+    eprintln!("best reading ({}): {tally}", best.describe(game));
+    let optimal = Tally::of(&analysis::optimal_play(game, &rounds));
+    eprintln!("optimal play: {optimal}");
+    let adversarial = Tally::of(&analysis::adversarial_play(game, &rounds));
+    eprintln!("adversarial play: {adversarial}");
+
+    if !stats_wanted() {
+        return Ok(());
+    }
+    let literal = Reading::Moves(game.moves().collect());
+    for reading in [literal, Reading::Results] {
+        eprintln!("{}:", reading.describe(game));
+        let outcomes = analysis::play(game, &rounds, &reading)?;
+        for (i, o) in outcomes.iter().enumerate() {
+            eprintln!(
+                "  round {}: {} vs {}: {:?}, scoring {}",
+                i + 1,
+                game.name(o.played.we_played),
+                game.name(o.played.opp_played),
+                o.result,
+                o.score
+            );
+        }
+    }
+    Ok(())
+}
@@ -2,9 +2,10 @@ use crate::dt;
 use std::io;
 
 pub fn score_all_moves(
+    game: &dt::Game,
     moves: impl Iterator<Item = Result<dt::Move, io::Error>>,
 ) -> Result<u64, io::Error> {
     moves
-        .map(|m| -> Result<u64, io::Error> { Ok(dt::score_move(&m?)) })
+        .map(|m| -> Result<u64, io::Error> { Ok(game.score_move(&m?)) })
         .sum()
 }
@@ -0,0 +1,13 @@
+use crate::dt;
+use std::io;
+
+pub fn resolve_moves<'a>(
+    game: &'a dt::Game,
+    wanted: impl Iterator<Item = Result<(dt::MoveType, dt::ResultType), io::Error>>
+        + 'a,
+) -> impl Iterator<Item = Result<dt::Move, io::Error>> + 'a {
+    wanted.map(|w| -> Result<dt::Move, io::Error> {
+        let (opp_played, result) = w?;
+        Ok(game.resolve(opp_played, result))
+    })
+}
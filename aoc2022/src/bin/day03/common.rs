@@ -0,0 +1,221 @@
+use std::fmt;
+use std::io;
+use std::ops::{BitAnd, BitOr};
+
+use aoc2022::utils::error::invalid_data_err;
+
+/// The item types, in priority order.
+pub const ITEMS: &[u8; 52] =
+    b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Returns an item's priority, from 1 for a to 52 for Z, or None if it's not
+/// an item.
+pub fn priority(c: u8) -> Option<u8> {
+    match c {
+        b'a'..=b'z' => Some(c - b'a' + 1),
+        b'A'..=b'Z' => Some(c - b'A' + 27),
+        _ => None,
+    }
+}
+
+/// An ItemSet is a set of item types, held as a bitmask with bit p - 1 set for
+/// the item of priority p.
+#[derive(Clone, Copy, Default, Eq, Hash, PartialEq)]
+pub struct ItemSet(u64);
+
+impl ItemSet {
+    pub const EMPTY: ItemSet = ItemSet(0);
+    pub const ALL: ItemSet = ItemSet((1 << ITEMS.len()) - 1);
+
+    /// Returns the set of the items in s, or an error naming the first
+    /// character that isn't one.
+    pub fn parse(s: &str) -> Result<Self, io::Error> {
+        let mut set = ItemSet::EMPTY;
+        for c in s.chars() {
+            if !c.is_ascii() || !set.insert(c as u8) {
+                return Err(invalid_data_err(&format!(
+                    "unrecognised item {c:?}"
+                )));
+            }
+        }
+        Ok(set)
+    }
+
+    /// Adds an item to the set, returning false if it isn't an item.
+    pub fn insert(&mut self, item: u8) -> bool {
+        match priority(item) {
+            Some(p) => {
+                self.0 |= 1 << (p - 1);
+                true
+            },
+            None => false,
+        }
+    }
+
+    pub fn intersection(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 & other.0)
+    }
+
+    pub fn union(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 | other.0)
+    }
+
+    /// Returns the number of item types in the set.
+    pub fn len(self) -> u32 {
+        self.0.count_ones()
+    }
+
+    /// Returns the items in the set, in priority order.
+    pub fn iter(self) -> impl Iterator<Item = u8> {
+        ITEMS
+            .iter()
+            .enumerate()
+            .filter(move |(i, _)| self.0 & (1 << i) != 0)
+            .map(|(_, &item)| item)
+    }
+
+    /// Returns the sum of the priorities of the items in the set.
+    pub fn priority_sum(self) -> u64 {
+        (0..ITEMS.len() as u64)
+            .filter(|i| self.0 & (1 << i) != 0)
+            .map(|i| i + 1)
+            .sum()
+    }
+
+    /// Returns the set's only item, or an error saying how many it has.
+    pub fn only(self) -> Result<u8, String> {
+        match self.len() {
+            1 => Ok(ITEMS[self.0.trailing_zeros() as usize]),
+            n => Err(format!("expected exactly one common item, found {n}")),
+        }
+    }
+}
+
+impl BitAnd for ItemSet {
+    type Output = ItemSet;
+
+    fn bitand(self, rhs: ItemSet) -> ItemSet {
+        self.intersection(rhs)
+    }
+}
+
+impl BitOr for ItemSet {
+    type Output = ItemSet;
+
+    fn bitor(self, rhs: ItemSet) -> ItemSet {
+        self.union(rhs)
+    }
+}
+
+impl fmt::Debug for ItemSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let items: String = self.iter().map(char::from).collect();
+        write!(f, "ItemSet({items:?})")
+    }
+}
+
+/// Splits a rucksack into its two compartments' items, or returns an error if
+/// it holds anything but items or can't be split evenly.
+pub fn compartments(line: &str) -> Result<(&str, &str), io::Error> {
+    // Items are ASCII, so once they're checked the middle byte is between
+    // characters.
+    ItemSet::parse(line)?;
+    if line.len() % 2 == 1 {
+        return Err(invalid_data_err(&format!(
+            "rucksack has an odd number of items: {line:?}"
+        )));
+    }
+    Ok(line.split_at(line.len() / 2))
+}
+
+/// Returns the error for a problem on the given line, numbered from 0.
+pub fn line_err(i: usize, e: impl fmt::Display) -> io::Error {
+    invalid_data_err(&format!("line {}: {e}", i + 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc2022::gen;
+    use aoc2022::utils::fuzz::{parser_panics, ANY_TEXT};
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_priority() {
+        assert_eq!(priority(b'a'), Some(1));
+        assert_eq!(priority(b'z'), Some(26));
+        assert_eq!(priority(b'A'), Some(27));
+        assert_eq!(priority(b'Z'), Some(52));
+        assert_eq!(priority(b'!'), None);
+    }
+
+    #[test]
+    fn test_item_set() {
+        let a = ItemSet::parse("vJrwpWtwJgWr").unwrap();
+        let b = ItemSet::parse("hcsFMMfFFhFp").unwrap();
+        assert_eq!((a & b).only(), Ok(b'p'));
+        assert_eq!((a & b).priority_sum(), 16);
+        assert_eq!(a.len(), 8);
+        assert_eq!(a.iter().collect::<Vec<_>>(), b"gprtvwJW");
+        assert_eq!((a | b).len(), 14);
+        assert_eq!(format!("{:?}", a & b), "ItemSet(\"p\")");
+
+        assert_eq!(ItemSet::ALL.len(), 52);
+        assert_eq!(ItemSet::ALL.priority_sum(), (1..=52).sum());
+        assert_eq!(
+            ItemSet::EMPTY.only(),
+            Err("expected exactly one common item, found 0".to_string())
+        );
+        assert_eq!(
+            ItemSet::parse("ab1").unwrap_err().to_string(),
+            "unrecognised item '1'"
+        );
+        assert_eq!(
+            ItemSet::parse("aé").unwrap_err().to_string(),
+            "unrecognised item 'é'"
+        );
+    }
+
+    #[test]
+    fn test_compartments() {
+        assert_eq!(compartments("abcd").unwrap(), ("ab", "cd"));
+        assert_eq!(
+            compartments("aéb").unwrap_err().to_string(),
+            "unrecognised item 'é'"
+        );
+        assert!(compartments("abc").is_err());
+    }
+
+    #[test]
+    fn test_generated() {
+        gen::for_each_input(3, gen::TEST_SIZES, |input| {
+            for line in input.lines() {
+                // Splitting a rucksack loses no items, and an ItemSet's items
+                // parse back to the same set.
+                let (left, right) = compartments(line).unwrap();
+                assert_eq!(format!("{left}{right}"), line);
+                let items = ItemSet::parse(line).unwrap();
+                let halves = ItemSet::parse(left).unwrap()
+                    | ItemSet::parse(right).unwrap();
+                assert_eq!(halves, items);
+                let printed: String = items.iter().map(char::from).collect();
+                assert_eq!(ItemSet::parse(&printed).unwrap(), items);
+            }
+        });
+    }
+
+    /// A regex for rucksacks, sometimes holding a non-item.
+    const INPUT_LIKE: &str = r"[a-zA-Z]{0,12}\PC?[a-zA-Z]{0,12}";
+
+    proptest! {
+        #[test]
+        fn prop_parser_never_panics(
+            input in prop_oneof![ANY_TEXT, INPUT_LIKE],
+        ) {
+            prop_assert!(!parser_panics(ItemSet::parse, &input));
+            let halves = |s: &str| compartments(s).map(|_| ());
+            prop_assert!(!parser_panics(halves, &input));
+        }
+    }
+}
@@ -0,0 +1,35 @@
+mod common;
+mod part1;
+mod part2;
+mod planner;
+
+use std::error::Error;
+
+use aoc2022::utils::file;
+use aoc2022::utils::report::{stats_wanted, Report};
+use aoc2022::YEAR;
+
+/// How many elves are in each group sharing a badge.
+const GROUP_SIZE: usize = 3;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let mut report = Report::new(YEAR, 3)?;
+
+    let lines = file::get_input_lines(YEAR, "day03")?;
+    report.try_part(1, || part1::solve(lines))?;
+
+    let lines = file::get_input_lines(YEAR, "day03")?;
+    report.try_part(2, || part2::solve(lines, GROUP_SIZE))?;
+
+    // How the rucksacks could be fixed, in detail with AOC_STATS.
+    let lines = file::get_input_lines(YEAR, "day03")?;
+    let plans = planner::RucksackReport::new(lines, GROUP_SIZE)?;
+    eprintln!("{}", plans.summary());
+    if stats_wanted() {
+        for line in plans.details() {
+            eprintln!("{line}");
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use std::io;
+
+use crate::common::{self, ItemSet};
+
+pub fn solve(
+    lines: impl Iterator<Item = Result<String, io::Error>>,
+) -> Result<u64, io::Error> {
+    // Summary for part1: take inputs, split in half, and find the common letter.
+    lines
+        .enumerate()
+        .map(|(i, line)| -> Result<u64, io::Error> {
+            let line = line?;
+            let (cmp1, cmp2) = common::compartments(&line)
+                .map_err(|e| common::line_err(i, e))?;
+            let common = ItemSet::parse(cmp1)
+                .map_err(|e| common::line_err(i, e))?
+                & ItemSet::parse(cmp2).map_err(|e| common::line_err(i, e))?;
+            common.only().map_err(|e| common::line_err(i, e))?;
+
+            Ok(common.priority_sum())
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc2022::gen;
+    use aoc2022::utils::file::{get_input_lines, get_str_lines};
+    use aoc2022::YEAR;
+
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        let lines = get_input_lines(YEAR, "example/day03").unwrap();
+        assert_eq!(solve(lines).unwrap(), 157);
+    }
+
+    #[test]
+    fn test_errors() {
+        let err = |s| solve(get_str_lines(s)).unwrap_err().to_string();
+        assert_eq!(
+            err("aa\nabc\n"),
+            "line 2: rucksack has an odd number of items: \"abc\""
+        );
+        assert_eq!(
+            err("ab\n"),
+            "line 1: expected exactly one common item, found 0"
+        );
+        assert_eq!(err("aa\na1a1\n"), "line 2: unrecognised item '1'");
+        assert_eq!(err("aéb\n"), "line 1: unrecognised item 'é'");
+    }
+
+    #[test]
+    fn test_generated() {
+        gen::for_each_input(3, gen::TEST_SIZES, |input| {
+            assert!(solve(get_str_lines(input)).is_ok());
+        });
+    }
+}
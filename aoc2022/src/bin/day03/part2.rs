@@ -0,0 +1,95 @@
+use std::io;
+
+use itertools::Itertools;
+
+use crate::common::{self, ItemSet};
+
+/// Finds each group of group_size rucksacks' one common item, and sums their
+/// priorities.
+pub fn solve(
+    lines: impl Iterator<Item = Result<String, io::Error>>,
+    group_size: usize,
+) -> Result<u64, io::Error> {
+    if group_size == 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "groups must have at least one rucksack",
+        ));
+    }
+
+    lines
+        .enumerate()
+        .chunks(group_size)
+        .into_iter()
+        .map(|group| -> Result<u64, io::Error> {
+            let mut common = ItemSet::ALL;
+            let mut count = 0;
+            let mut last = 0;
+            for (i, line) in group {
+                common = common
+                    & ItemSet::parse(&line?)
+                        .map_err(|e| common::line_err(i, e))?;
+                count += 1;
+                last = i;
+            }
+            if count < group_size {
+                return Err(common::line_err(
+                    last,
+                    format!(
+                        "input ends part way through a group of {group_size}"
+                    ),
+                ));
+            }
+            common.only().map_err(|e| common::line_err(last, e))?;
+
+            Ok(common.priority_sum())
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc2022::gen;
+    use aoc2022::utils::file::{get_input_lines, get_str_lines};
+    use aoc2022::YEAR;
+
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        let lines = get_input_lines(YEAR, "example/day03").unwrap();
+        assert_eq!(solve(lines, 3).unwrap(), 70);
+
+        let lines = get_input_lines(YEAR, "example/day03").unwrap();
+        assert_eq!(
+            solve(lines, 6).unwrap_err().to_string(),
+            "line 6: expected exactly one common item, found 0"
+        );
+    }
+
+    #[test]
+    fn test_group_sizes() {
+        assert_eq!(solve(get_str_lines("ab\nbc\ncd\nde\n"), 2).unwrap(), 6);
+        assert_eq!(
+            solve(get_str_lines("ab\n"), 1).unwrap_err().to_string(),
+            "line 1: expected exactly one common item, found 2"
+        );
+        assert_eq!(
+            solve(get_str_lines("ab\nbc\ncd\n"), 2)
+                .unwrap_err()
+                .to_string(),
+            "line 3: input ends part way through a group of 2"
+        );
+        assert_eq!(
+            solve(get_str_lines("ab\n"), 0).unwrap_err().kind(),
+            io::ErrorKind::InvalidInput
+        );
+    }
+
+    #[test]
+    fn test_generated() {
+        gen::for_each_input(3, gen::TEST_SIZES, |input| {
+            assert!(solve(get_str_lines(input), 3).is_ok());
+        });
+    }
+}
@@ -0,0 +1,364 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io;
+
+use itertools::Itertools;
+
+use crate::common::{self, priority, ItemSet, ITEMS};
+
+/// A Swap exchanges an item from a rucksack's left compartment for one from
+/// its right.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Swap {
+    pub left: u8,
+    pub right: u8,
+}
+
+/// A Plan says how to fix one rucksack, so no item type is in both of its
+/// compartments.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Plan {
+    /// The item types in both compartments.
+    pub misplaced: ItemSet,
+    /// The fewest swaps leaving every item type in one compartment, or None
+    /// if no swaps can, as when each compartment is one of the same item.
+    pub swaps: Option<Vec<Swap>>,
+}
+
+impl Plan {
+    /// Plans how to fix a rucksack.
+    pub fn new(line: &str) -> Result<Self, io::Error> {
+        let (left, right) = common::compartments(line)?;
+        let misplaced = ItemSet::parse(left)? & ItemSet::parse(right)?;
+        let (left, right) = (counts(left), counts(right));
+
+        // Every item type must end up wholly in one compartment. Each swap
+        // moves one item each way, so as many items must move right as left.
+        // Deciding a type at a time, moves maps how many more items are moved
+        // right than left to the fewest moved right, and which types move
+        // right and left to get there.
+        let mut moves =
+            BTreeMap::from([(0, (0, ItemSet::EMPTY, ItemSet::EMPTY))]);
+        for p in 0..ITEMS.len() {
+            let (l, r) = (left[p] as isize, right[p] as isize);
+            if l + r == 0 {
+                continue;
+            }
+            let mut next = BTreeMap::new();
+            let mut keep = |diff, (moved, to_right, to_left)| {
+                let best =
+                    next.entry(diff).or_insert((moved, to_right, to_left));
+                if moved < best.0 {
+                    *best = (moved, to_right, to_left);
+                }
+            };
+            for (&diff, &(moved, to_right, mut to_left)) in &moves {
+                // Keep the type on the left, moving any on the right over.
+                if r > 0 {
+                    to_left.insert(ITEMS[p]);
+                }
+                keep(diff - r, (moved, to_right, to_left));
+            }
+            for (&diff, &(moved, mut to_right, to_left)) in &moves {
+                if l > 0 {
+                    to_right.insert(ITEMS[p]);
+                }
+                keep(diff + l, (moved + l as usize, to_right, to_left));
+            }
+            moves = next;
+        }
+
+        let swaps = moves.get(&0).map(|&(_, to_right, to_left)| {
+            let items = |set: ItemSet, counts: &[usize; 52]| {
+                set.iter()
+                    .flat_map(|item| {
+                        let p = priority(item).unwrap() as usize - 1;
+                        itertools::repeat_n(item, counts[p])
+                    })
+                    .collect::<Vec<_>>()
+            };
+            items(to_right, &left)
+                .into_iter()
+                .zip(items(to_left, &right))
+                .map(|(left, right)| Swap { left, right })
+                .collect()
+        });
+        Ok(Plan { misplaced, swaps })
+    }
+}
+
+impl fmt::Display for Plan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let misplaced: String = self.misplaced.iter().map(char::from).collect();
+        match &self.swaps {
+            _ if self.misplaced.len() == 0 => write!(f, "nothing misplaced"),
+            None => write!(f, "{misplaced} misplaced, and can't be fixed"),
+            Some(swaps) => {
+                let swaps = swaps
+                    .iter()
+                    .map(|s| {
+                        format!("{}<->{}", s.left as char, s.right as char)
+                    })
+                    .join(" ");
+                write!(f, "{misplaced} misplaced, fixed by swapping {swaps}")
+            },
+        }
+    }
+}
+
+/// Returns how many of each item type are in s, by priority.
+fn counts(s: &str) -> [usize; 52] {
+    let mut counts = [0; 52];
+    for c in s.bytes() {
+        if let Some(p) = priority(c) {
+            counts[p as usize - 1] += 1;
+        }
+    }
+    counts
+}
+
+/// A RucksackReport plans how to fix every rucksack, and lists each group's
+/// badge candidates: the item types all its rucksacks have.
+#[derive(Clone, Debug)]
+pub struct RucksackReport {
+    pub plans: Vec<Plan>,
+    pub badges: Vec<ItemSet>,
+}
+
+/// A Summary of a RucksackReport.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Summary {
+    pub rucksacks: usize,
+    /// Rucksacks with an item type in both compartments.
+    pub misplaced: usize,
+    /// The sum of the priorities of the misplaced item types.
+    pub misplaced_priority: u64,
+    /// Rucksacks that no swaps can fix.
+    pub unfixable: usize,
+    /// Swaps needed to fix all the others.
+    pub swaps: usize,
+    pub groups: usize,
+    /// Groups with exactly one badge candidate.
+    pub badges: usize,
+    /// Groups with more than one.
+    pub ambiguous: usize,
+}
+
+impl RucksackReport {
+    pub fn new(
+        lines: impl Iterator<Item = Result<String, io::Error>>,
+        group_size: usize,
+    ) -> Result<Self, io::Error> {
+        let lines: Vec<String> = lines.collect::<Result<_, _>>()?;
+        let ungrouped = lines.len().checked_rem(group_size).unwrap_or(1);
+        if ungrouped > 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "{} rucksacks don't split into groups of {group_size}",
+                    lines.len()
+                ),
+            ));
+        }
+
+        let plans = lines
+            .iter()
+            .enumerate()
+            .map(|(i, line)| {
+                Plan::new(line).map_err(|e| common::line_err(i, e))
+            })
+            .collect::<Result<_, _>>()?;
+        let badges = lines
+            .chunks(group_size)
+            .map(|group| {
+                group.iter().try_fold(ItemSet::ALL, |common, line| {
+                    Ok::<_, io::Error>(common & ItemSet::parse(line)?)
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(RucksackReport { plans, badges })
+    }
+
+    pub fn summary(&self) -> Summary {
+        let mut summary = Summary {
+            rucksacks: self.plans.len(),
+            groups: self.badges.len(),
+            ..Summary::default()
+        };
+        for plan in &self.plans {
+            if plan.misplaced.len() > 0 {
+                summary.misplaced += 1;
+            }
+            summary.misplaced_priority += plan.misplaced.priority_sum();
+            match &plan.swaps {
+                Some(swaps) => summary.swaps += swaps.len(),
+                None => summary.unfixable += 1,
+            }
+        }
+        for badge in &self.badges {
+            match badge.len() {
+                0 => (),
+                1 => summary.badges += 1,
+                _ => summary.ambiguous += 1,
+            }
+        }
+        summary
+    }
+
+    /// Returns a line describing each rucksack's Plan, and each group's badge
+    /// candidates after its last rucksack.
+    pub fn details(&self) -> impl Iterator<Item = String> + '_ {
+        let group_size = self.plans.len() / self.badges.len().max(1);
+        self.plans.iter().enumerate().flat_map(move |(i, plan)| {
+            let mut lines = vec![format!("rucksack {}: {plan}", i + 1)];
+            if (i + 1) % group_size == 0 {
+                let badge = self.badges[i / group_size];
+                let candidates: String = badge.iter().map(char::from).collect();
+                lines.push(format!(
+                    "group {}: badge candidates {candidates:?}",
+                    i / group_size + 1
+                ));
+            }
+            lines
+        })
+    }
+}
+
+impl fmt::Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} of {} rucksacks have misplaced items (priority {}): {} swaps \
+            fix all but {}. {} of {} groups have one badge candidate, {} more \
+            than one",
+            self.misplaced,
+            self.rucksacks,
+            self.misplaced_priority,
+            self.swaps,
+            self.unfixable,
+            self.badges,
+            self.groups,
+            self.ambiguous,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc2022::gen;
+    use aoc2022::utils::file::{get_input_lines, get_str_lines};
+    use aoc2022::utils::fuzz::{parser_panics, ANY_TEXT};
+    use aoc2022::YEAR;
+    use proptest::prelude::*;
+
+    use super::*;
+
+    /// Applies a plan's swaps, checking they leave nothing misplaced.
+    fn check_swaps(line: &str, swaps: &[Swap]) {
+        let (left, right) = line.split_at(line.len() / 2);
+        let (mut left, mut right) =
+            (left.as_bytes().to_vec(), right.as_bytes().to_vec());
+        for s in swaps {
+            let l = left.iter().position(|&c| c == s.left).unwrap();
+            let r = right.iter().position(|&c| c == s.right).unwrap();
+            left[l] = s.right;
+            right[r] = s.left;
+        }
+        let (left, right) = (
+            String::from_utf8(left).unwrap(),
+            String::from_utf8(right).unwrap(),
+        );
+        let common =
+            ItemSet::parse(&left).unwrap() & ItemSet::parse(&right).unwrap();
+        assert_eq!(common.len(), 0, "{line}: {swaps:?}");
+    }
+
+    #[test]
+    fn test_plan() {
+        let plan = Plan::new("vJrwpWtwJgWrhcsFMMfFFhFp").unwrap();
+        assert_eq!(plan.misplaced.only(), Ok(b'p'));
+        let swaps = plan.swaps.unwrap();
+        assert_eq!(swaps.len(), 1);
+        check_swaps("vJrwpWtwJgWrhcsFMMfFFhFp", &swaps);
+
+        let plan = Plan::new("abac").unwrap();
+        assert_eq!(
+            plan.swaps,
+            Some(vec![Swap {
+                left: b'a',
+                right: b'c'
+            }])
+        );
+        assert_eq!(plan.to_string(), "a misplaced, fixed by swapping a<->c");
+
+        // Whichever way the a moves, there's nothing to move back.
+        let plan = Plan::new("aa").unwrap();
+        assert_eq!(plan.swaps, None);
+        assert_eq!(plan.to_string(), "a misplaced, and can't be fixed");
+
+        let plan = Plan::new("abcd").unwrap();
+        assert_eq!(plan.swaps, Some(vec![]));
+        assert_eq!(plan.to_string(), "nothing misplaced");
+
+        assert!(Plan::new("abc").is_err());
+        assert_eq!(
+            Plan::new("aéb").unwrap_err().to_string(),
+            "unrecognised item 'é'"
+        );
+    }
+
+    #[test]
+    fn test_rucksack_report() {
+        let lines = get_input_lines(YEAR, "example/day03").unwrap();
+        let report = RucksackReport::new(lines, 3).unwrap();
+        let summary = report.summary();
+        assert_eq!(summary.rucksacks, 6);
+        assert_eq!(summary.misplaced, 6);
+        assert_eq!(summary.misplaced_priority, 157);
+        assert_eq!((summary.groups, summary.badges), (2, 2));
+        let badges: Vec<_> =
+            report.badges.iter().map(|b| b.only().unwrap()).collect();
+        assert_eq!(badges, b"rZ");
+
+        let details: Vec<_> = report.details().collect();
+        assert_eq!(details.len(), 8);
+        assert_eq!(details[3], "group 1: badge candidates \"r\"");
+
+        let lines = get_input_lines(YEAR, "example/day03").unwrap();
+        assert!(RucksackReport::new(lines, 4).is_err());
+
+        let err = RucksackReport::new(get_str_lines("aéb\n"), 1).unwrap_err();
+        assert_eq!(err.to_string(), "line 1: unrecognised item 'é'");
+    }
+
+    #[test]
+    fn test_generated() {
+        gen::for_each_input(3, &[1, 10, 100], |input| {
+            let report = RucksackReport::new(get_str_lines(input), 3).unwrap();
+            for (line, plan) in input.lines().zip(&report.plans) {
+                assert_eq!(plan.misplaced.len(), 1);
+                if let Some(swaps) = &plan.swaps {
+                    check_swaps(line, swaps);
+                }
+            }
+            assert_eq!(report.summary().badges, report.badges.len());
+        });
+    }
+
+    /// A regex for lists of rucksacks, sometimes holding a non-item.
+    const INPUT_LIKE: &str = r"([a-zA-Z]{0,12}\PC?[a-zA-Z]{0,12}\n){0,7}";
+
+    proptest! {
+        #[test]
+        fn prop_parser_never_panics(
+            input in prop_oneof![ANY_TEXT, INPUT_LIKE],
+        ) {
+            let plans = |s: &str| {
+                s.lines().map(Plan::new).collect::<Result<Vec<_>, _>>()
+            };
+            prop_assert!(!parser_panics(plans, &input));
+            let report = |s: &str| RucksackReport::new(get_str_lines(s), 3);
+            prop_assert!(!parser_panics(report, &input));
+        }
+    }
+}
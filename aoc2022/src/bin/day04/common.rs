@@ -1,22 +1,24 @@
 use std::error::Error;
+use std::str;
 
-use aoc2022::utils::error::{parse_error, ParseError};
+use aoc2022::utils::error::invalid_data_err;
 use itertools::Itertools;
 use lazy_static::lazy_static;
 use regex::Regex;
-use std::str;
 
+/// A Range is an inclusive range of sections.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct Range {
-    from: u64,
-    to: u64,
+    pub from: u64,
+    pub to: u64,
 }
 
 impl Range {
-    pub fn new(from: u64, to: u64) -> Result<Range, ParseError> {
+    pub fn new(from: u64, to: u64) -> Result<Range, String> {
         if from <= to {
             Ok(Range { from, to })
         } else {
-            Err(parse_error(format!("from > to ({from} > {to})").as_str()))
+            Err(format!("from > to ({from} > {to})"))
         }
     }
 
@@ -47,30 +49,41 @@ pub fn parse_line(line: &str) -> Result<(Range, Range), Box<dyn Error>> {
 
     let vals = match PARSER.captures(line) {
         Some(v) => v,
-        None => {
-            return Err(parse_error(
-                format!("failed to match line {line}").as_str(),
-            )
-            .into())
-        },
+        None => return Err(format!("expected a-b,c-d, got {line:?}").into()),
     };
 
-    assert_eq!(vals.len(), 5, "bad num captures in line {line}");
-    match vals
+    // The regex has four groups, which all take part in any match.
+    let (v1, v2, v3, v4) = vals
         .iter()
         .skip(1)
-        .map(|x| x.unwrap().as_str().parse::<u64>())
+        .map(|x| x.map_or("", |m| m.as_str()).parse::<u64>())
         .collect_tuple()
-    {
-        Some((v1, v2, v3, v4)) => {
-            Ok((Range::new(v1?, v2?)?, Range::new(v3?, v4?)?))
-        },
-        None => Err(parse_error("???").into()),
-    }
+        .unwrap();
+    Ok((Range::new(v1?, v2?)?, Range::new(v3?, v4?)?))
+}
+
+/// Parses every line into a pair of Ranges, stopping at the first error with
+/// its line number.
+pub fn parse_lines(
+    lines: impl Iterator<Item = Result<String, std::io::Error>>,
+) -> Result<Vec<(Range, Range)>, Box<dyn Error>> {
+    lines
+        .enumerate()
+        .map(|(i, line)| {
+            parse_line(&line?).map_err(|e| {
+                invalid_data_err(&format!("line {}: {e}", i + 1)).into()
+            })
+        })
+        .collect()
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc2022::gen;
+    use aoc2022::utils::file::get_str_lines;
+    use aoc2022::utils::fuzz::{parser_panics, ANY_TEXT};
+    use proptest::prelude::*;
+
     use super::*;
 
     #[test]
@@ -163,4 +176,57 @@ mod tests {
             .unwrap()
             .contains(&Range::new(1, 5).unwrap()));
     }
+
+    #[test]
+    fn test_parse_lines() {
+        let lines = get_str_lines("2-4,6-8\n2-3,4-5\n");
+        let pairs = parse_lines(lines).unwrap();
+        assert_eq!(
+            pairs[1],
+            (Range { from: 2, to: 3 }, Range { from: 4, to: 5 })
+        );
+
+        let err = |s| parse_lines(get_str_lines(s)).unwrap_err().to_string();
+        assert_eq!(
+            err("1-2,3-4\n1-2\n"),
+            "line 2: expected a-b,c-d, got \"1-2\""
+        );
+        assert_eq!(err("1-2,4-3\n"), "line 1: from > to (4 > 3)");
+        assert_eq!(
+            err("1-2,3-99999999999999999999\n"),
+            format!(
+                "line 1: {}",
+                "99999999999999999999".parse::<u64>().unwrap_err()
+            )
+        );
+    }
+
+    #[test]
+    fn test_generated() {
+        gen::for_each_input(4, gen::TEST_SIZES, |input| {
+            for line in input.lines() {
+                let (r1, r2) = parse_line(line).unwrap();
+                let printed =
+                    format!("{}-{},{}-{}", r1.from, r1.to, r2.from, r2.to);
+                assert_eq!(printed, line);
+                if r1.contains(&r2) || r2.contains(&r1) {
+                    assert!(r1.overlaps_with(&r2), "{line}");
+                }
+            }
+        });
+    }
+
+    /// A regex for inputs shaped like section assignments.
+    const INPUT_LIKE: &str =
+        r"([0-9]{0,21}-?[0-9]{0,3},?[0-9]{0,3}-?[0-9]{0,21}\n){0,5}";
+
+    proptest! {
+        #[test]
+        fn prop_parser_never_panics(
+            input in prop_oneof![ANY_TEXT, INPUT_LIKE],
+        ) {
+            let pairs = |s: &str| parse_lines(get_str_lines(s));
+            prop_assert!(!parser_panics(pairs, &input));
+        }
+    }
 }
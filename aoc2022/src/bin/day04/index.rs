@@ -0,0 +1,350 @@
+use std::fmt;
+
+use crate::common::Range;
+
+/// An Elf is identified by the line of its assignment, numbered from 1, and
+/// whether it's the first or second of the pair on that line.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Elf {
+    pub line: usize,
+    pub second: bool,
+}
+
+impl fmt::Display for Elf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let half = if self.second { 'b' } else { 'a' };
+        write!(f, "{}{half}", self.line)
+    }
+}
+
+/// A SectionIndex holds every elf's assignment, for queries across all of
+/// them rather than within a pair.
+///
+/// The assignments are sorted by start, and a segment tree over that order
+/// holds the greatest end in each run of them. Those starting by some
+/// section form a prefix, and the tree finds the ones in it ending at or
+/// after another without looking at the rest, so queries take O(log n) per
+/// elf they return.
+pub struct SectionIndex {
+    /// Sorted by start, then longest first.
+    sorted: Vec<(Range, Elf)>,
+    /// max_end[1] is the greatest end of all; node i's halves are 2i and
+    /// 2i + 1, and leaf size + k is sorted[k].
+    max_end: Vec<u64>,
+    size: usize,
+}
+
+impl SectionIndex {
+    pub fn new(pairs: &[(Range, Range)]) -> Self {
+        let mut sorted: Vec<_> = pairs
+            .iter()
+            .enumerate()
+            .flat_map(|(i, &(r1, r2))| {
+                let elf = |second| Elf {
+                    line: i + 1,
+                    second,
+                };
+                [(r1, elf(false)), (r2, elf(true))]
+            })
+            .collect();
+        sorted.sort_by_key(|&(r, elf)| (r.from, u64::MAX - r.to, elf));
+
+        // Leaves past the end are never searched, so 0 is as good as any.
+        let size = sorted.len().next_power_of_two();
+        let mut max_end = vec![0; 2 * size];
+        for (k, (r, _)) in sorted.iter().enumerate() {
+            max_end[size + k] = r.to;
+        }
+        for i in (1..size).rev() {
+            max_end[i] = max_end[2 * i].max(max_end[2 * i + 1]);
+        }
+        SectionIndex {
+            sorted,
+            max_end,
+            size,
+        }
+    }
+
+    /// Returns the number of elves.
+    pub fn len(&self) -> usize {
+        self.sorted.len()
+    }
+
+    /// Returns the sections from the first any elf is assigned to the last, or
+    /// None if there are no elves.
+    pub fn span(&self) -> Option<Range> {
+        self.sorted.first().map(|&(r, _)| Range {
+            from: r.from,
+            to: self.max_end[1],
+        })
+    }
+
+    /// Returns the positions in sorted before end of the assignments ending
+    /// at or after min_end, in order.
+    fn ending_from(&self, end: usize, min_end: u64) -> Vec<usize> {
+        let mut found = Vec::new();
+        self.collect(1, 0, self.size, end, min_end, &mut found);
+        found
+    }
+
+    fn collect(
+        &self,
+        node: usize,
+        lo: usize,
+        hi: usize,
+        end: usize,
+        min_end: u64,
+        found: &mut Vec<usize>,
+    ) {
+        if lo >= end || self.max_end[node] < min_end {
+            return;
+        }
+        if hi - lo == 1 {
+            found.push(lo);
+            return;
+        }
+        let mid = (lo + hi) / 2;
+        self.collect(2 * node, lo, mid, end, min_end, found);
+        self.collect(2 * node + 1, mid, hi, end, min_end, found);
+    }
+
+    /// Returns the elves whose assignments overlap r, in line order.
+    pub fn overlapping(&self, r: &Range) -> Vec<Elf> {
+        let end = self.sorted.partition_point(|(s, _)| s.from <= r.to);
+        let mut elves: Vec<_> = self
+            .ending_from(end, r.from)
+            .into_iter()
+            .map(|k| self.sorted[k].1)
+            .collect();
+        elves.sort();
+        elves
+    }
+
+    /// Returns the elves assigned the given section, in line order.
+    pub fn covering(&self, section: u64) -> Vec<Elf> {
+        self.overlapping(&Range {
+            from: section,
+            to: section,
+        })
+    }
+
+    /// Returns the most elves assigned any one section, and the first section
+    /// with that many, or None if there are no elves.
+    pub fn max_depth(&self) -> Option<(usize, u64)> {
+        // Sweep the starts and ends in order. The ranges are inclusive, so at
+        // the same section, starts come before ends.
+        let mut events: Vec<_> = self
+            .sorted
+            .iter()
+            .flat_map(|(r, _)| [(r.from, false), (r.to, true)])
+            .collect();
+        events.sort_unstable();
+
+        let (mut depth, mut most, mut first) = (0, 0, 0);
+        for (section, is_end) in events {
+            if is_end {
+                depth -= 1;
+            } else {
+                depth += 1;
+                if depth > most {
+                    (most, first) = (depth, section);
+                }
+            }
+        }
+        if most > 0 {
+            Some((most, first))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the runs of sections within span that no elf is assigned.
+    pub fn uncovered(&self, span: &Range) -> Vec<Range> {
+        let mut gaps = Vec::new();
+        // The first section not yet known to be covered, or None past u64::MAX.
+        let mut next = Some(span.from);
+        for (r, _) in &self.sorted {
+            let n = match next {
+                Some(n) if n <= span.to => n,
+                _ => return gaps,
+            };
+            if r.from > n {
+                gaps.push(Range {
+                    from: n,
+                    to: (r.from - 1).min(span.to),
+                });
+            }
+            if r.to >= n {
+                next = r.to.checked_add(1);
+            }
+        }
+        if let Some(n) = next {
+            if n <= span.to {
+                gaps.push(Range {
+                    from: n,
+                    to: span.to,
+                });
+            }
+        }
+        gaps
+    }
+
+    /// Returns every pair of elves where the first's assignment contains the
+    /// second's, across all lines. Elves with the same assignment appear once,
+    /// the first in line order containing the other.
+    ///
+    /// Sorting by start, then longest first, puts every assignment after
+    /// those containing it, so for each one this only has to find the earlier
+    /// ones ending at or after it: O(n log n) plus O(log n) per pair, rather
+    /// than comparing every pair.
+    pub fn containing_pairs(&self) -> Vec<(Elf, Elf)> {
+        let mut pairs = Vec::new();
+        for (k, &(r, inner)) in self.sorted.iter().enumerate() {
+            for j in self.ending_from(k, r.to) {
+                pairs.push((self.sorted[j].1, inner));
+            }
+        }
+        pairs.sort();
+        pairs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc2022::gen;
+    use aoc2022::utils::file::{get_input_lines, get_str_lines};
+    use aoc2022::YEAR;
+    use itertools::Itertools;
+
+    use super::*;
+    use crate::common::parse_lines;
+
+    fn example() -> SectionIndex {
+        let lines = get_input_lines(YEAR, "example/day04").unwrap();
+        SectionIndex::new(&parse_lines(lines).unwrap())
+    }
+
+    fn elves(names: &str) -> Vec<Elf> {
+        names
+            .split(' ')
+            .map(|name| {
+                let (line, half) = name.split_at(name.len() - 1);
+                Elf {
+                    line: line.parse().unwrap(),
+                    second: half == "b",
+                }
+            })
+            .collect()
+    }
+
+    /// Checks containing_pairs against comparing every pair.
+    fn check_pairs(pairs: &[(Range, Range)]) {
+        let all: Vec<_> = pairs
+            .iter()
+            .enumerate()
+            .flat_map(|(i, &(r1, r2))| {
+                [
+                    (
+                        r1,
+                        Elf {
+                            line: i + 1,
+                            second: false,
+                        },
+                    ),
+                    (
+                        r2,
+                        Elf {
+                            line: i + 1,
+                            second: true,
+                        },
+                    ),
+                ]
+            })
+            .collect();
+        let mut expected = vec![];
+        for ((r1, e1), (r2, e2)) in all.iter().tuple_combinations() {
+            if r1.contains(r2) {
+                expected.push((*e1, *e2));
+            } else if r2.contains(r1) {
+                expected.push((*e2, *e1));
+            }
+        }
+        expected.sort();
+        assert_eq!(SectionIndex::new(pairs).containing_pairs(), expected);
+    }
+
+    #[test]
+    fn test_overlapping() {
+        let index = example();
+        assert_eq!(index.len(), 12);
+        assert_eq!(index.covering(7), elves("1b 3a 3b 4a 4b 6b"));
+        assert_eq!(index.covering(1), vec![]);
+        assert_eq!(index.covering(9), elves("3b"));
+        let r = Range::new(8, 20).unwrap();
+        assert_eq!(index.overlapping(&r), elves("1b 3b 4a 6b"));
+    }
+
+    #[test]
+    fn test_max_depth() {
+        assert_eq!(example().max_depth(), Some((8, 6)));
+        assert_eq!(SectionIndex::new(&[]).max_depth(), None);
+    }
+
+    #[test]
+    fn test_uncovered() {
+        let index = example();
+        let span = Range::new(1, 10).unwrap();
+        let gaps = vec![Range::new(1, 1).unwrap(), Range::new(10, 10).unwrap()];
+        assert_eq!(index.uncovered(&span), gaps);
+        assert_eq!(index.uncovered(&Range::new(3, 8).unwrap()), vec![]);
+        assert_eq!(index.span(), Some(Range::new(2, 9).unwrap()));
+        assert_eq!(SectionIndex::new(&[]).span(), None);
+
+        let pairs = parse_lines(get_str_lines("1-2,5-6\n")).unwrap();
+        let index = SectionIndex::new(&pairs);
+        let span = Range::new(2, 5).unwrap();
+        assert_eq!(index.uncovered(&span), vec![Range::new(3, 4).unwrap()]);
+        let span = Range::new(0, u64::MAX).unwrap();
+        assert_eq!(index.uncovered(&span).len(), 3);
+    }
+
+    #[test]
+    fn test_containing_pairs() {
+        let index = example();
+        let pairs = index.containing_pairs();
+        // The pairs within a line are part 1's.
+        let within: Vec<_> =
+            pairs.iter().filter(|(a, b)| a.line == b.line).collect();
+        assert_eq!(within.len(), 2);
+        let lines = get_input_lines(YEAR, "example/day04").unwrap();
+        check_pairs(&parse_lines(lines).unwrap());
+
+        let pairs = parse_lines(get_str_lines("3-5,3-5\n3-5,4-4\n")).unwrap();
+        check_pairs(&pairs);
+        let same = SectionIndex::new(&pairs).containing_pairs();
+        assert_eq!(same[0], (elves("1a")[0], elves("1b")[0]));
+    }
+
+    #[test]
+    fn test_generated() {
+        gen::for_each_input(4, gen::TEST_SIZES, |input| {
+            let pairs = parse_lines(get_str_lines(input)).unwrap();
+            check_pairs(&pairs);
+
+            let index = SectionIndex::new(&pairs);
+            for section in [1, 50, 99] {
+                let brute: Vec<_> = index
+                    .sorted
+                    .iter()
+                    .filter(|(r, _)| r.from <= section && section <= r.to)
+                    .map(|&(_, elf)| elf)
+                    .sorted()
+                    .collect();
+                assert_eq!(index.covering(section), brute);
+            }
+            if let Some((depth, section)) = index.max_depth() {
+                assert_eq!(index.covering(section).len(), depth);
+            }
+        });
+    }
+}
@@ -0,0 +1,56 @@
+use std::error::Error;
+
+use aoc2022::utils::file::get_input_lines;
+use aoc2022::utils::report::{stats_wanted, Report};
+use aoc2022::YEAR;
+
+mod common;
+mod index;
+mod part1;
+mod part2;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let mut report = Report::new(YEAR, 4)?;
+
+    let lines = get_input_lines(YEAR, "day04")?;
+    report.try_part(1, || part1::solve(lines))?;
+
+    let lines = get_input_lines(YEAR, "day04")?;
+    report.try_part(2, || part2::solve(lines))?;
+
+    // Queries across every elf's assignment, listed in full with AOC_STATS.
+    let lines = get_input_lines(YEAR, "day04")?;
+    let index = index::SectionIndex::new(&common::parse_lines(lines)?);
+    let pairs = index.containing_pairs();
+    eprintln!(
+        "{} elves: {} pairs where one contains the other",
+        index.len(),
+        pairs.len()
+    );
+    if let (Some(span), Some((depth, section))) =
+        (index.span(), index.max_depth())
+    {
+        // Sections are numbered from 1, but the parser accepts 0 too.
+        let from = span.from.min(1);
+        let uncovered = index.uncovered(&common::Range::new(from, span.to)?);
+        eprintln!(
+            "most elves on one section: {depth}, first at {section}; {} gaps \
+            no elf covers up to {}",
+            uncovered.len(),
+            span.to
+        );
+        if stats_wanted() {
+            for gap in uncovered {
+                eprintln!("uncovered: {}-{}", gap.from, gap.to);
+            }
+            for elf in index.covering(section) {
+                eprintln!("on section {section}: elf {elf}");
+            }
+            for (outer, inner) in pairs {
+                eprintln!("elf {outer} contains elf {inner}");
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,654 @@
+use core::fmt;
+use std::error::Error;
+use std::io;
+
+use aoc2022::utils::error::{parse_error, ParseError};
+use aoc2022::utils::file::InputRecord;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MoveType {
+    Restack,
+    Block,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Move {
+    from: usize,
+    to: usize,
+    count: usize,
+    move_type: MoveType,
+    /// The 1-based line number of the move in the input.
+    line: usize,
+}
+
+impl Move {
+    /// Parses a record of moves, one per line.
+    pub fn parse_from_record(
+        record: &InputRecord,
+        move_type: MoveType,
+    ) -> impl Iterator<Item = Result<Move, Box<dyn Error>>> + '_ {
+        // Parse "move X from Y to Z".
+        record.lines().iter().enumerate().map(move |(i, l)| {
+            let (count, from, to) = scan_fmt!(
+                l.as_str(),
+                "move {d} from {d} to {d}",
+                usize,
+                usize,
+                usize
+            )
+            .map_err(|e| record.line_err(i, e))?;
+            Ok(Move {
+                count,
+                from,
+                to,
+                move_type,
+                line: record.line_number(i),
+            })
+        })
+    }
+}
+
+impl Move {
+    /// Makes a move that isn't from the input, so has line number 0.
+    pub fn new(
+        count: usize,
+        from: usize,
+        to: usize,
+        move_type: MoveType,
+    ) -> Move {
+        Move {
+            count,
+            from,
+            to,
+            move_type,
+            line: 0,
+        }
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Returns the move that undoes this one. Moving the crates straight back
+    /// the same way restores them: a restack reverses them again, and a block
+    /// keeps their order.
+    pub fn inverse(&self) -> Move {
+        Move {
+            from: self.to,
+            to: self.from,
+            ..*self
+        }
+    }
+}
+
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Move: {} [{}]--> {}", self.from, self.count, self.to)
+    }
+}
+
+/// Parses the input's records: the starting stacks, then the moves, if any.
+pub fn parse_records(
+    mut records: impl Iterator<Item = Result<InputRecord, io::Error>>,
+    move_type: MoveType,
+) -> Result<(State, Vec<Move>), Box<dyn Error>> {
+    let state = match records.next() {
+        None => return Err(parse_error("input was empty").into()),
+        Some(r) => State::new_from_record(&r?)?,
+    };
+    let moves = match records.next() {
+        None => Vec::new(),
+        Some(r) => {
+            Move::parse_from_record(&r?, move_type).collect::<Result<_, _>>()?
+        },
+    };
+    if let Some(r) = records.next() {
+        return Err(r?.err("expected only the stacks and the moves").into());
+    }
+
+    Ok((state, moves))
+}
+
+/// A MoveError is why a move can't be applied to a State.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MoveError {
+    /// The move names a stack that doesn't exist.
+    NoSuchStack {
+        line: usize,
+        stack: usize,
+        stacks: usize,
+    },
+    /// The move takes more crates than are on its stack.
+    TooFewCrates {
+        line: usize,
+        stack: usize,
+        count: usize,
+        height: usize,
+    },
+}
+
+impl fmt::Display for MoveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MoveError::NoSuchStack {
+                line,
+                stack,
+                stacks,
+            } => write!(
+                f,
+                "line {line}: there is no stack {stack}, only 1 to {stacks}"
+            ),
+            MoveError::TooFewCrates {
+                line,
+                stack,
+                count,
+                height,
+            } => write!(
+                f,
+                "line {line}: cannot move {count} crates from stack {stack}, \
+                which has {height}"
+            ),
+        }
+    }
+}
+
+impl Error for MoveError {}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct State {
+    stacks: Vec<Vec<String>>,
+}
+
+impl State {
+    fn new(stacks: Vec<Vec<String>>) -> Result<State, ParseError> {
+        if stacks.is_empty() {
+            return Err(parse_error("zero stacks provided"));
+        }
+
+        Ok(State { stacks })
+    }
+
+    /// Parses the drawing of the starting stacks, ending with the line
+    /// numbering them. A crate belongs to the stack whose number is under it,
+    /// so there can be any number of stacks, crates' labels can be any width,
+    /// and lines needn't be padded with spaces.
+    pub fn new_from_record(
+        record: &InputRecord,
+    ) -> Result<State, Box<dyn Error>> {
+        let (numbers, drawing) = match record.lines().split_last() {
+            None => return Err(record.err("no stacks given").into()),
+            Some(split) => split,
+        };
+
+        // The last line numbers the stacks, from 1.
+        let numbers = words(numbers);
+        for (i, &(_, _, word)) in numbers.iter().enumerate() {
+            if word.parse() != Ok(i + 1) {
+                return Err(record
+                    .line_err(
+                        drawing.len(),
+                        format!("expected stack {}, got {word:?}", i + 1),
+                    )
+                    .into());
+            }
+        }
+
+        // Work down the drawing, checking no crate is above a gap.
+        let mut stacks: Vec<Vec<String>> = vec![Vec::new(); numbers.len()];
+        for (i, line) in drawing.iter().enumerate() {
+            let mut row = vec![false; numbers.len()];
+            for (start, end, word) in words(line) {
+                let label = match word.strip_prefix('[') {
+                    Some(w) => w.strip_suffix(']'),
+                    None => None,
+                };
+                let label = match label {
+                    Some(l) if !l.is_empty() && !l.contains(['[', ']']) => l,
+                    _ => {
+                        return Err(record
+                            .line_err(
+                                i,
+                                format!("expected a crate, got {word:?}"),
+                            )
+                            .into())
+                    },
+                };
+
+                // The numbers are in order, so the first ending at or after
+                // the crate's start is the only one that might be under it,
+                // unless the next is too.
+                let k = numbers.partition_point(|&(_, e, _)| e < start);
+                let under = |k: usize| matches!(numbers.get(k), Some(&(s, _, _)) if s <= end);
+                if !under(k) || under(k + 1) {
+                    return Err(record
+                        .line_err(
+                            i,
+                            format!(
+                                "crate {word} isn't over exactly one stack"
+                            ),
+                        )
+                        .into());
+                }
+                if row[k] {
+                    return Err(record
+                        .line_err(i, format!("two crates over stack {}", k + 1))
+                        .into());
+                }
+                row[k] = true;
+                stacks[k].push(label.to_string());
+            }
+            if let Some(k) =
+                (0..stacks.len()).find(|&k| !row[k] && !stacks[k].is_empty())
+            {
+                return Err(record
+                    .line_err(
+                        i,
+                        format!("nothing under a crate in stack {}", k + 1),
+                    )
+                    .into());
+            }
+        }
+
+        // Reverse the order of the stacks to get 0..n in bottom..top ordering.
+        stacks.iter_mut().for_each(|v| v.reverse());
+
+        Ok(State::new(stacks)?)
+    }
+
+    /// Applies a move, or returns why it can't be, leaving the State as it
+    /// was.
+    pub fn apply_move(&mut self, m: &Move) -> Result<(), MoveError> {
+        // Note: from/to are 1-indexed.
+        for stack in [m.from, m.to] {
+            if stack == 0 || stack > self.stacks.len() {
+                return Err(MoveError::NoSuchStack {
+                    line: m.line,
+                    stack,
+                    stacks: self.stacks.len(),
+                });
+            }
+        }
+
+        let height = self.stacks[m.from - 1].len();
+        if m.count > height {
+            return Err(MoveError::TooFewCrates {
+                line: m.line,
+                stack: m.from,
+                count: m.count,
+                height,
+            });
+        }
+
+        self.transfer(m.count, m.from - 1, m.to - 1, m.move_type);
+        Ok(())
+    }
+
+    /// Reverses a move, which must be the last one applied.
+    pub fn undo_move(&mut self, m: &Move) {
+        let m = m.inverse();
+        self.transfer(m.count, m.from - 1, m.to - 1, m.move_type);
+    }
+
+    /// Moves count crates between stacks, indexed from 0, which must exist and
+    /// hold enough crates.
+    fn transfer(
+        &mut self,
+        count: usize,
+        from: usize,
+        to: usize,
+        move_type: MoveType,
+    ) {
+        // Crates moved onto the stack they came from end up as they were.
+        if from == to {
+            return;
+        }
+        let at = self.stacks[from].len() - count;
+        let mut buf = self.stacks[from].split_off(at);
+        match move_type {
+            // Restacking moves the crates one at a time, so the top one ends
+            // up at the bottom.
+            MoveType::Restack => buf.reverse(),
+            MoveType::Block => (),
+        }
+        self.stacks[to].append(&mut buf);
+    }
+
+    /// Returns the stacks, each from the bottom up.
+    pub fn stacks(&self) -> &[Vec<String>] {
+        &self.stacks
+    }
+
+    pub fn get_top_of_stacks(&self) -> Vec<Option<&String>> {
+        self.stacks.iter().map(|s| s.last()).collect()
+    }
+
+    fn max_depth(&self) -> usize {
+        self.stacks.iter().map(|s| s.len()).max().unwrap()
+    }
+}
+
+/// Splits a line into its words, each with the columns, counting chars, of its
+/// first and last characters.
+fn words(line: &str) -> Vec<(usize, usize, &str)> {
+    let mut words = Vec::new();
+    // The column and byte offset of the current word's start, if in one.
+    let mut start = None;
+    let mut chars = line.char_indices().enumerate().peekable();
+    while let Some((col, (at, c))) = chars.next() {
+        if c.is_whitespace() {
+            continue;
+        }
+        let (first_col, first_at) = *start.get_or_insert((col, at));
+        let ends = match chars.peek() {
+            None => true,
+            Some((_, (_, next))) => next.is_whitespace(),
+        };
+        if ends {
+            let next_at = at + c.len_utf8();
+            words.push((first_col, col, &line[first_at..next_at]));
+            start = None;
+        }
+    }
+    words
+}
+
+/// Writes the stacks and moves out as an input they could be parsed from.
+pub fn serialize(state: &State, moves: &[Move]) -> String {
+    let mut s = format!("{state}\n");
+    if !moves.is_empty() {
+        s.push('\n');
+    }
+    for m in moves {
+        s += &format!("move {} from {} to {}\n", m.count, m.from, m.to);
+    }
+    s
+}
+
+impl fmt::Display for State {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Every stack is as wide as the widest crate or number, so the crates
+        // and numbers centred in it line up. Each is followed by a space.
+        let width = self
+            .stacks
+            .iter()
+            .flatten()
+            .map(|c| c.chars().count() + 2)
+            .chain([3, self.stacks.len().to_string().len()])
+            .max()
+            .unwrap();
+
+        // Work from the top of the tallest stack down.
+        for i in (0..self.max_depth()).rev() {
+            for stack in &self.stacks {
+                match stack.get(i) {
+                    Some(c) => write!(f, "{:^width$} ", format!("[{c}]"))?,
+                    None => write!(f, "{:width$} ", "")?,
+                }
+            }
+            writeln!(f)?;
+        }
+
+        // Then number the stacks from 1.
+        for i in 1..=self.stacks.len() {
+            write!(f, "{i:^width$} ")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc2022::gen;
+    use aoc2022::utils::file::{
+        get_input_records, get_str_records, read_input,
+    };
+    use aoc2022::utils::fuzz::{
+        assert_parser_never_panics, parser_panics, ANY_TEXT,
+    };
+    use aoc2022::YEAR;
+    use proptest::prelude::*;
+
+    use super::*;
+
+    /// Makes stacks of one-letter crates, bottom first.
+    fn stacks(crates: &[&str]) -> Vec<Vec<String>> {
+        crates
+            .iter()
+            .map(|s| s.chars().map(String::from).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_records() {
+        let records = get_input_records(YEAR, "example/day05").unwrap();
+
+        let (s, ms) = parse_records(records, MoveType::Restack).unwrap();
+        eprintln!("{s}");
+        assert_eq!(s.stacks.len(), 3);
+        ms.iter().for_each(|m| eprintln!("{m}"));
+        assert_eq!(ms.len(), 4);
+
+        let bad = "    [D]\n[N] [C]\n 1   2 \n\nmove 1 from 2 to 1\nmove x\n";
+        let err = parse_records(get_str_records(bad), MoveType::Restack);
+        assert!(err.unwrap_err().to_string().starts_with("line 6: "));
+    }
+
+    /// Checks the input round-trips through parsing and serializing, and
+    /// returns what it parses to.
+    fn round_trip(input: &str) -> (State, Vec<Move>) {
+        let records = get_str_records(input);
+        let (state, moves) = parse_records(records, MoveType::Block).unwrap();
+        let printed = serialize(&state, &moves);
+        let records = get_str_records(&printed);
+        let (again, moves_again) =
+            parse_records(records, MoveType::Block).unwrap();
+        assert_eq!(again, state, "{printed}");
+        assert_eq!(serialize(&again, &moves_again), printed);
+        (state, moves)
+    }
+
+    #[test]
+    fn test_parse_diagrams() {
+        // The example with its lines trimmed.
+        let example = read_input(YEAR, "example/day05").unwrap();
+        let trimmed: String = example
+            .lines()
+            .map(|l| l.trim_end().to_string() + "\n")
+            .collect();
+        let (s, ms) = round_trip(&trimmed);
+        assert_eq!((s.clone(), ms.clone()), round_trip(&example));
+        assert_eq!(s.stacks, stacks(&["ZN", "MCD", "P"]));
+        assert_eq!(ms.len(), 4);
+
+        let (s, _) = round_trip(concat!(
+            "                                        [K]\n",
+            "[A]                                     [J] [L]\n",
+            " 1   2   3   4   5   6   7   8   9  10  11  12\n",
+        ));
+        assert_eq!(s.stacks.len(), 12);
+        assert_eq!(s.stacks[10], ["J", "K"]);
+
+        let (s, ms) = round_trip(concat!(
+            "       [Bob]\n",
+            "[A1] [Alice] [λ]\n",
+            " 1     2     3\n",
+            "\n",
+            "move 2 from 2 to 1\n",
+        ));
+        assert_eq!(s.stacks, [vec!["A1"], vec!["Alice", "Bob"], vec!["λ"]]);
+        assert_eq!(
+            serialize(&s, &ms),
+            concat!(
+                "         [Bob]          \n",
+                " [A1]   [Alice]   [λ]   \n",
+                "   1       2       3    \n",
+                "\n",
+                "move 2 from 2 to 1\n",
+            )
+        );
+
+        let err = |input| {
+            let records = get_str_records(input);
+            let err = parse_records(records, MoveType::Block).unwrap_err();
+            err.to_string()
+        };
+        assert_eq!(err("[A]\n 1   3\n"), "line 2: expected stack 2, got \"3\"");
+        assert_eq!(
+            err("[A] B\n 1   2\n"),
+            "line 1: expected a crate, got \"B\""
+        );
+        assert_eq!(
+            err("[A]\n     1\n"),
+            "line 1: crate [A] isn't over exactly one stack"
+        );
+        assert_eq!(
+            err("[ABCDE]\n 1 2\n"),
+            "line 1: crate [ABCDE] isn't over exactly one stack"
+        );
+        assert_eq!(
+            err("[A]\n    [B]\n 1   2\n"),
+            "line 2: nothing under a crate in stack 1"
+        );
+        assert!(err("[]\n 1\n").starts_with("line 1: expected a crate"));
+    }
+
+    #[test]
+    fn test_state_new_display_top_of_stacks_apply_move() {
+        let s = State::new([].into());
+        assert!(s.is_err());
+
+        let mut s = State::new(stacks(&["ABC", "D", "EF", "", "G"])).unwrap();
+        assert_eq!(s.stacks.len(), 5);
+        assert_eq!(s.stacks[0].len(), 3);
+        assert_eq!(s.stacks[3].len(), 0);
+
+        assert_eq!(
+            format!("{s}"),
+            concat!(
+                "[C]                 \n",
+                "[B]     [F]         \n",
+                "[A] [D] [E]     [G] \n",
+                " 1   2   3   4   5  ",
+            )
+            .to_string()
+        );
+
+        assert_eq!(
+            s.get_top_of_stacks()
+                .iter()
+                .map(|c| c.map(String::as_str))
+                .collect::<Vec<_>>(),
+            [Some("C"), Some("D"), Some("F"), None, Some("G")]
+        );
+
+        // Now try applying a move and check the stacks are OK.
+        s.apply_move(&Move {
+            count: 2,
+            from: 1,
+            to: 2,
+            move_type: MoveType::Restack,
+            line: 1,
+        })
+        .unwrap();
+
+        assert_eq!(s.stacks, stacks(&["A", "DCB", "EF", "", "G"]));
+
+        s.apply_move(&Move {
+            count: 2,
+            from: 2,
+            to: 1,
+            move_type: MoveType::Block,
+            line: 2,
+        })
+        .unwrap();
+
+        assert_eq!(s.stacks, stacks(&["ACB", "D", "EF", "", "G"]));
+
+        // Invalid moves leave the stacks alone.
+        let bad = |count, from, to| Move {
+            count,
+            from,
+            to,
+            move_type: MoveType::Block,
+            line: 7,
+        };
+        let before = s.clone();
+        assert_eq!(
+            s.apply_move(&bad(1, 1, 6)),
+            Err(MoveError::NoSuchStack {
+                line: 7,
+                stack: 6,
+                stacks: 5
+            })
+        );
+        assert!(s.apply_move(&bad(1, 0, 1)).is_err());
+        let err = s.apply_move(&bad(2, 4, 1)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 7: cannot move 2 crates from stack 4, which has 0"
+        );
+        assert_eq!(s, before);
+
+        // Restacking onto the same stack leaves it alone.
+        s.apply_move(&Move {
+            count: 3,
+            from: 1,
+            to: 1,
+            move_type: MoveType::Restack,
+            line: 8,
+        })
+        .unwrap();
+        assert_eq!(s, before);
+    }
+
+    #[test]
+    fn test_fuzz() {
+        let example = read_input(YEAR, "example/day05").unwrap();
+        assert_parser_never_panics("day05", &[&example], |input| {
+            parse_records(get_str_records(input), MoveType::Restack)
+        });
+    }
+
+    #[test]
+    fn test_generated() {
+        gen::for_each_input(5, gen::TEST_SIZES, |input| {
+            let num_crates = input.matches('[').count();
+
+            for move_type in [MoveType::Restack, MoveType::Block] {
+                let records = get_str_records(input);
+                let (mut s, ms) = parse_records(records, move_type).unwrap();
+                if move_type == MoveType::Block {
+                    round_trip(input);
+                }
+
+                for m in ms {
+                    s.apply_move(&m).unwrap();
+                }
+                assert_eq!(
+                    s.stacks.iter().map(|s| s.len()).sum::<usize>(),
+                    num_crates
+                );
+            }
+        });
+    }
+
+    /// A regex for inputs shaped like a drawing of stacks and its moves.
+    const INPUT_LIKE: &str = concat!(
+        r"((\[[A-Z]{0,2}\]? ?){0,4}\n){0,3}( ?[0-9]{0,2} {0,3}){0,4}\n\n?",
+        r"(move -?[0-9]{0,21} from [0-9]{0,3} to [0-9]{0,3}\n){0,4}",
+    );
+
+    proptest! {
+        #[test]
+        fn prop_parser_never_panics(
+            input in prop_oneof![ANY_TEXT, INPUT_LIKE],
+        ) {
+            for move_type in [MoveType::Restack, MoveType::Block] {
+                let parse =
+                    |s: &str| parse_records(get_str_records(s), move_type);
+                prop_assert!(!parser_panics(parse, &input));
+            }
+        }
+    }
+}
@@ -0,0 +1,247 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+
+use itertools::Itertools;
+
+use crate::dt::{Move, MoveType, State};
+
+/// A SolveError is why no moves were found from one State to another.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveError {
+    /// The States have different numbers of stacks.
+    StackCount { start: usize, target: usize },
+    /// The States don't have the same crates.
+    DifferentCrates,
+    /// No moves lead to the target at all.
+    Unreachable,
+    /// No list of at most this many moves leads to the target.
+    NotWithin(usize),
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolveError::StackCount { start, target } => {
+                write!(f, "can't turn {start} stacks into {target} stacks")
+            },
+            SolveError::DifferentCrates => {
+                write!(f, "the stacks don't hold the same crates")
+            },
+            SolveError::Unreachable => {
+                write!(f, "no moves lead to the target stacks")
+            },
+            SolveError::NotWithin(n) => {
+                write!(f, "no {n} or fewer moves lead to the target stacks")
+            },
+        }
+    }
+}
+
+impl Error for SolveError {}
+
+/// How a State was first reached in a search: None for where it started, or
+/// the State before and the move from it.
+type Parents = HashMap<State, Option<(State, Move)>>;
+
+/// Returns a shortest list of moves turning start into target, or why there
+/// isn't one of at most max_moves.
+///
+/// This searches breadth first from both ends at once, since every move can
+/// be undone by another, always growing the smaller frontier by one move.
+/// The first State both searches reach is on a shortest path, and once they
+/// have gone max_moves between them without meeting, there is none that
+/// short.
+pub fn shortest_moves(
+    start: &State,
+    target: &State,
+    move_type: MoveType,
+    max_moves: usize,
+) -> Result<Vec<Move>, SolveError> {
+    let (n, m) = (start.stacks().len(), target.stacks().len());
+    if n != m {
+        return Err(SolveError::StackCount {
+            start: n,
+            target: m,
+        });
+    }
+    if crates(start) != crates(target) {
+        return Err(SolveError::DifferentCrates);
+    }
+
+    if start == target {
+        return Ok(Vec::new());
+    }
+
+    // Index 0 is the forward search, from start, and 1 the backward one.
+    let mut seen: [Parents; 2] = [
+        HashMap::from([(start.clone(), None)]),
+        HashMap::from([(target.clone(), None)]),
+    ];
+    let mut frontiers = [vec![start.clone()], vec![target.clone()]];
+    for _ in 0..max_moves {
+        let side = usize::from(frontiers[0].len() > frontiers[1].len());
+        let mut next = Vec::new();
+        for state in &frontiers[side] {
+            for m in legal_moves(state, move_type) {
+                let mut after = state.clone();
+                after.apply_move(&m).expect("the move is legal");
+                if seen[side].contains_key(&after) {
+                    continue;
+                }
+                seen[side].insert(after.clone(), Some((state.clone(), m)));
+                if seen[1 - side].contains_key(&after) {
+                    return Ok(join(&seen[0], &seen[1], &after));
+                }
+                next.push(after);
+            }
+        }
+
+        // Everything reachable has been seen.
+        if next.is_empty() {
+            return Err(SolveError::Unreachable);
+        }
+        frontiers[side] = next;
+    }
+    Err(SolveError::NotWithin(max_moves))
+}
+
+/// Returns a State's crates, sorted.
+fn crates(state: &State) -> Vec<&String> {
+    state.stacks().iter().flatten().sorted().collect()
+}
+
+/// Returns every move that can be made from a State, except those moving
+/// crates onto the stack they came from, which change nothing.
+fn legal_moves(
+    state: &State,
+    move_type: MoveType,
+) -> impl Iterator<Item = Move> + '_ {
+    let n = state.stacks().len();
+    (1..=n).flat_map(move |from| {
+        let height = state.stacks()[from - 1].len();
+        (1..=n).filter(move |&to| to != from).flat_map(move |to| {
+            (1..=height).map(move |count| Move::new(count, from, to, move_type))
+        })
+    })
+}
+
+/// Returns the moves from the forward search's start to the backward
+/// search's, through a State both reached.
+fn join(forward: &Parents, backward: &Parents, meeting: &State) -> Vec<Move> {
+    let mut moves = Vec::new();
+    let mut state = meeting;
+    while let Some((before, m)) = &forward[state] {
+        moves.push(*m);
+        state = before;
+    }
+    moves.reverse();
+
+    // The backward search's moves lead towards the meeting State, so undo
+    // them.
+    let mut state = meeting;
+    while let Some((after, m)) = &backward[state] {
+        moves.push(m.inverse());
+        state = after;
+    }
+    moves
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc2022::gen;
+    use aoc2022::utils::file::{get_input_records, get_str_records};
+    use aoc2022::YEAR;
+
+    use super::*;
+    use crate::dt::parse_records;
+
+    /// Checks the moves turn start into target.
+    fn check(start: &State, target: &State, moves: &[Move]) {
+        let mut state = start.clone();
+        for m in moves {
+            state.apply_move(m).unwrap();
+        }
+        assert_eq!(&state, target);
+    }
+
+    fn parse(input: &str, move_type: MoveType) -> (State, Vec<Move>) {
+        parse_records(get_str_records(input), move_type).unwrap()
+    }
+
+    #[test]
+    fn test_example() {
+        for move_type in [MoveType::Restack, MoveType::Block] {
+            let records = get_input_records(YEAR, "example/day05").unwrap();
+            let (start, moves) = parse_records(records, move_type).unwrap();
+            let mut target = start.clone();
+            for m in &moves {
+                target.apply_move(m).unwrap();
+            }
+
+            let found = shortest_moves(&start, &target, move_type, 4).unwrap();
+            check(&start, &target, &found);
+            assert!(found.len() <= moves.len());
+            assert_eq!(
+                shortest_moves(&start, &target, move_type, found.len() - 1),
+                Err(SolveError::NotWithin(found.len() - 1))
+            );
+            assert_eq!(
+                shortest_moves(&start, &start, move_type, 0),
+                Ok(vec![])
+            );
+        }
+    }
+
+    #[test]
+    fn test_shortest() {
+        let (start, _) = parse("[A]\n[B]\n 1   2\n", MoveType::Block);
+        let (target, _) = parse("    [B]\n    [A]\n 1   2\n", MoveType::Block);
+        // Restacking both flips them, but moving them as a block doesn't.
+        let found = shortest_moves(&start, &target, MoveType::Restack, 5);
+        assert_eq!(found, Ok(vec![Move::new(2, 1, 2, MoveType::Restack)]));
+        let found =
+            shortest_moves(&start, &target, MoveType::Block, 5).unwrap();
+        assert_eq!(found.len(), 2);
+        check(&start, &target, &found);
+    }
+
+    #[test]
+    fn test_errors() {
+        let (start, _) = parse("[A]\n 1 \n", MoveType::Block);
+        let (other, _) = parse("[B]\n 1 \n", MoveType::Block);
+        let (wider, _) = parse("[A]\n 1   2\n", MoveType::Block);
+        let solve = |target| shortest_moves(&start, target, MoveType::Block, 3);
+        assert_eq!(solve(&other), Err(SolveError::DifferentCrates));
+        assert_eq!(
+            solve(&wider).unwrap_err().to_string(),
+            "can't turn 1 stacks into 2 stacks"
+        );
+
+        // With one stack, nothing can move.
+        let (start, _) = parse("[A]\n[B]\n 1 \n", MoveType::Block);
+        let (target, _) = parse("[B]\n[A]\n 1 \n", MoveType::Block);
+        assert_eq!(
+            shortest_moves(&start, &target, MoveType::Block, 3),
+            Err(SolveError::Unreachable)
+        );
+    }
+
+    #[test]
+    fn test_generated() {
+        gen::for_each_input(5, &[1, 2, 3], |input| {
+            for move_type in [MoveType::Restack, MoveType::Block] {
+                let (start, moves) = parse(input, move_type);
+                let mut target = start.clone();
+                for m in &moves {
+                    target.apply_move(m).unwrap();
+                }
+                let found =
+                    shortest_moves(&start, &target, move_type, moves.len())
+                        .unwrap();
+                assert!(found.len() <= moves.len());
+                check(&start, &target, &found);
+            }
+        });
+    }
+}
@@ -0,0 +1,93 @@
+#[macro_use]
+extern crate scan_fmt;
+
+use std::error::Error;
+
+use aoc2022::utils::file::{get_input_records, get_str_records};
+use aoc2022::utils::report::{stats_wanted, Report};
+use aoc2022::YEAR;
+
+mod dt;
+mod inverse;
+mod sim;
+
+/// How many of the input's first moves to check can't be done in fewer.
+const CHECKED_MOVES: usize = 3;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let mut report = Report::new(YEAR, 5)?;
+
+    let records = get_input_records(YEAR, "day05")?;
+    report.try_part(1, || solve(records, dt::MoveType::Restack))?;
+
+    let records = get_input_records(YEAR, "day05")?;
+    report.try_part(2, || solve(records, dt::MoveType::Block))?;
+
+    // With AOC_STATS, animate part two's moves, a frame at a time.
+    if stats_wanted() {
+        let records = get_input_records(YEAR, "day05")?;
+        let (state, moves) = dt::parse_records(records, dt::MoveType::Block)?;
+        let mut sim = sim::Simulator::new(state.clone(), moves);
+        for frame in sim.frames()? {
+            eprintln!("{frame}\n");
+        }
+
+        // Then check undoing the moves in turn gets back to the start, and
+        // that drawing the stacks and listing the moves gives the same input.
+        let moves = sim.history().to_vec();
+        sim.rewind();
+        if sim.state() != &state {
+            return Err(format!(
+                "undoing {} moves didn't restore the starting stacks",
+                moves.len()
+            )
+            .into());
+        }
+        let input = dt::serialize(&state, &moves);
+        let records = get_str_records(&input);
+        if dt::parse_records(records, dt::MoveType::Block)?.0 != state {
+            return Err("the stacks didn't survive being redrawn".into());
+        }
+
+        // Finally, see if the first few moves could have been shorter.
+        let checked = &moves[..moves.len().min(CHECKED_MOVES)];
+        let mut after = state.clone();
+        for m in checked {
+            after.apply_move(m)?;
+        }
+        let shortest = inverse::shortest_moves(
+            &state,
+            &after,
+            dt::MoveType::Block,
+            checked.len(),
+        )?;
+        eprintln!(
+            "the first {} moves could be done in {}",
+            checked.len(),
+            shortest.len()
+        );
+    }
+
+    Ok(())
+}
+
+use std::io;
+
+use aoc2022::utils::file::InputRecord;
+use itertools::Itertools;
+
+pub fn solve(
+    records: impl Iterator<Item = Result<InputRecord, io::Error>>,
+    move_type: dt::MoveType,
+) -> Result<String, Box<dyn Error>> {
+    let (state, moves) = dt::parse_records(records, move_type)?;
+    let mut sim = sim::Simulator::new(state, moves);
+    sim.run()?;
+
+    Ok(sim
+        .state()
+        .get_top_of_stacks()
+        .iter()
+        .filter_map(|o| o.as_ref())
+        .join(""))
+}
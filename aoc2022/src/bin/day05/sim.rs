@@ -0,0 +1,165 @@
+use crate::dt::{Move, MoveError, State};
+
+/// A Simulator replays a list of moves on the stacks, remembering how far it
+/// has got so it can step forward and back through them.
+pub struct Simulator {
+    state: State,
+    moves: Vec<Move>,
+    /// How many of the moves have been applied.
+    applied: usize,
+}
+
+impl Simulator {
+    pub fn new(state: State, moves: Vec<Move>) -> Self {
+        Simulator {
+            state,
+            moves,
+            applied: 0,
+        }
+    }
+
+    pub fn state(&self) -> &State {
+        &self.state
+    }
+
+    /// Returns the moves applied so far, in order.
+    pub fn history(&self) -> &[Move] {
+        &self.moves[..self.applied]
+    }
+
+    /// Applies the next move, returning it, or None if there are none left.
+    /// If it can't be applied, the Simulator stays where it was.
+    pub fn step(&mut self) -> Result<Option<&Move>, MoveError> {
+        let m = match self.moves.get(self.applied) {
+            None => return Ok(None),
+            Some(m) => m,
+        };
+        self.state.apply_move(m)?;
+        self.applied += 1;
+        Ok(Some(m))
+    }
+
+    /// Reverses the last move applied, returning it, or None if none have
+    /// been.
+    pub fn undo(&mut self) -> Option<&Move> {
+        if self.applied == 0 {
+            return None;
+        }
+        self.applied -= 1;
+        let m = &self.moves[self.applied];
+        self.state.undo_move(m);
+        Some(m)
+    }
+
+    /// Applies all the remaining moves.
+    pub fn run(&mut self) -> Result<(), MoveError> {
+        while self.step()?.is_some() {}
+        Ok(())
+    }
+
+    /// Undoes every move applied, back to the starting stacks.
+    pub fn rewind(&mut self) {
+        while self.undo().is_some() {}
+    }
+
+    /// Applies all the remaining moves, returning a frame of the animation
+    /// for the stacks as they are now and after each move: a caption, then
+    /// the stacks drawn as in the input.
+    pub fn frames(&mut self) -> Result<Vec<String>, MoveError> {
+        let mut frames =
+            vec![format!("after {} moves:\n{}", self.applied, self.state)];
+        while let Some(m) = self.step()? {
+            let caption = format!("line {}: {m}", m.line());
+            frames.push(format!("{caption}\n{}", self.state));
+        }
+        Ok(frames)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc2022::gen;
+    use aoc2022::utils::file::{get_input_records, get_str_records};
+    use aoc2022::YEAR;
+
+    use super::*;
+    use crate::dt::{parse_records, MoveType};
+
+    fn example(move_type: MoveType) -> Simulator {
+        let records = get_input_records(YEAR, "example/day05").unwrap();
+        let (state, moves) = parse_records(records, move_type).unwrap();
+        Simulator::new(state, moves)
+    }
+
+    #[test]
+    fn test_step_and_undo() {
+        let mut sim = example(MoveType::Restack);
+        let start = sim.state().clone();
+        assert_eq!(sim.undo(), None);
+
+        assert_eq!(sim.step().unwrap().map(|m| m.line()), Some(6));
+        let after_one = sim.state().clone();
+        sim.run().unwrap();
+        assert_eq!(sim.history().len(), 4);
+        assert_eq!(sim.step(), Ok(None));
+
+        for _ in 0..3 {
+            sim.undo().unwrap();
+        }
+        assert_eq!(sim.state(), &after_one);
+        sim.rewind();
+        assert_eq!(sim.state(), &start);
+        assert!(sim.history().is_empty());
+    }
+
+    #[test]
+    fn test_frames() {
+        let mut sim = example(MoveType::Block);
+        let frames = sim.frames().unwrap();
+        assert_eq!(frames.len(), 5);
+        assert!(frames[0].starts_with("after 0 moves:\n"));
+        assert_eq!(
+            frames[4],
+            concat!(
+                "line 9: Move: 1 [1]--> 2\n",
+                "        [D] \n",
+                "        [N] \n",
+                "        [Z] \n",
+                "[M] [C] [P] \n",
+                " 1   2   3  ",
+            )
+        );
+    }
+
+    #[test]
+    fn test_invalid_move() {
+        let input = "[A]\n 1 \n\nmove 1 from 1 to 1\nmove 2 from 1 to 1\n";
+        let records = get_str_records(input);
+        let (state, moves) = parse_records(records, MoveType::Block).unwrap();
+        let mut sim = Simulator::new(state, moves);
+        assert_eq!(
+            sim.run(),
+            Err(MoveError::TooFewCrates {
+                line: 5,
+                stack: 1,
+                count: 2,
+                height: 1
+            })
+        );
+        assert_eq!(sim.history().len(), 1);
+    }
+
+    #[test]
+    fn test_generated() {
+        gen::for_each_input(5, gen::TEST_SIZES, |input| {
+            for move_type in [MoveType::Restack, MoveType::Block] {
+                let records = get_str_records(input);
+                let (state, moves) = parse_records(records, move_type).unwrap();
+                let mut sim = Simulator::new(state.clone(), moves);
+                sim.run().unwrap();
+                sim.rewind();
+                assert_eq!(sim.state(), &state);
+            }
+        });
+    }
+}
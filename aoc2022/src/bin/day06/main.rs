@@ -0,0 +1,106 @@
+mod stream;
+
+use std::error::Error;
+use std::io::Read;
+
+use aoc2022::utils::file::get_input_reader;
+use aoc2022::utils::report::{stats_wanted, Report};
+use aoc2022::YEAR;
+use itertools::Itertools;
+
+/// The sizes of the start-of-packet and start-of-message markers.
+const PACKET: usize = 4;
+const MESSAGE: usize = 14;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let mut report = Report::new(YEAR, 6)?;
+
+    report.try_part(1, || solve(get_input_reader(YEAR, "day06")?, PACKET))?;
+    report.try_part(2, || solve(get_input_reader(YEAR, "day06")?, MESSAGE))?;
+
+    // Every marker, not just the first, listed in full with AOC_STATS.
+    for n in [PACKET, MESSAGE] {
+        let found: Vec<usize> =
+            stream::markers(get_input_reader(YEAR, "day06")?, n)
+                .collect::<Result<_, _>>()?;
+        eprintln!("{} markers of {n} characters", found.len());
+        if stats_wanted() {
+            eprintln!("{}", found.iter().join(" "));
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the position of the first marker of n characters in a datastream,
+/// counting from 1, reading no more of it than it has to.
+pub fn solve(reader: impl Read, n: usize) -> Result<usize, Box<dyn Error>> {
+    match stream::markers(reader, n).next() {
+        None => Err("no marker found".into()),
+        Some(pos) => Ok(pos?),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc2022::gen;
+    use aoc2022::utils::fuzz::{parser_panics, ANY_TEXT};
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        let first = |s: &str, n| solve(s.as_bytes(), n).ok();
+        assert_eq!(first("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 4), Some(7));
+        assert_eq!(first("bvwbjplbgvbhsrlpgdmjqwftvncz", 4), Some(5));
+        assert_eq!(first("nppdvjthqldpwncqszvftbrmjlhg", 4), Some(6));
+        assert_eq!(first("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 4), Some(10));
+        assert_eq!(first("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 4), Some(11));
+
+        assert_eq!(first("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 14), Some(19));
+        assert_eq!(first("bvwbjplbgvbhsrlpgdmjqwftvncz", 14), Some(23));
+        assert_eq!(first("nppdvjthqldpwncqszvftbrmjlhg", 14), Some(23));
+        assert_eq!(first("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 14), Some(29));
+        assert_eq!(first("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 14), Some(26));
+
+        assert_eq!(first("", 0), None);
+        assert_eq!(first("", 1), None);
+        assert_eq!(first("a", 1), Some(1));
+        assert_eq!(first("a", 2), None);
+
+        let err = solve("abc".as_bytes(), 4).unwrap_err();
+        assert_eq!(err.to_string(), "no marker found");
+    }
+
+    #[test]
+    fn test_generated() {
+        gen::for_each_input(6, gen::TEST_SIZES, |input| {
+            let input = input.trim_end();
+
+            let packet = solve(input.as_bytes(), 4).ok();
+            if let Some(i) = packet {
+                assert!(input[i - 4..i].chars().all_unique());
+            }
+            if let Ok(i) = solve(input.as_bytes(), 14) {
+                assert!(input[i - 14..i].chars().all_unique());
+                assert!(packet.unwrap() <= i - 10);
+            }
+        });
+    }
+
+    /// A regex for inputs shaped like a datastream.
+    const INPUT_LIKE: &str = r"[a-z]{0,40}\n?";
+
+    proptest! {
+        #[test]
+        fn prop_parser_never_panics(
+            input in prop_oneof![ANY_TEXT, INPUT_LIKE],
+        ) {
+            for n in [0, 1, 4, 14] {
+                let marker = |s: &str| solve(s.as_bytes(), n);
+                prop_assert!(!parser_panics(marker, &input));
+            }
+        }
+    }
+}
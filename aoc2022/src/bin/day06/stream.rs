@@ -0,0 +1,228 @@
+use std::io::{self, BufReader, Bytes, Read};
+
+use aoc2022::utils::error::invalid_data_err;
+
+/// A Detector watches a datastream a byte at a time for markers: runs of n
+/// bytes that are all different.
+///
+/// It keeps the last n bytes, how many of each byte value are among them,
+/// and how many values appear more than once, so each byte takes O(1) time
+/// however large n is.
+pub struct Detector {
+    /// The last n bytes, as a ring buffer, and where the next one goes.
+    window: Vec<u8>,
+    next: usize,
+    /// How many bytes have been pushed.
+    pushed: usize,
+    counts: [usize; 256],
+    repeated: usize,
+}
+
+impl Detector {
+    pub fn new(n: usize) -> Self {
+        Detector {
+            window: vec![0; n],
+            next: 0,
+            pushed: 0,
+            counts: [0; 256],
+            repeated: 0,
+        }
+    }
+
+    /// Adds the next byte of the stream, returning whether it ends a marker.
+    pub fn push(&mut self, b: u8) -> bool {
+        self.pushed += 1;
+        let n = self.window.len();
+        if n == 0 {
+            return true;
+        }
+
+        if self.pushed > n {
+            let old = self.window[self.next] as usize;
+            self.counts[old] -= 1;
+            if self.counts[old] == 1 {
+                self.repeated -= 1;
+            }
+        }
+        self.counts[b as usize] += 1;
+        if self.counts[b as usize] == 2 {
+            self.repeated += 1;
+        }
+        self.window[self.next] = b;
+        self.next = (self.next + 1) % n;
+
+        self.pushed >= n && self.repeated == 0
+    }
+
+    /// Returns how many bytes have been pushed.
+    pub fn pushed(&self) -> usize {
+        self.pushed
+    }
+}
+
+/// The iterator returned by markers.
+pub struct Markers<R> {
+    bytes: Bytes<BufReader<R>>,
+    detector: Detector,
+    /// Whether the line has ended, so only more line endings may follow.
+    ended: bool,
+    /// Whether an error has been returned, ending the iterator.
+    failed: bool,
+}
+
+/// Reads a datastream of one line, returning the position of every marker of
+/// n bytes in it: how many bytes have been read when it ends, counting from 1.
+/// Only the current window is held, so memory use doesn't grow with the
+/// stream.
+pub fn markers<R: Read>(reader: R, n: usize) -> Markers<R> {
+    Markers {
+        bytes: BufReader::new(reader).bytes(),
+        detector: Detector::new(n),
+        ended: false,
+        failed: false,
+    }
+}
+
+impl<R: Read> Iterator for Markers<R> {
+    type Item = Result<usize, io::Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        loop {
+            let b = match self.bytes.next()? {
+                Ok(b) => b,
+                Err(e) => {
+                    self.failed = true;
+                    return Some(Err(e));
+                },
+            };
+            match b {
+                b'\n' | b'\r' => self.ended = true,
+                _ if self.ended => {
+                    self.failed = true;
+                    return Some(Err(invalid_data_err(
+                        "expected a single line of input",
+                    )));
+                },
+                _ => {
+                    if self.detector.push(b) {
+                        return Some(Ok(self.detector.pushed()));
+                    }
+                },
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::time::Instant;
+
+    use aoc2022::gen;
+    use aoc2022::utils::iter::distinct_windows;
+    use aoc2022::utils::rng::Rng;
+    use itertools::Itertools;
+
+    use super::*;
+
+    fn all(s: &str, n: usize) -> Vec<usize> {
+        markers(s.as_bytes(), n).map(Result::unwrap).collect()
+    }
+
+    /// Returns every marker's position by checking each window in turn.
+    fn naive(s: &[u8], n: usize) -> Vec<usize> {
+        (n.max(1)..=s.len())
+            .filter(|&end| s[end - n..end].iter().all_unique())
+            .collect()
+    }
+
+    #[test]
+    fn test_markers() {
+        assert_eq!(all("abcabb\n", 3), [3, 4, 5]);
+        assert_eq!(all("aabb", 2), [3]);
+        assert_eq!(all("ab", 3), []);
+        assert_eq!(all("abab", 1), [1, 2, 3, 4]);
+        assert_eq!(all("ab", 0), [1, 2]);
+        assert_eq!(all("a\r\n\n", 1), [1]);
+
+        let err = markers("ab\ncd".as_bytes(), 2).collect_vec();
+        assert_eq!(err.len(), 2);
+        assert_eq!(
+            err[1].as_ref().unwrap_err().to_string(),
+            "expected a single line of input"
+        );
+    }
+
+    #[test]
+    fn test_generated() {
+        gen::for_each_input(6, gen::TEST_SIZES, |input| {
+            let line = input.trim_end().as_bytes();
+            // Every byte of the signal is read: each one ends a 1-wide window.
+            assert_eq!(all(input, 1), (1..=line.len()).collect_vec());
+            for n in [1, 4, 14, 20] {
+                assert_eq!(all(input, n), naive(line, n));
+                let windows = distinct_windows(line.iter(), n)
+                    .positions(|distinct| distinct)
+                    .map(|i| i + 1)
+                    .collect_vec();
+                assert_eq!(all(input, n), windows);
+            }
+        });
+    }
+
+    /// Times finding every marker in `AOC_BENCH_BYTES` (default 64 MiB) of
+    /// random bytes, for window sizes up to 10,000, to check the time taken
+    /// doesn't grow with the window. Run with:
+    ///
+    /// `cargo test --release -p aoc2022 --bin day06 -- --ignored
+    /// --test-threads=1 --nocapture`
+    #[test]
+    #[ignore]
+    fn bench_window_sizes() {
+        let bytes: usize = match env::var("AOC_BENCH_BYTES") {
+            Ok(n) => n.parse().unwrap(),
+            Err(_) => 64 << 20,
+        };
+
+        // Any byte but a line ending, so windows of up to 254 can be markers.
+        let mut rng = Rng::new(6);
+        let input: Vec<u8> = (0..bytes)
+            .map(|_| match rng.below(256) as u8 {
+                b'\n' | b'\r' => b'a',
+                b => b,
+            })
+            .collect();
+
+        // Check a prefix against the naive approach first.
+        for n in [4, 14, 100] {
+            let prefix = &input[..1 << 16];
+            let found = markers(prefix, n).map(Result::unwrap).collect_vec();
+            assert_eq!(found, naive(prefix, n), "n = {n}");
+        }
+
+        let mut rates = Vec::new();
+        for n in [4, 14, 100, 1_000, 10_000] {
+            let start = Instant::now();
+            let found = markers(&input[..], n).map(Result::unwrap).count();
+            let elapsed = start.elapsed();
+            let rate = bytes as f64 / (1 << 20) as f64 / elapsed.as_secs_f64();
+            eprintln!(
+                "n = {n:>6}: {found:>9} markers in {elapsed:.2?} \
+                ({rate:.0} MiB/s)"
+            );
+            rates.push(rate);
+        }
+        let (slowest, fastest) = rates
+            .iter()
+            .fold((f64::MAX, 0.0f64), |(lo, hi), &r| (lo.min(r), hi.max(r)));
+        // Rescanning each window would make n = 10,000 thousands of times
+        // slower than n = 4, so this leaves plenty of room for noise.
+        assert!(
+            fastest < 10.0 * slowest,
+            "throughput varied from {slowest:.0} to {fastest:.0} MiB/s"
+        );
+    }
+}
@@ -0,0 +1,113 @@
+use std::error::Error;
+use std::io;
+
+use aoc2022::utils::file::get_input_lines;
+use aoc2022::utils::report::Report;
+use aoc2022::YEAR;
+
+use crate::dt::Dir;
+use crate::parse::{parse_lines, Token};
+
+mod dt;
+mod parse;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let mut report = Report::new(YEAR, 7)?;
+
+    let mut tree =
+        report.parse(|| get_tree(get_input_lines(YEAR, "day07")?))?;
+    let total_size = tree.update_contents_size();
+    eprintln!("{tree}");
+    eprintln!("total size: {total_size}");
+
+    report.part(1, || tree.sum_dir_size_lte(100_000));
+
+    let delete_target = total_size - 40_000_000;
+    eprintln!("try delete {delete_target} bytes");
+    report.part(2, || tree.smallest_dir_size_gte(delete_target));
+
+    Ok(())
+}
+
+fn get_tree(
+    lines: impl Iterator<Item = Result<String, io::Error>>,
+) -> Result<dt::Dir, Box<dyn Error>> {
+    let mut path: Vec<String> = vec![];
+    let mut root = Dir::new();
+    let mut cwd = &mut root;
+
+    for cmd in parse_lines(lines) {
+        match cmd? {
+            Token::Cd(dir) => match dir.as_str() {
+                ".." => {
+                    path.pop().unwrap();
+                    cwd = root.cd(&path);
+                },
+                dir => {
+                    cwd = cwd.cd(&[dir.to_string()]);
+                    path.push(dir.to_string());
+                },
+            },
+            Token::Ls => (),
+            Token::Dir(_) => (),
+            Token::File(size, name) => cwd.add_file(name, size),
+        };
+    }
+
+    eprintln!("final path was {}", path.join("\\"));
+
+    Ok(root)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc2022::gen;
+    use aoc2022::utils::file::get_str_lines;
+    use itertools::Itertools;
+
+    use super::*;
+
+    #[test]
+    fn test_generated() {
+        gen::for_each_input(7, gen::TEST_SIZES, |input| {
+            let mut tree = get_tree(get_str_lines(input)).unwrap();
+
+            let file_sizes: u64 = input
+                .lines()
+                .filter_map(|l| l.split_once(' '))
+                .filter_map(|(size, _)| size.parse::<u64>().ok())
+                .sum();
+            let total_size = tree.update_contents_size();
+            assert_eq!(total_size, file_sizes);
+
+            // The tree holds exactly the entries listed, below a "/" dir: its
+            // printed entries, written back as ls output, match the session's.
+            let listed = input
+                .lines()
+                .filter(|l| !l.starts_with('$'))
+                .chain(["dir /"])
+                .map(str::to_string)
+                .sorted()
+                .collect_vec();
+            let printed = tree
+                .to_string()
+                .lines()
+                .map(|l| {
+                    let (name, kind) =
+                        l.trim_start()[2..].split_once(' ').unwrap();
+                    match kind.strip_prefix("(file, size=") {
+                        Some(size) => {
+                            format!("{} {name}", &size[..size.len() - 1])
+                        },
+                        None => format!("dir {name}"),
+                    }
+                })
+                .sorted()
+                .collect_vec();
+            assert_eq!(printed, listed);
+
+            let delete_target = total_size - 40_000_000;
+            assert!(tree.smallest_dir_size_gte(delete_target) <= total_size);
+        });
+    }
+}
@@ -39,7 +39,10 @@ impl str::FromStr for Token {
         }
 
         if let Some(caps) = RE_FILE.captures_iter(s).next() {
-            let size: u64 = str::parse(caps.get(1).unwrap().into()).unwrap();
+            let size: u64 =
+                str::parse(caps.get(1).unwrap().into()).map_err(|e| {
+                    parse_error(format!("bad file size in {s}: {e}").as_str())
+                })?;
             let name = caps.get(2).unwrap().as_str();
             return Ok(Token::File(size, name.to_string()));
         }
@@ -62,6 +65,13 @@ pub fn parse_lines(
 
 #[cfg(test)]
 mod tests {
+    use aoc2022::utils::file::read_input;
+    use aoc2022::utils::fuzz::{
+        assert_parser_never_panics, parser_panics, ANY_TEXT,
+    };
+    use aoc2022::YEAR;
+    use proptest::prelude::*;
+
     use super::*;
 
     #[test]
@@ -80,4 +90,37 @@ mod tests {
             Token::File(1234, "bar".to_string())
         );
     }
+
+    #[test]
+    fn test_fuzz() {
+        assert!(str::parse::<Token>("18446744073709551616 big").is_err());
+
+        let example = read_input(YEAR, "example/day07").unwrap();
+        assert_parser_never_panics("day07", &[&example], |input| {
+            input
+                .lines()
+                .map(str::parse::<Token>)
+                .collect::<Result<Vec<_>, _>>()
+        });
+    }
+
+    /// A regex for inputs shaped like a terminal session.
+    const INPUT_LIKE: &str = concat!(
+        r"((\$ (cd|ls) ?[a-z/.]{0,3}|dir ?[a-z]{0,3}|",
+        r"-?[0-9]{0,21} ?[a-z.]{0,4})\n){0,8}",
+    );
+
+    proptest! {
+        #[test]
+        fn prop_parser_never_panics(
+            input in prop_oneof![ANY_TEXT, INPUT_LIKE],
+        ) {
+            let tokens = |s: &str| {
+                s.lines()
+                    .map(str::parse::<Token>)
+                    .collect::<Result<Vec<_>, _>>()
+            };
+            prop_assert!(!parser_panics(tokens, &input));
+        }
+    }
 }
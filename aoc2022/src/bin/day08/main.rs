@@ -5,12 +5,23 @@ use aoc2022::types::digit::Digit;
 use aoc2022::types::grid::Grid;
 use aoc2022::utils::file::get_input_lines;
 use aoc2022::utils::iter;
+use aoc2022::utils::report::Report;
+use aoc2022::YEAR;
 
 mod parse;
 
 fn main() -> Result<(), Box<dyn Error>> {
-    let grid = parse::parse_input(get_input_lines("day08")?)?;
+    let mut report = Report::new(YEAR, 8)?;
 
+    let grid =
+        report.parse(|| parse::parse_input(get_input_lines(YEAR, "day08")?))?;
+    report.part(1, || solve_part1(&grid));
+    report.part(2, || solve_part2(&grid));
+
+    Ok(())
+}
+
+fn solve_part1(grid: &Grid<Digit>) -> usize {
     // Part 1: create a grid of bools for the visible trees, iterating in all
     // four directions (← → ↑ ↓), setting visibility whenever we can see a tree
     // from the edge before encountering a taller tree.
@@ -21,14 +32,13 @@ fn main() -> Result<(), Box<dyn Error>> {
     update_visibility_grid(&mut visibility_grid, grid.iter_rev_cols());
 
     // Then count the number of visible trees.
-    println!(
-        "Part one: {}",
-        visibility_grid
-            .enumerate()
-            .filter_map(|(_, ok)| if *ok { Some(()) } else { None })
-            .count()
-    );
+    visibility_grid
+        .enumerate()
+        .filter_map(|(_, ok)| if *ok { Some(()) } else { None })
+        .count()
+}
 
+fn solve_part2(grid: &Grid<Digit>) -> u32 {
     // Part 2: for each tree, count the number of trees before finding one of
     // equal or greater size, and multiply the numbers from each direction to
     // get the visibility score. Find the largest such score.
@@ -75,9 +85,7 @@ fn main() -> Result<(), Box<dyn Error>> {
             score_right * score_left * score_up * score_down,
         );
     }
-    println!("Part two: {largest_score}");
-
-    Ok(())
+    largest_score
 }
 
 fn update_visibility_grid<'a>(
@@ -94,3 +102,41 @@ fn update_visibility_grid<'a>(
             })
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use aoc2022::gen;
+    use aoc2022::utils::file::get_str_lines;
+    use aoc2022::utils::fuzz::{parser_panics, ANY_TEXT};
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_generated() {
+        gen::for_each_input(8, gen::TEST_SIZES, |input| {
+            let grid = parse::parse_input(get_str_lines(input)).unwrap();
+            let n = input.lines().count();
+            assert_eq!((grid.width(), grid.height()), (n, n));
+            assert_eq!(grid.to_string(), input);
+
+            // Every tree on the edge is visible.
+            let edge = if n == 1 { 1 } else { 4 * n - 4 };
+            assert!((edge..=n * n).contains(&solve_part1(&grid)));
+            solve_part2(&grid);
+        });
+    }
+
+    /// A regex for inputs shaped like a grid of tree heights.
+    const INPUT_LIKE: &str = r"([0-9]{0,5}\n){0,5}";
+
+    proptest! {
+        #[test]
+        fn prop_parser_never_panics(
+            input in prop_oneof![ANY_TEXT, INPUT_LIKE],
+        ) {
+            let grid = |s: &str| parse::parse_input(get_str_lines(s));
+            prop_assert!(!parser_panics(grid, &input));
+        }
+    }
+}
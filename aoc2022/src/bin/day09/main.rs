@@ -0,0 +1,35 @@
+#[macro_use]
+extern crate scan_fmt;
+
+use std::error::Error;
+
+use aoc2022::utils::file::get_input_lines;
+use aoc2022::utils::report::Report;
+use aoc2022::YEAR;
+use parse::{Movement, State};
+
+mod parse;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let mut report = Report::new(YEAR, 9)?;
+
+    let moves: Vec<Movement> =
+        report.parse(|| -> Result<_, Box<dyn Error>> {
+            let mut moves = Vec::new();
+            for line in get_input_lines(YEAR, "day09")? {
+                moves.push(line?.as_str().try_into()?);
+            }
+            Ok(moves)
+        })?;
+
+    for (part, knots) in [(1, 2), (2, 10)] {
+        report.part(part, || {
+            let mut s = State::new(knots);
+            moves.iter().for_each(|m| s.do_move(*m));
+            eprintln!("{s}");
+            s.count_visited()
+        });
+    }
+
+    Ok(())
+}
@@ -163,6 +163,10 @@ fn clamp_01(a: i32) -> i32 {
 
 #[cfg(test)]
 mod tests {
+    use aoc2022::gen;
+    use aoc2022::utils::fuzz::{parser_panics, ANY_TEXT};
+    use proptest::prelude::*;
+
     use super::*;
 
     #[test]
@@ -206,4 +210,49 @@ mod tests {
         println!("{s}");
         assert_eq!(s.count_visited(), 36);
     }
+
+    #[test]
+    fn test_generated() {
+        gen::for_each_input(9, gen::TEST_SIZES, |input| {
+            for line in input.lines() {
+                let Movement(d, n) = line.try_into().unwrap();
+                let letter = match d {
+                    Direction::Up => 'U',
+                    Direction::Down => 'D',
+                    Direction::Left => 'L',
+                    Direction::Right => 'R',
+                };
+                assert_eq!(format!("{letter} {n}"), line);
+            }
+
+            let visited: Vec<usize> = [2, 10]
+                .into_iter()
+                .map(|knots| {
+                    let mut s = State::new(knots);
+                    for line in input.lines() {
+                        s.do_move(line.try_into().unwrap());
+                    }
+                    s.count_visited()
+                })
+                .collect();
+            assert!(1 <= visited[1] && visited[1] <= visited[0]);
+        });
+    }
+
+    /// A regex for inputs shaped like a list of motions.
+    const INPUT_LIKE: &str = r"([UDLRudlr]? ?-?[0-9]{0,12}\n){0,5}";
+
+    proptest! {
+        #[test]
+        fn prop_parser_never_panics(
+            input in prop_oneof![ANY_TEXT, INPUT_LIKE],
+        ) {
+            let motions = |s: &str| {
+                s.lines()
+                    .map(Movement::try_from)
+                    .collect::<Result<Vec<_>, _>>()
+            };
+            prop_assert!(!parser_panics(motions, &input));
+        }
+    }
 }
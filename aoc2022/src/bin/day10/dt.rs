@@ -2,6 +2,12 @@ use std::collections::BTreeMap;
 use std::error::Error;
 use std::fmt::{self, Write};
 
+use aoc2022::types::grid::Grid;
+
+/// The CRT is 40 pixels wide and 6 high, drawing one pixel per cycle.
+const CRT_WIDTH: u32 = 40;
+const CRT_HEIGHT: u32 = 6;
+
 pub enum Instruction {
     Noop,
     AddX(i32),
@@ -95,6 +101,28 @@ impl Machine {
         }
         Ok(s)
     }
+
+    /// Returns the pixels drawn on the CRT over its first 240 cycles, with true
+    /// for a lit pixel.
+    pub fn get_screen(&self) -> Result<Grid<bool>, Box<dyn Error>> {
+        let mut screen =
+            Grid::new(false, CRT_WIDTH.try_into()?, CRT_HEIGHT.try_into()?);
+        for cycle in 1..=(CRT_WIDTH * CRT_HEIGHT) {
+            let x = self
+                .get_at(cycle)
+                .ok_or("simulation didn't run long enough")?;
+            let pos = (cycle - 1) % CRT_WIDTH;
+            let row = (cycle - 1) / CRT_WIDTH;
+            let pos_i: i32 = pos.try_into()?;
+
+            if ((pos_i - 1)..=(pos_i + 1)).contains(&x) {
+                *screen
+                    .get_mut(pos.try_into()?, row.try_into()?)
+                    .ok_or("pixel out of range")? = true;
+            }
+        }
+        Ok(screen)
+    }
 }
 
 impl fmt::Display for Machine {
@@ -120,7 +148,12 @@ impl fmt::Display for Machine {
 
 #[cfg(test)]
 mod tests {
-    use aoc2022::utils::file::get_input_lines;
+    use aoc2022::gen;
+    use aoc2022::utils::file::{get_input_lines, get_str_lines};
+    use aoc2022::utils::fuzz::{parser_panics, ANY_TEXT};
+    use aoc2022::YEAR;
+    use itertools::Itertools;
+    use proptest::prelude::*;
 
     use crate::parse::parse_lines;
 
@@ -129,7 +162,7 @@ mod tests {
     #[test]
     fn test_machine() {
         let mut machine = Machine::new();
-        let lines = get_input_lines("example/day10").unwrap();
+        let lines = get_input_lines(YEAR, "example/day10").unwrap();
         parse_lines(lines).for_each(|instr| machine.exec(instr.unwrap()));
         println!("{machine:?}");
 
@@ -139,5 +172,51 @@ mod tests {
         assert_eq!(machine.get_at(140), Some(21));
         assert_eq!(machine.get_at(180), Some(16));
         assert_eq!(machine.get_at(220), Some(18));
+
+        let screen = machine.get_screen().unwrap();
+        assert_eq!(
+            screen
+                .iter_rows()
+                .next()
+                .unwrap()
+                .map(|(_, p)| *p)
+                .collect_vec(),
+            "##..##..##..##..##..##..##..##..##..##.."
+                .chars()
+                .map(|c| c == '#')
+                .collect_vec()
+        );
+    }
+
+    #[test]
+    fn test_generated() {
+        gen::for_each_input(10, &[0, 1, 10, 1000], |input| {
+            let mut machine = Machine::new();
+            for (line, instr) in
+                input.lines().zip(parse_lines(get_str_lines(input)))
+            {
+                let instr = instr.unwrap();
+                assert_eq!(instr.to_string(), line);
+                machine.exec(instr);
+            }
+
+            assert!(machine.get_part1_score().is_ok());
+            assert!(machine.get_screen().is_ok());
+        });
+    }
+
+    /// A regex for inputs shaped like a program.
+    const INPUT_LIKE: &str = r"((noop|addx ?-?[0-9]{0,12})\n){0,8}";
+
+    proptest! {
+        #[test]
+        fn prop_parser_never_panics(
+            input in prop_oneof![ANY_TEXT, INPUT_LIKE],
+        ) {
+            let program = |s: &str| {
+                parse_lines(get_str_lines(s)).collect::<Result<Vec<_>, _>>()
+            };
+            prop_assert!(!parser_panics(program, &input));
+        }
     }
 }
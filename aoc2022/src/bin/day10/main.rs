@@ -0,0 +1,31 @@
+#[macro_use]
+extern crate scan_fmt;
+
+use std::error::Error;
+
+use aoc2022::utils::file::get_input_lines;
+use aoc2022::utils::ocr;
+use aoc2022::utils::report::Report;
+use aoc2022::YEAR;
+
+mod dt;
+mod parse;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let mut report = Report::new(YEAR, 10)?;
+
+    let machine = report.parse(|| -> Result<_, Box<dyn Error>> {
+        let mut machine = dt::Machine::new();
+        for instr in parse::parse_lines(get_input_lines(YEAR, "day10")?) {
+            machine.exec(instr?);
+        }
+        Ok(machine)
+    })?;
+
+    report.try_part(1, || machine.get_part1_score())?;
+    report.try_part(2, || -> Result<_, Box<dyn Error>> {
+        Ok(ocr::recognise(&machine.get_screen()?)?)
+    })?;
+
+    Ok(())
+}
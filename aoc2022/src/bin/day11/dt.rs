@@ -0,0 +1,487 @@
+use std::collections::VecDeque;
+use std::error::Error;
+use std::fmt::Write;
+use std::{cmp, fmt, io};
+
+use aoc2022::utils::file::InputRecord;
+use aoc2022::utils::int::{ArithError, Int};
+use num::integer::lcm;
+
+#[derive(Debug)]
+enum Op {
+    Add(u32),
+    Mul(u32),
+    Square,
+}
+
+impl Op {
+    fn apply<T: Int>(
+        &self,
+        worry_level: &T,
+        modulus: Option<&T>,
+    ) -> Result<T, ArithError> {
+        // Clever trick: we're only concerned with remainders. So use the LCM
+        // of all the monkey's divisors, and use that to restrict the range of
+        // possible values.
+        let x = match self {
+            Self::Add(n) => worry_level.try_add(&T::try_from_i64((*n).into())?),
+            Self::Mul(n) => worry_level.try_mul(&T::try_from_i64((*n).into())?),
+            Self::Square => worry_level.try_mul(worry_level),
+        }?;
+        Ok(match modulus {
+            Some(m) => x.mod_floor(m),
+            None => x,
+        })
+    }
+}
+
+impl fmt::Display for Op {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "new = old {} {}",
+            match self {
+                Self::Square | Self::Mul(_) => '*',
+                Self::Add(_) => '+',
+            },
+            match self {
+                Self::Add(n) | Self::Mul(n) => n.to_string(),
+                Self::Square => "old".to_string(),
+            }
+        )
+    }
+}
+
+#[derive(Debug)]
+struct Test {
+    divisor: u8,
+    if_true: usize,
+    if_false: usize,
+}
+
+impl Test {
+    fn get_throw<T: Int>(&self, val: &T) -> usize {
+        match val.is_multiple_of(&self.divisor.into()) {
+            true => self.if_true,
+            false => self.if_false,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Monkey<T> {
+    items: VecDeque<T>,
+    op: Op,
+    test: Test,
+    num_inspected: u32,
+}
+
+impl<T: Int> Monkey<T> {
+    /// Parses the notes on the n'th monkey.
+    fn parse_record(
+        record: &InputRecord,
+        n: usize,
+    ) -> Result<Self, Box<dyn Error>> {
+        // A missing line is reported after the one before it, or on the
+        // record itself if it's empty, as between two blank lines.
+        let line = |i: usize, expected: &str| {
+            record.lines().get(i).ok_or_else(|| match i {
+                0 => record.err(format!("expected {expected}")),
+                _ => {
+                    record.line_err(i - 1, format!("expected {expected} next"))
+                },
+            })
+        };
+
+        // This line should be "Monkey N"
+        let num = scan_fmt!(line(0, r#""Monkey N""#)?, "Monkey {d}", usize)
+            .map_err(|_| record.line_err(0, r#"expected "Monkey N""#))?;
+        if num != n {
+            return Err(record.line_err(0, "wrong monkey number").into());
+        }
+
+        // This line should be "  Starting items: ..."
+        let items = line(1, r#""Starting items""#)?
+            .strip_prefix("  Starting items: ")
+            .ok_or_else(|| {
+                record.line_err(1, r#"couldn't parse "Starting items""#)
+            })?
+            .split(", ")
+            .map(T::parse_int)
+            .collect::<Result<VecDeque<T>, _>>()
+            .map_err(|e| record.line_err(1, e))?;
+
+        let op = line(2, r#""Operation: ...""#)?
+            .strip_prefix("  Operation: new = old ")
+            .ok_or_else(|| {
+                record.line_err(2, "unable to parse Operation: bad prefix")
+            })?;
+        let op = if let Some(rhs) = op.strip_prefix("* ") {
+            if rhs == "old" {
+                Op::Square
+            } else {
+                Op::Mul(rhs.parse().map_err(|e| record.line_err(2, e))?)
+            }
+        } else if let Some(rhs) = op.strip_prefix("+ ") {
+            Op::Add(rhs.parse().map_err(|e| record.line_err(2, e))?)
+        } else {
+            return Err(record
+                .line_err(2, "unable to parse Operation: bad suffix")
+                .into());
+        };
+
+        let divisor =
+            scan_fmt!(line(3, r#""Test""#)?, "Test: divisible by {d}", u8)
+                .map_err(|_| record.line_err(3, "unable to parse Test line"))?;
+        if divisor == 0 {
+            return Err(record
+                .line_err(3, "can't test divisibility by 0")
+                .into());
+        }
+
+        let if_true = scan_fmt!(
+            line(4, r#""If true""#)?,
+            "If true: throw to monkey {d}",
+            usize
+        )
+        .map_err(|_| record.line_err(4, "unable to parse If true line"))?;
+
+        let if_false = scan_fmt!(
+            line(5, r#""If false""#)?,
+            "If false: throw to monkey {d}",
+            usize
+        )
+        .map_err(|_| record.line_err(5, "unable to parse If false line"))?;
+
+        // A monkey throwing to itself would inspect the same item forever.
+        for (i, to) in [(4, if_true), (5, if_false)] {
+            if to == n {
+                return Err(record
+                    .line_err(i, "a monkey can't throw to itself")
+                    .into());
+            }
+        }
+
+        if record.lines().len() > 6 {
+            return Err(record
+                .line_err(6, "expected a blank line after the monkey")
+                .into());
+        }
+
+        Ok(Monkey {
+            items,
+            op,
+            test: Test {
+                divisor,
+                if_true,
+                if_false,
+            },
+            num_inspected: 0,
+        })
+    }
+
+    fn fmt_indent(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "  Items: {:?}", self.items)?;
+        writeln!(f, "  Operation: {}", self.op)?;
+        writeln!(f, "  Test: divisible by {}", self.test.divisor)?;
+        writeln!(f, "    If true: throw to monkey {}", self.test.if_true)?;
+        writeln!(f, "    If false: throw to monkey {}", self.test.if_false)?;
+        Ok(())
+    }
+}
+
+/// The state of the monkeys, with worry levels of type T. A u128 is plenty for
+/// puzzle inputs with the usual rules, but BigUint lets worry levels grow
+/// without limit, e.g. to check the LCM trick.
+#[derive(Debug)]
+pub struct State<T> {
+    monkeys: Vec<Monkey<T>>,
+    relief_factor: T,
+    // The LCM of the divisors, if worry levels can be kept modulo it.
+    modulus: Option<T>,
+}
+
+impl<T: Int> State<T> {
+    /// Parses the monkeys' notes, one record per monkey.
+    pub fn try_parse_from(
+        records: impl Iterator<Item = Result<InputRecord, io::Error>>,
+        relief_factor: u64,
+    ) -> Result<Self, Box<dyn Error>> {
+        let mut ms: Vec<Monkey<T>> = Vec::new();
+        for record in records {
+            ms.push(Monkey::parse_record(&record?, ms.len())?);
+        }
+
+        // Check the throw values are all in range.
+        let valid_range = 0..ms.len();
+        for m in ms.iter() {
+            if !valid_range.contains(&m.test.if_true)
+                || !valid_range.contains(&m.test.if_false)
+            {
+                return Err(parse_err(
+                    "found a if true/false index that was out of range",
+                ));
+            }
+        }
+
+        // Calculate the LCM. Reducing modulo it doesn't commute with dividing
+        // by the relief factor, so it can only be used if there's no relief.
+        let modulus = match relief_factor {
+            1 => T::from_u64(
+                ms.iter()
+                    .map(|m| m.test.divisor)
+                    .fold(1, |a, b| lcm(a, b as u64)),
+            ),
+            _ => None,
+        };
+        let relief_factor = T::from_u64(relief_factor)
+            .filter(|r| !r.is_zero())
+            .ok_or_else(|| parse_err("bad relief factor"))?;
+
+        Ok(State {
+            monkeys: ms,
+            relief_factor,
+            modulus,
+        })
+    }
+
+    /// Stops worry levels being kept modulo the LCM of the divisors, so they're
+    /// tracked exactly even without relief.
+    #[cfg(test)]
+    pub fn without_modulus(mut self) -> Self {
+        self.modulus = None;
+        self
+    }
+
+    pub fn step(&mut self) -> Result<(), ArithError> {
+        for i in 0..self.monkeys.len() {
+            while let Some(item) = self.monkeys[i].items.pop_front() {
+                let this = &mut self.monkeys[i];
+                this.num_inspected += 1;
+
+                let worry_level = this
+                    .op
+                    .apply(&item, self.modulus.as_ref())?
+                    .try_div(&self.relief_factor)?;
+                let throw_to = this.test.get_throw(&worry_level);
+                self.monkeys[throw_to].items.push_back(worry_level);
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn print_items_thrown(&self) {
+        for (i, m) in self.monkeys.iter().enumerate() {
+            eprintln!("Monkey {i} inspected items {} times", m.num_inspected);
+        }
+    }
+
+    /// Returns the product of the two largest numbers of items inspected, or
+    /// an error if there are fewer than two monkeys.
+    pub fn monkey_business_value(&self) -> Result<u64, Box<dyn Error>> {
+        let mut x = self
+            .monkeys
+            .iter()
+            .map(|m| u64::from(m.num_inspected))
+            .collect::<Vec<u64>>();
+        if x.len() < 2 {
+            return Err(format!(
+                "monkey business needs at least 2 monkeys, found {}",
+                x.len()
+            )
+            .into());
+        }
+        x.sort_unstable_by_key(|x| cmp::Reverse(*x));
+        // Both fit in a u32, so their product can't overflow.
+        Ok(x[0] * x[1])
+    }
+}
+
+impl<T: Int> fmt::Display for State<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (n, m) in self.monkeys.iter().enumerate() {
+            writeln!(f, "Monkey {n}")?;
+            m.fmt_indent(f)?;
+            f.write_char('\n')?;
+        }
+        match &self.modulus {
+            Some(m) => write!(f, "Group LCM: {m}"),
+            None => write!(f, "No group LCM"),
+        }
+    }
+}
+
+fn parse_err(msg: &'static str) -> Box<dyn Error> {
+    use aoc2022::utils::error::parse_error;
+
+    Box::new(parse_error(msg))
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc2022::gen;
+    use aoc2022::utils::file::{
+        get_input_records, get_str_records, read_input,
+    };
+    use aoc2022::utils::fuzz::{parser_panics, ANY_TEXT};
+    use aoc2022::YEAR;
+    use num::BigUint;
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let records = get_input_records(YEAR, "example/day11").unwrap();
+        let mut state = State::<u128>::try_parse_from(records, 3).unwrap();
+        assert_eq!(state.monkeys.len(), 4);
+        for _ in 0..20 {
+            state.step().unwrap();
+        }
+        assert_eq!(state.monkey_business_value().unwrap(), 10605);
+    }
+
+    #[test]
+    fn test_bad_notes() {
+        let example = read_input(YEAR, "example/day11").unwrap();
+        let bad = example.replacen("If false: throw", "If false: toss", 1);
+        let err = State::<u128>::try_parse_from(get_str_records(&bad), 3)
+            .unwrap_err();
+        assert_eq!(err.to_string(), "line 6: unable to parse If false line");
+
+        let short =
+            example.replacen("    If false: throw to monkey 3\n", "", 1);
+        let err = State::<u128>::try_parse_from(get_str_records(&short), 3)
+            .unwrap_err();
+        assert_eq!(err.to_string(), r#"line 5: expected "If false" next"#);
+
+        let zero = example.replacen("divisible by 23", "divisible by 0", 1);
+        let err = State::<u128>::try_parse_from(get_str_records(&zero), 1)
+            .unwrap_err();
+        assert_eq!(err.to_string(), "line 4: can't test divisibility by 0");
+
+        let own = example.replacen("throw to monkey 2", "throw to monkey 0", 1);
+        let err = State::<u128>::try_parse_from(get_str_records(&own), 1)
+            .unwrap_err();
+        assert_eq!(err.to_string(), "line 5: a monkey can't throw to itself");
+
+        // With no monkeys there's nothing to multiply.
+        let none = State::<u128>::try_parse_from(get_str_records(""), 3);
+        assert_eq!(
+            none.unwrap()
+                .monkey_business_value()
+                .unwrap_err()
+                .to_string(),
+            "monkey business needs at least 2 monkeys, found 0"
+        );
+
+        // Blank lines at the start or doubled give empty records.
+        for (bad, line) in [
+            (format!("\n{example}"), 1),
+            (example.replacen("\n\n", "\n\n\n", 1), 8),
+        ] {
+            let err = State::<u128>::try_parse_from(get_str_records(&bad), 3)
+                .unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                format!(r#"record at line {line}: expected "Monkey N""#)
+            );
+        }
+    }
+
+    #[test]
+    fn test_without_modulus() {
+        // Without relief or the LCM trick, worry levels outgrow a u128 after
+        // about 30 rounds, but a BigUint tracks them exactly, and gives the
+        // same answer as the trick does.
+        let rounds = 60;
+        fn state<T: Int>(modulus: bool) -> State<T> {
+            let records = get_input_records(YEAR, "example/day11").unwrap();
+            let state = State::try_parse_from(records, 1).unwrap();
+            match modulus {
+                true => state,
+                false => state.without_modulus(),
+            }
+        }
+
+        let mut small: State<u128> = state(false);
+        assert_eq!(
+            (0..rounds).try_for_each(|_| small.step()),
+            Err(ArithError::Overflow)
+        );
+
+        let mut big: State<BigUint> = state(false);
+        let mut trick: State<u128> = state(true);
+        for _ in 0..rounds {
+            big.step().unwrap();
+            trick.step().unwrap();
+        }
+        assert_eq!(big.monkey_business_value().unwrap(), 304 * 301);
+        assert_eq!(trick.monkey_business_value().unwrap(), 304 * 301);
+    }
+
+    #[test]
+    fn test_generated() {
+        // Without relief, worry levels are kept small. With it, they're
+        // tracked exactly, so may overflow, but must not panic.
+        gen::for_each_input(11, &[0, 1, 5, 10], |input| {
+            let records = get_str_records(input);
+            let mut state = State::<u128>::try_parse_from(records, 1).unwrap();
+            assert_eq!(state.monkeys.len(), input.matches("Monkey").count());
+
+            // The notes written back from the parsed monkeys match the input.
+            let mut printed = String::new();
+            for (n, m) in state.monkeys.iter().enumerate() {
+                if n > 0 {
+                    printed.push('\n');
+                }
+                let items: Vec<String> =
+                    m.items.iter().map(|i| i.to_string()).collect();
+                writeln!(printed, "Monkey {n}:").unwrap();
+                writeln!(printed, "  Starting items: {}", items.join(", "))
+                    .unwrap();
+                writeln!(printed, "  Operation: {}", m.op).unwrap();
+                writeln!(printed, "  Test: divisible by {}", m.test.divisor)
+                    .unwrap();
+                let (t, f) = (m.test.if_true, m.test.if_false);
+                writeln!(printed, "    If true: throw to monkey {t}").unwrap();
+                writeln!(printed, "    If false: throw to monkey {f}").unwrap();
+            }
+            assert_eq!(printed, input);
+
+            for _ in 0..100 {
+                state.step().unwrap();
+            }
+            state.monkey_business_value().unwrap();
+
+            let records = get_str_records(input);
+            let mut state = State::<u128>::try_parse_from(records, 3).unwrap();
+            for _ in 0..100 {
+                if state.step().is_err() {
+                    break;
+                }
+            }
+        });
+    }
+
+    /// A regex for inputs shaped like the notes on the monkeys.
+    const INPUT_LIKE: &str = concat!(
+        r"(Monkey [0-9]{0,2}:\n  Starting items: ([0-9]{0,40}(, )?){0,3}\n",
+        r"  Operation: new = old [*+/] (old|-?[0-9]{0,4})\n",
+        r"  Test: divisible by -?[0-9]{0,4}\n",
+        r"    If true: throw to monkey [0-9]{0,2}\n",
+        r"    If false: throw to monkey [0-9]{0,2}\n\n?){0,3}",
+    );
+
+    proptest! {
+        #[test]
+        fn prop_parser_never_panics(
+            input in prop_oneof![ANY_TEXT, INPUT_LIKE],
+        ) {
+            let notes =
+                |s: &str| State::<u128>::try_parse_from(get_str_records(s), 3);
+            prop_assert!(!parser_panics(notes, &input));
+        }
+    }
+}
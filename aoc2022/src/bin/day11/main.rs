@@ -0,0 +1,48 @@
+use std::error::Error;
+
+use aoc2022::utils::file::get_input_records;
+use aoc2022::utils::int::{bigint_mode, Int};
+use aoc2022::utils::report::Report;
+use aoc2022::YEAR;
+use dt::State;
+use num::BigUint;
+
+#[macro_use]
+extern crate scan_fmt;
+
+mod dt;
+#[cfg(test)]
+mod reference;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let mut report = Report::new(YEAR, 11)?;
+
+    if bigint_mode() {
+        solve::<BigUint>(&mut report)
+    } else {
+        solve::<u128>(&mut report)
+    }
+}
+
+/// Solves both parts, tracking worry levels as T.
+fn solve<T: Int>(report: &mut Report) -> Result<(), Box<dyn Error>> {
+    for (part, relief_factor, rounds) in [(1, 3, 20), (2, 1, 10_000)] {
+        let mut state = report.parse(|| {
+            State::<T>::try_parse_from(
+                get_input_records(YEAR, "day11")?,
+                relief_factor,
+            )
+        })?;
+        eprintln!("{state}");
+
+        report.try_part(part, || {
+            for _ in 0..rounds {
+                state.step()?;
+            }
+            state.print_items_thrown();
+            state.monkey_business_value()
+        })?;
+    }
+
+    Ok(())
+}
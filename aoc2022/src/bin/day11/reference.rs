@@ -0,0 +1,136 @@
+//! A slow reference solver, which tracks exact worry levels as big integers
+//! rather than relying on the LCM trick. Used to check dt::State.
+
+use std::cmp;
+
+use num::{BigUint, Integer, Zero};
+
+struct Monkey {
+    items: Vec<BigUint>,
+    op: Box<dyn Fn(&BigUint) -> BigUint>,
+    divisor: BigUint,
+    if_true: usize,
+    if_false: usize,
+}
+
+/// Returns the monkey business after the given number of rounds. Panics on bad
+/// input, or if a worry level grows beyond max_bits bits.
+pub fn monkey_business(
+    input: &str,
+    relief_factor: u32,
+    rounds: u32,
+    max_bits: u64,
+) -> u64 {
+    let mut monkeys: Vec<Monkey> =
+        input.split("\n\n").enumerate().map(parse).collect();
+    let mut inspected = vec![0u64; monkeys.len()];
+
+    for _ in 0..rounds {
+        for i in 0..monkeys.len() {
+            for item in std::mem::take(&mut monkeys[i].items) {
+                inspected[i] += 1;
+
+                let m = &monkeys[i];
+                let item = (m.op)(&item) / relief_factor;
+                assert!(item.bits() <= max_bits, "worry level too large");
+                let to = match item.is_multiple_of(&m.divisor) {
+                    true => m.if_true,
+                    false => m.if_false,
+                };
+                monkeys[to].items.push(item);
+            }
+        }
+    }
+
+    inspected.sort_by_key(|n| cmp::Reverse(*n));
+    inspected[0] * inspected[1]
+}
+
+/// Parses the notes on the nth monkey, panicking if they're malformed.
+fn parse((n, notes): (usize, &str)) -> Monkey {
+    let lines: Vec<&str> = notes.lines().collect();
+    assert_eq!(lines.len(), 6, "expected six lines of notes");
+    assert_eq!(lines[0], format!("Monkey {n}:"));
+
+    let field = |i: usize, prefix: &str| {
+        lines[i]
+            .strip_prefix(prefix)
+            .unwrap_or_else(|| panic!("expected {prefix:?}"))
+    };
+
+    let items = field(1, "  Starting items: ")
+        .split(", ")
+        .map(|n| n.parse().unwrap())
+        .collect();
+
+    let op: Box<dyn Fn(&BigUint) -> BigUint> =
+        match field(2, "  Operation: new = old ").split_once(' ').unwrap() {
+            ("*", "old") => Box::new(|old| old * old),
+            ("*", n) => {
+                let n: BigUint = n.parse().unwrap();
+                Box::new(move |old| old * &n)
+            },
+            ("+", n) => {
+                let n: BigUint = n.parse().unwrap();
+                Box::new(move |old| old + &n)
+            },
+            (op, _) => panic!("unknown op {op}"),
+        };
+
+    let divisor: BigUint = field(3, "  Test: divisible by ").parse().unwrap();
+    assert!(!divisor.is_zero());
+
+    Monkey {
+        items,
+        op,
+        divisor,
+        if_true: field(4, "    If true: throw to monkey ").parse().unwrap(),
+        if_false: field(5, "    If false: throw to monkey ").parse().unwrap(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc2022::gen;
+    use aoc2022::utils::file::{get_str_records, read_input};
+    use aoc2022::utils::test::assert_no_divergence;
+    use aoc2022::YEAR;
+
+    use super::*;
+    use crate::dt::State;
+
+    /// Runs the optimised solver as main does.
+    fn optimised(input: &str, relief_factor: u64, rounds: u32) -> u64 {
+        let records = get_str_records(input);
+        let mut state =
+            State::<u128>::try_parse_from(records, relief_factor).unwrap();
+        for _ in 0..rounds {
+            state.step().unwrap();
+        }
+        state.monkey_business_value().unwrap()
+    }
+
+    #[test]
+    fn test_example() {
+        let input = read_input(YEAR, "example/day11").unwrap();
+        assert_eq!(monkey_business(&input, 3, 20, 128), 10605);
+        assert_eq!(monkey_business(&input, 1, 20, 1 << 14), 99 * 103);
+    }
+
+    #[test]
+    fn test_differential() {
+        // With relief, the optimised solver tracks exact worry levels as u128s,
+        // so can't check inputs where they grow any bigger. Without, it keeps
+        // them small, so they may grow as far as is practical.
+        for (relief_factor, rounds, max_bits) in
+            [(3, 20, 128), (1, 20, 1 << 14)]
+        {
+            assert_no_divergence(
+                gen::generator(11),
+                &[2, 3, 4, 6, 8],
+                |input| monkey_business(input, relief_factor, rounds, max_bits),
+                |input| optimised(input, relief_factor.into(), rounds),
+            );
+        }
+    }
+}
@@ -128,12 +128,13 @@ impl HMap {
                 let p: Point = (c, (x, y)).try_into()?;
                 idx_row.push(grid.add_node(p));
 
-                if c == 'S' {
-                    assert!(start.is_none());
-                    start = Some((x, y));
-                } else if c == 'E' {
-                    assert!(end.is_none());
-                    end = Some((x, y));
+                let seen = match c {
+                    'S' => start.replace((x, y)),
+                    'E' => end.replace((x, y)),
+                    _ => None,
+                };
+                if seen.is_some() {
+                    return Err(format!("more than one {c} in map").into());
                 }
             }
 
@@ -164,8 +165,8 @@ impl HMap {
         Ok(Self {
             idx_grid,
             grid,
-            start: start.unwrap(),
-            end: end.unwrap(),
+            start: start.ok_or("no S in map")?,
+            end: end.ok_or("no E in map")?,
         })
     }
 }
@@ -179,3 +180,70 @@ impl fmt::Display for HMap {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use aoc2022::gen;
+    use aoc2022::utils::file::get_str_lines;
+    use aoc2022::utils::fuzz::{parser_panics, ANY_TEXT};
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_errors() {
+        let err = |input: &str| {
+            HMap::parse_from_lines(get_str_lines(input))
+                .unwrap_err()
+                .to_string()
+        };
+        assert_eq!(err(""), "no S in map");
+        assert_eq!(err("Sab\n"), "no E in map");
+        assert_eq!(err("SbE\nSbc\n"), "more than one S in map");
+        assert_eq!(err("SbE\nabE\n"), "more than one E in map");
+    }
+
+    #[test]
+    fn test_generated() {
+        gen::for_each_input(12, &[0, 1, 10, 30], |input| {
+            let map = HMap::parse_from_lines(get_str_lines(input)).unwrap();
+            let width = input.lines().next().unwrap().len();
+            assert_eq!(map.idx_grid.width(), width);
+
+            // Writing each point's height back, with S and E at the start and
+            // end, gives the input.
+            let mut printed = String::new();
+            for row in map.idx_grid.iter_rows() {
+                for (pos, idx) in row {
+                    let point = map.grid.node_weight(*idx).unwrap();
+                    assert_eq!(point.pos, pos);
+                    match pos {
+                        p if p == map.start => printed.push('S'),
+                        p if p == map.end => printed.push('E'),
+                        _ => write!(printed, "{point}").unwrap(),
+                    }
+                }
+                printed.push('\n');
+            }
+            assert_eq!(printed, input);
+
+            // There's always a straight path from S to E.
+            let part_one = map.find_part_one_dist();
+            assert!(part_one < width);
+            assert!(map.find_part_two_dist() as usize <= part_one);
+        });
+    }
+
+    /// A regex for inputs shaped like a heightmap.
+    const INPUT_LIKE: &str = r"([a-zSE]{0,6}\n){0,5}";
+
+    proptest! {
+        #[test]
+        fn prop_parser_never_panics(
+            input in prop_oneof![ANY_TEXT, INPUT_LIKE],
+        ) {
+            let map = |s: &str| HMap::parse_from_lines(get_str_lines(s));
+            prop_assert!(!parser_panics(map, &input));
+        }
+    }
+}
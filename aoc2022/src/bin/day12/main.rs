@@ -0,0 +1,20 @@
+use std::error::Error;
+
+use aoc2022::utils::file::get_input_lines;
+use aoc2022::utils::report::Report;
+use aoc2022::YEAR;
+
+use crate::dt::HMap;
+
+mod dt;
+mod parse;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let mut report = Report::new(YEAR, 12)?;
+
+    let map = report
+        .parse(|| HMap::parse_from_lines(get_input_lines(YEAR, "day12")?))?;
+    report.part(1, || map.find_part_one_dist());
+    report.part(2, || map.find_part_two_dist());
+    Ok(())
+}
@@ -0,0 +1,305 @@
+use std::fmt::{Display, Write};
+use std::iter::Peekable;
+
+use aoc2022::utils::error::{parse_error, ParseError};
+
+#[derive(Debug)]
+pub enum MaybeVec {
+    One(u32),
+    Vec(Vec<MaybeVec>),
+}
+
+impl Display for MaybeVec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::One(n) => write!(f, "{n}"),
+            Self::Vec(v) => {
+                f.write_char('[')?;
+                for (i, e) in v.iter().enumerate() {
+                    e.fmt(f)?;
+                    if i < v.len() - 1 {
+                        f.write_str(", ")?;
+                    }
+                }
+                f.write_char(']')
+            },
+        }
+    }
+}
+
+impl PartialEq for MaybeVec {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::One(l), Self::One(r)) => l == r,
+            (Self::Vec(l), Self::Vec(r)) => l == r,
+            (Self::One(l), Self::Vec(r)) => {
+                let l = vec![Self::One(*l)];
+                &l == r
+            },
+            (Self::Vec(l), Self::One(r)) => {
+                let r = vec![Self::One(*r)];
+                l == &r
+            },
+        }
+    }
+}
+
+impl Eq for MaybeVec {}
+
+impl PartialOrd for MaybeVec {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for MaybeVec {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        match (self, other) {
+            (Self::One(a), Self::One(b)) => a.cmp(b),
+            (Self::Vec(a), Self::Vec(b)) => a.cmp(b),
+            (Self::One(a), Self::Vec(b)) => {
+                let a: Vec<MaybeVec> = vec![Self::One(*a)];
+                a.cmp(b)
+            },
+            (Self::Vec(a), Self::One(b)) => {
+                let b: Vec<MaybeVec> = vec![Self::One(*b)];
+                a.cmp(&b)
+            },
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+enum Token {
+    Open,
+    Close,
+    Number(u32),
+}
+
+impl Display for Token {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Open => f.write_char('['),
+            Self::Close => f.write_char(']'),
+            Self::Number(n) => write!(f, "{n}"),
+        }
+    }
+}
+
+/// Lists can't be nested any deeper than this, so malformed input can't
+/// overflow the stack.
+const MAX_DEPTH: usize = 256;
+
+pub fn parse(input: &str) -> Result<MaybeVec, ParseError> {
+    TokenIter::new(input.chars()).parse()
+}
+
+struct TokenIter<T: Iterator<Item = char>>(Peekable<T>);
+
+impl<T: Iterator<Item = char>> TokenIter<T> {
+    fn new(iter: T) -> Self {
+        Self(iter.peekable())
+    }
+
+    fn parse(mut self) -> Result<MaybeVec, ParseError> {
+        match self.next_token()? {
+            Token::Open => {
+                let r = MaybeVec::Vec(self.consume_vec(1)?);
+                match self.next() {
+                    None => Ok(r),
+                    Some(t) => Err(parse_error(
+                        format!("unexpected token {} at end of stream", t?)
+                            .as_str(),
+                    )),
+                }
+            },
+            t => Err(parse_error(
+                format!("expected [ at start of stream, got {t}").as_str(),
+            )),
+        }
+    }
+
+    fn consume_vec(
+        &mut self,
+        depth: usize,
+    ) -> Result<Vec<MaybeVec>, ParseError> {
+        // Parses from self until a closing bracket. Assumes the opening bracket
+        // has been consumed.
+        if depth > MAX_DEPTH {
+            return Err(parse_error("lists nested too deeply"));
+        }
+
+        let mut res: Vec<MaybeVec> = Vec::new();
+        loop {
+            let next = match self.next_token()? {
+                Token::Close => return Ok(res),
+                Token::Open => MaybeVec::Vec(self.consume_vec(depth + 1)?),
+                Token::Number(n) => MaybeVec::One(n),
+            };
+            res.push(next);
+        }
+    }
+
+    /// As next, but treats the end of input as an error.
+    fn next_token(&mut self) -> Result<Token, ParseError> {
+        self.next()
+            .unwrap_or_else(|| Err(parse_error("unexpected end of input")))
+    }
+}
+
+impl<T: Iterator<Item = char>> Iterator for TokenIter<T> {
+    type Item = Result<Token, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(Ok(match self.0.next()? {
+            '[' => Token::Open,
+            ']' => {
+                // Also consume a trailing ','.
+                self.0.next_if_eq(&',');
+                Token::Close
+            },
+            c @ '0'..='9' => {
+                let mut n = (c as u32) - ('0' as u32);
+                loop {
+                    let next_digit = match self.0.peek() {
+                        None => break,
+                        Some(c) => match c {
+                            '0'..='9' => {
+                                (self.0.next().unwrap() as u32) - ('0' as u32)
+                            },
+                            ',' => {
+                                self.0.next();
+                                break;
+                            },
+                            ']' => break,
+                            c => {
+                                return Some(Err(parse_error(
+                                    format!(
+                                        "unexpected char '{c}' (expected digit or ,)"
+                                    )
+                                    .as_str(),
+                                )))
+                            },
+                        },
+                    };
+                    n = match n
+                        .checked_mul(10)
+                        .and_then(|n| n.checked_add(next_digit))
+                    {
+                        Some(n) => n,
+                        None => {
+                            return Some(Err(parse_error(
+                                "number overflowed u32",
+                            )))
+                        },
+                    };
+                }
+                Token::Number(n)
+            },
+            c => {
+                return Some(Err(parse_error(
+                    format!(
+                        "unexpected char '{c}' (expected bracket or number)"
+                    )
+                    .as_str(),
+                )))
+            },
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc2022::gen;
+    use aoc2022::utils::file::read_input;
+    use aoc2022::utils::fuzz::{
+        assert_parser_never_panics, parser_panics, ANY_TEXT,
+    };
+    use aoc2022::YEAR;
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            parse("[1,2,3,[123,5,[]]]").unwrap(),
+            MaybeVec::Vec(vec![
+                MaybeVec::One(1),
+                MaybeVec::One(2),
+                MaybeVec::One(3),
+                MaybeVec::Vec(vec![
+                    MaybeVec::One(123),
+                    MaybeVec::One(5),
+                    MaybeVec::Vec(vec![]),
+                ]),
+            ])
+        );
+    }
+
+    fn p(input: &str) -> MaybeVec {
+        parse(input).unwrap()
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        for input in ["", "1", "[1", "[1]]", "[1a]", "[4294967296]", "]"] {
+            assert!(parse(input).is_err(), "{input}");
+        }
+        assert!(parse(&"[".repeat(100_000)).is_err());
+    }
+
+    #[test]
+    fn test_fuzz() {
+        let example = read_input(YEAR, "example/day13").unwrap();
+        assert_parser_never_panics("day13", &[&example], |input| {
+            input.lines().map(parse).collect::<Result<Vec<_>, _>>()
+        });
+    }
+
+    #[test]
+    fn test_cmp() {
+        assert!(p("[1]") < p("[2]"));
+        assert!(p("[1,2]") < p("[2,2]"));
+        assert!(p("[1]") < p("[1,0]"));
+        assert!(p("[1]") == p("[[1]]"));
+        assert!(p("[1,2]") == p("[[1],2]"));
+        assert!(p("[1,2]") < p("[[1],3]"));
+        assert!(p("[1,3]") > p("[[1],2]"));
+
+        assert!(p("[1,1,3,1,1]") < p("[1,1,5,1,1]"));
+        assert!(p("[[1],[2,3,4]]") < p("[[1],4]"));
+        assert!(p("[9]") > p("[[8,7,6]]"));
+        assert!(p("[[4,4],4,4]") < p("[[4,4],4,4,4]"));
+        assert!(p("[7,7,7,7]") > p("[7,7,7]"));
+        assert!(p("[]") < p("[3]"));
+        assert!(p("[[[]]]") > p("[[]]"));
+        assert!(
+            p("[1,[2,[3,[4,[5,6,7]]]],8,9]") > p("[1,[2,[3,[4,[5,6,0]]]],8,9]")
+        );
+    }
+
+    #[test]
+    fn test_generated() {
+        gen::for_each_input(13, gen::TEST_SIZES, |input| {
+            for line in input.lines().filter(|l| !l.is_empty()) {
+                let packet = parse(line).unwrap().to_string();
+                assert_eq!(packet.replace(", ", ","), line);
+            }
+        });
+    }
+
+    /// A regex for inputs shaped like pairs of packets.
+    const INPUT_LIKE: &str = r"([\[\],0-9]{0,30}\n{1,2}){0,4}";
+
+    proptest! {
+        #[test]
+        fn prop_parser_never_panics(
+            input in prop_oneof![ANY_TEXT, INPUT_LIKE],
+        ) {
+            let packets =
+                |s: &str| s.lines().map(parse).collect::<Result<Vec<_>, _>>();
+            prop_assert!(!parser_panics(packets, &input));
+        }
+    }
+}
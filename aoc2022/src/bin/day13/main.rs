@@ -0,0 +1,66 @@
+use std::error::Error;
+use std::io;
+
+use aoc2022::utils::file::{get_input_records, InputRecord};
+use aoc2022::utils::report::Report;
+use aoc2022::YEAR;
+use dt::parse;
+
+use crate::dt::MaybeVec;
+
+mod dt;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let mut report = Report::new(YEAR, 13)?;
+    report.try_part(1, solve_part1)?;
+    report.try_part(2, solve_part2)?;
+
+    Ok(())
+}
+
+fn solve_part1() -> Result<usize, Box<dyn Error>> {
+    let mut score = 0;
+    for (i, record) in get_input_records(YEAR, "day13")?.enumerate() {
+        let record = record?;
+        let packets = parse_record(&record)?;
+        let (line_1, line_2) = match packets.as_slice() {
+            [line_1, line_2] => (line_1, line_2),
+            _ => return Err(record.err("expected a pair of packets").into()),
+        };
+        if line_1 <= line_2 {
+            score += i + 1;
+        }
+    }
+
+    Ok(score)
+}
+
+fn solve_part2() -> Result<usize, Box<dyn Error>> {
+    let mut packets: Vec<MaybeVec> = vec![parse("[[2]]")?, parse("[[6]]")?];
+    for record in get_input_records(YEAR, "day13")? {
+        packets.extend(parse_record(&record?)?);
+    }
+
+    packets.sort();
+
+    let idx_div_2 = packets
+        .binary_search(&parse("[[2]]")?)
+        .expect("unable to find [[2]]")
+        + 1;
+    let idx_div_6 = packets
+        .binary_search(&parse("[[6]]")?)
+        .expect("unable to find [[6]]")
+        + 1;
+
+    Ok(idx_div_2 * idx_div_6)
+}
+
+/// Parses a record of packets, one per line.
+fn parse_record(record: &InputRecord) -> Result<Vec<MaybeVec>, io::Error> {
+    record
+        .lines()
+        .iter()
+        .enumerate()
+        .map(|(i, line)| parse(line).map_err(|e| record.line_err(i, e)))
+        .collect()
+}
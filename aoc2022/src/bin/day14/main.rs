@@ -0,0 +1,29 @@
+use std::error::Error;
+
+use aoc2022::utils::file::get_input_lines;
+use aoc2022::utils::report::Report;
+use aoc2022::YEAR;
+
+use crate::parse::parse_lines;
+
+mod dt;
+mod parse;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let mut report = Report::new(YEAR, 14)?;
+
+    let mut s =
+        report.parse(|| parse_lines(get_input_lines(YEAR, "day14")?))?;
+    eprintln!("{s}");
+    report.part(1, || s.add_sand_to_stable());
+    eprintln!("{s}");
+
+    let mut s =
+        report.parse(|| parse_lines(get_input_lines(YEAR, "day14")?))?;
+    s.draw_floor();
+    eprintln!("drew the floor");
+    eprintln!("{s}");
+    report.part(2, || s.add_sand_to_stable());
+    eprintln!("{s}");
+    Ok(())
+}
@@ -0,0 +1,119 @@
+use std::error::Error;
+use std::io;
+
+use aoc2022::utils::error::ParseError;
+use aoc2022::utils::parse::Parser;
+use itertools::Itertools;
+
+use crate::dt::State;
+
+fn parse_line(line: &str) -> Result<Vec<(u32, u32)>, ParseError> {
+    let mut r: Vec<(u32, u32)> = Vec::new();
+    let mut p: Parser = line.into();
+
+    while !p.is_empty() {
+        let x = p.u32()?;
+        p.str(",")?;
+        let y = p.u32()?;
+        r.push((x, y));
+
+        if !p.is_empty() {
+            p.str(" -> ")?;
+        }
+    }
+
+    Ok(r)
+}
+
+// A wall of rock, between two points.
+type Wall = ((u32, u32), (u32, u32));
+
+fn parse_line_walls(
+    line: &str,
+) -> Result<impl Iterator<Item = Wall>, ParseError> {
+    Ok(parse_line(line)?.into_iter().tuple_windows())
+}
+
+pub fn parse_lines(
+    lines: impl Iterator<Item = Result<String, io::Error>>,
+) -> Result<State, Box<dyn Error>> {
+    let mut s = State::new();
+    for line in lines {
+        let line = line?;
+        for (from, to) in parse_line_walls(line.as_str())? {
+            s.draw_rock(from, to);
+        }
+    }
+    Ok(s)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc2022::gen;
+    use aoc2022::utils::file::{get_str_lines, read_input};
+    use aoc2022::utils::fuzz::{
+        assert_parser_never_panics, parser_panics, ANY_TEXT,
+    };
+    use aoc2022::YEAR;
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_line() {
+        let line = "1,2 -> 3,4 -> 5,6 -> 7,8";
+        assert_eq!(
+            parse_line(line).unwrap(),
+            vec![(1, 2), (3, 4), (5, 6), (7, 8)]
+        );
+        assert_eq!(
+            parse_line_walls(line).unwrap().collect_vec(),
+            vec![((1, 2), (3, 4)), ((3, 4), (5, 6)), ((5, 6), (7, 8)),]
+        );
+    }
+
+    #[test]
+    fn test_fuzz() {
+        let example = read_input(YEAR, "example/day14").unwrap();
+        assert_parser_never_panics("day14", &[&example], |input| {
+            input.lines().map(parse_line).collect::<Result<Vec<_>, _>>()
+        });
+    }
+
+    #[test]
+    fn test_generated() {
+        gen::for_each_input(14, gen::TEST_SIZES, |input| {
+            for line in input.lines() {
+                let printed = parse_line(line)
+                    .unwrap()
+                    .iter()
+                    .map(|(x, y)| format!("{x},{y}"))
+                    .join(" -> ");
+                assert_eq!(printed, line);
+            }
+
+            let mut s = parse_lines(get_str_lines(input)).unwrap();
+            let part_one = s.add_sand_to_stable();
+
+            let mut s = parse_lines(get_str_lines(input)).unwrap();
+            s.draw_floor();
+            assert!(s.add_sand_to_stable() >= part_one);
+        });
+    }
+
+    /// A regex for inputs shaped like rock paths.
+    const INPUT_LIKE: &str =
+        r"(([0-9]{0,11},?-?[0-9]{0,11}( -> )?){0,4}\n){0,4}";
+
+    proptest! {
+        #[test]
+        fn prop_parser_never_panics(
+            input in prop_oneof![ANY_TEXT, INPUT_LIKE],
+        ) {
+            let paths = |s: &str| {
+                s.lines().map(parse_line).collect::<Result<Vec<_>, _>>()
+            };
+            prop_assert!(!parser_panics(paths, &input));
+        }
+    }
+}
@@ -4,6 +4,8 @@ extern crate scan_fmt;
 use std::error::Error;
 
 use aoc2022::utils::file::get_input_lines;
+use aoc2022::utils::report::Report;
+use aoc2022::YEAR;
 use parse::parse_lines;
 
 #[allow(unused_imports)]
@@ -20,8 +22,11 @@ fn main() -> Result<(), Box<dyn Error>> {
     let (filename, part1_row, part2_lim) =
         // ("example/day15", 10, 20);
         ("day15", 2_000_000, 4_000_000);
-    let sensors = parse_lines(get_input_lines(filename)?)?;
-    println!("part one: {}", solve_part1(part1_row, &sensors));
-    println!("part two: {}", solve_part2(part2_lim, &sensors));
+    let mut report = Report::new(YEAR, 15)?;
+
+    let sensors =
+        report.parse(|| parse_lines(get_input_lines(YEAR, filename)?))?;
+    report.part(1, || solve_part1(part1_row, &sensors));
+    report.part(2, || solve_part2(part2_lim, &sensors));
     Ok(())
 }
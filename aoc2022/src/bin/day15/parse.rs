@@ -0,0 +1,95 @@
+use std::error::Error;
+use std::io;
+
+#[derive(Debug)]
+pub struct SensorData {
+    pub sensor_loc: (i32, i32),
+    pub beacon_loc: (i32, i32),
+}
+
+impl SensorData {
+    pub fn range(&self) -> i32 {
+        self.dist_from(&self.beacon_loc)
+    }
+
+    pub fn dist_from(&self, other: &(i32, i32)) -> i32 {
+        (self.sensor_loc.0 - other.0).abs()
+            + (self.sensor_loc.1 - other.1).abs()
+    }
+}
+
+pub fn parse_lines(
+    lines: impl Iterator<Item = Result<String, io::Error>>,
+) -> Result<Vec<SensorData>, Box<dyn Error>> {
+    let mut r: Vec<SensorData> = Vec::new();
+
+    for line in lines {
+        let line = line?;
+        let (s_x, s_y, b_x, b_y) = scan_fmt!(
+            &line,
+            "Sensor at x={}, y={}: closest beacon is at x={}, y={}",
+            i32,
+            i32,
+            i32,
+            i32
+        )?;
+
+        r.push(SensorData {
+            sensor_loc: (s_x, s_y),
+            beacon_loc: (b_x, b_y),
+        });
+    }
+
+    Ok(r)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc2022::gen;
+    use aoc2022::utils::file::get_str_lines;
+    use aoc2022::utils::fuzz::{parser_panics, ANY_TEXT};
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_generated() {
+        gen::for_each_input(15, gen::TEST_SIZES, |input| {
+            let sensors = parse_lines(get_str_lines(input)).unwrap();
+            assert_eq!(sensors.len(), input.lines().count());
+            for (s, line) in sensors.iter().zip(input.lines()) {
+                let ((s_x, s_y), (b_x, b_y)) = (s.sensor_loc, s.beacon_loc);
+                let printed = format!(
+                    "Sensor at x={s_x}, y={s_y}: closest beacon is at x={b_x}, \
+                     y={b_y}"
+                );
+                assert_eq!(printed, line);
+            }
+
+            // No other beacon may be as close as a sensor's reported one.
+            for s in sensors.iter() {
+                for other in sensors.iter() {
+                    if other.beacon_loc != s.beacon_loc {
+                        assert!(s.dist_from(&other.beacon_loc) > s.range());
+                    }
+                }
+            }
+        });
+    }
+
+    /// A regex for inputs shaped like sensor reports.
+    const INPUT_LIKE: &str = concat!(
+        r"(Sensor at x=-?[0-9]{0,11}, y=-?[0-9]{0,11}: ",
+        r"closest beacon is at x=-?[0-9]{0,11}, y=-?[0-9]{0,11}\n){0,3}",
+    );
+
+    proptest! {
+        #[test]
+        fn prop_parser_never_panics(
+            input in prop_oneof![ANY_TEXT, INPUT_LIKE],
+        ) {
+            let sensors = |s: &str| parse_lines(get_str_lines(s));
+            prop_assert!(!parser_panics(sensors, &input));
+        }
+    }
+}
@@ -10,8 +10,8 @@ pub fn solve_part1(row: i32, sensors: &Vec<SensorData>) -> usize {
     // Finally, remove all cells from the row 2_000_000 set with a beacon
     // already present, count the number of items remaining, and return that.
     let mut no_beacon_cells = HashSet::<i32>::new();
-    print!("adding beacon data: ");
-    io::stdout().flush().unwrap();
+    eprint!("adding beacon data: ");
+    io::stderr().flush().unwrap();
 
     for s in sensors {
         let taxicab_dist = (s.sensor_loc.0 - s.beacon_loc.0).abs()
@@ -21,11 +21,11 @@ pub fn solve_part1(row: i32, sensors: &Vec<SensorData>) -> usize {
         for x in (s.sensor_loc.0 - d_x)..=(s.sensor_loc.0 + d_x) {
             no_beacon_cells.insert(x);
         }
-        print!(".");
-        io::stdout().flush().unwrap();
+        eprint!(".");
+        io::stderr().flush().unwrap();
     }
 
-    println!(" done!");
+    eprintln!(" done!");
 
     // Remove cells containing beacons.
     for s in sensors {
@@ -35,7 +35,7 @@ pub fn solve_part1(row: i32, sensors: &Vec<SensorData>) -> usize {
         }
     }
 
-    println!("count cells...");
+    eprintln!("count cells...");
 
     no_beacon_cells.len()
 }
@@ -9,8 +9,8 @@ pub fn solve_part2(max: i32, sensors: &Vec<SensorData>) -> i64 {
     // iterate over the list of sensors we might be in range of. Work out the
     // largest possible jump to the right (x+) and do so. If that takes us off
     // the edge, go to (0, ++y).
-    print!("finding uncovered cell: ");
-    io::stdout().flush().unwrap();
+    eprint!("finding uncovered cell: ");
+    io::stderr().flush().unwrap();
     for y in 0..=max {
         let mut x: i32 = 0;
 
@@ -35,7 +35,7 @@ pub fn solve_part2(max: i32, sensors: &Vec<SensorData>) -> i64 {
             if next_x == 0 {
                 // If we couldn't jump forwards, must have found a position
                 // which isn't covered by any sensor.
-                println!(" done!\nx={x} y={y}");
+                eprintln!(" done!\nx={x} y={y}");
                 return (x as i64) * 4_000_000 + (y as i64);
             }
 
@@ -43,11 +43,11 @@ pub fn solve_part2(max: i32, sensors: &Vec<SensorData>) -> i64 {
         }
 
         if y & 0x1_FFFF == 0 {
-            print!(".");
-            io::stdout().flush().unwrap();
+            eprint!(".");
+            io::stderr().flush().unwrap();
         }
     }
-    println!("no solution found!");
+    eprintln!("no solution found!");
 
     panic!("no solution found!");
 }
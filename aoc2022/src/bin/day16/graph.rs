@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use petgraph::algo::floyd_warshall;
 use petgraph::prelude::*;
 
@@ -35,18 +37,44 @@ pub fn build_problem_graph(s: &State) -> GraphMap<GraphValve, u32, Directed> {
     let fw_pair_distances = floyd_warshall(&graph, |(_, _, n)| *n).unwrap();
 
     // Build a new graph. Make it directed so we don't return to the start state
-    // AA (which has rate=0 in both cases).
+    // AA (which has rate=0 in both cases). Always include AA, even if there are
+    // no useful valves to reach from it.
     let mut fw_graph: GraphMap<GraphValve, u32, Directed> = GraphMap::new();
+    if let Some((name, aa)) = s.valves.get_key_value("AA") {
+        fw_graph.add_node(GraphValve {
+            name,
+            rate: aa.rate,
+        });
+    }
 
     for ((from, to), dist) in fw_pair_distances {
         // Ignore nodes where rate=0 - these are useless to us - or self-edges,
-        // other than out-edges from the AA node.
-        // Include an assert to handle a petgraph bug (#524).
-        assert_ne!(dist, u32::MAX, "encountered an upstream bug: petgraph#524");
-        if dist > 0 && (from.rate != 0 || from.name == "AA") && to.rate != 0 {
+        // other than out-edges from the AA node. Valves with no path between
+        // them are u32::MAX apart, so ignore those too.
+        if dist > 0
+            && dist != u32::MAX
+            && (from.rate != 0 || from.name == "AA")
+            && to.rate != 0
+        {
             fw_graph.add_edge(from, to, dist);
         }
     }
 
     fw_graph
 }
+
+/// Returns a distinct bit for each valve in the graph, so that sets of valves
+/// can be held as bitmasks, which are cheap to copy, hash and compare.
+pub fn valve_bits<'a>(
+    graph: &GraphMap<GraphValve<'a>, u32, Directed>,
+) -> HashMap<GraphValve<'a>, u64> {
+    assert!(
+        graph.node_count() <= 64,
+        "too many valves for a u64 bitmask"
+    );
+    graph
+        .nodes()
+        .enumerate()
+        .map(|(i, n)| (n, 1 << i))
+        .collect()
+}
@@ -0,0 +1,26 @@
+use std::error::Error;
+
+use aoc2022::utils::file::get_input_lines;
+use aoc2022::utils::report::Report;
+use aoc2022::YEAR;
+use parse::State;
+
+use crate::part1::solve_part1;
+use crate::part2::solve_part2;
+
+mod graph;
+mod parse;
+mod part1;
+mod part2;
+#[cfg(test)]
+mod reference;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let mut report = Report::new(YEAR, 16)?;
+
+    let s = report.parse(|| State::parse(get_input_lines(YEAR, "day16")?))?;
+    report.part(1, || solve_part1(&s));
+    report.part(2, || solve_part2(&s));
+
+    Ok(())
+}
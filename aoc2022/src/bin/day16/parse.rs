@@ -0,0 +1,151 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt::Display;
+use std::io;
+
+use aoc2022::utils::error::parse_error;
+use aoc2022::utils::parse::Parser;
+
+#[derive(Debug)]
+pub struct Valve {
+    pub rate: u32,
+    pub tunnels_to: Vec<String>,
+}
+
+// State combines Valves with the information necessary to retrieve the valve
+// from its name.
+pub struct State {
+    pub valves: BTreeMap<String, Valve>,
+}
+
+impl State {
+    pub fn parse(
+        lines: impl Iterator<Item = Result<String, io::Error>>,
+    ) -> Result<Self, Box<dyn Error>> {
+        let mut valves: BTreeMap<String, Valve> = BTreeMap::new();
+
+        for line in lines {
+            let line = line?;
+            let mut p: Parser = line.as_str().into();
+
+            p.str("Valve ")?;
+            let name = p.read_n(2)?;
+
+            p.str(" has flow rate=")?;
+            let rate = p.u32()?;
+
+            p.str("; tunnel")?;
+            p.optional_char('s');
+            p.str(" lead")?;
+            p.optional_char('s');
+            p.str(" to valve")?;
+            p.optional_char('s');
+            p.str(" ")?;
+
+            let mut tunnels_to: Vec<String> = Vec::new();
+            while !p.is_empty() {
+                tunnels_to.push(p.read_n(2)?);
+                p.optional_char(',').then(|| p.optional_char(' '));
+            }
+
+            valves.insert(name, Valve { rate, tunnels_to });
+        }
+
+        // Check that tunnels are symmetric: if A is reachable from B, B must
+        // be reachable from A.
+        for (from_name, from_valve) in valves.iter() {
+            for to_name in from_valve.tunnels_to.iter() {
+                let to_valve = valves.get(to_name).ok_or_else(|| {
+                    parse_error(
+                        format!("no valve {to_name} to tunnel to").as_str(),
+                    )
+                })?;
+                if !to_valve.tunnels_to.contains(from_name) {
+                    return Err(parse_error(
+                        format!(
+                            "tunnel from {from_name} to {to_name} is one-way"
+                        )
+                        .as_str(),
+                    )
+                    .into());
+                }
+            }
+        }
+
+        Ok(Self { valves })
+    }
+}
+
+impl Display for State {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (name, valve) in self.valves.iter() {
+            write!(f, "{name}: {} -> ", valve.rate)?;
+            for t in valve.tunnels_to.iter() {
+                write!(f, "{t} ")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc2022::gen;
+    use aoc2022::utils::file::{get_str_lines, read_input};
+    use aoc2022::utils::fuzz::{
+        assert_parser_never_panics, parser_panics, ANY_TEXT,
+    };
+    use aoc2022::YEAR;
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_fuzz() {
+        let example = read_input(YEAR, "example/day16").unwrap();
+        assert_parser_never_panics("day16", &[&example], |input| {
+            State::parse(get_str_lines(input))
+        });
+    }
+
+    #[test]
+    fn test_generated() {
+        gen::for_each_input(16, gen::TEST_SIZES, |input| {
+            let s = State::parse(get_str_lines(input)).unwrap();
+            assert_eq!(s.valves.len(), input.lines().count());
+            for line in input.lines() {
+                let name = &line[6..8];
+                let valve = &s.valves[name];
+                let to = valve.tunnels_to.join(", ");
+                let printed = match valve.tunnels_to.len() {
+                    1 => format!("tunnel leads to valve {to}"),
+                    _ => format!("tunnels lead to valves {to}"),
+                };
+                let printed = format!(
+                    "Valve {name} has flow rate={}; {printed}",
+                    valve.rate
+                );
+                assert_eq!(printed, line);
+            }
+            assert_eq!(s.valves["AA"].rate, 0);
+            assert!(s.valves.values().all(|v| !v.tunnels_to.is_empty()));
+        });
+    }
+
+    /// A regex for inputs shaped like a scan of valves and tunnels.
+    const INPUT_LIKE: &str = concat!(
+        r"(Valve [A-D]{0,3} has flow rate=-?[0-9]{0,11}; ",
+        r"tunnels? leads? to valves? ([A-D]{0,3}(, )?){0,3}\n){0,4}",
+    );
+
+    proptest! {
+        #[test]
+        fn prop_parser_never_panics(
+            input in prop_oneof![ANY_TEXT, INPUT_LIKE],
+        ) {
+            let valves = |s: &str| State::parse(get_str_lines(s));
+            prop_assert!(!parser_panics(valves, &input));
+        }
+    }
+}
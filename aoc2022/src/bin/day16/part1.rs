@@ -0,0 +1,96 @@
+use std::collections::HashMap;
+
+use aoc2022::utils::memo::{FxBuildHasher, Memo};
+use aoc2022::utils::report::stats_wanted;
+use petgraph::dot::Dot;
+use petgraph::prelude::*;
+
+use crate::graph::{build_problem_graph, valve_bits, GraphValve};
+use crate::parse::State;
+
+pub fn solve_part1(s: &State) -> u32 {
+    // Only 15 valves have a non-zero rate.
+    // Create a graph where nodes are valves, and edges are the tunnels between.
+    // Each edge is annotated with the minutes required to traverse it.
+    // Use the Floyd-Warshall algorithm to do so minimally by removing 0 nodes.
+    // Now we've reduced the problem space greatly. For the full-size input, we
+    // have only 16 important nodes.
+    let graph = build_problem_graph(s);
+
+    eprintln!("graphviz:\n{:?}\n\n", Dot::new(&graph));
+
+    // Perform a depth-first search bounded by length, starting from the AA node.
+    let aa: Vec<_> = graph.nodes().filter(|n| n.name == "AA").collect();
+    assert_eq!(aa.len(), 1);
+    let aa = aa[0];
+
+    bounded_dfs(&graph, aa, 30)
+}
+
+fn bounded_dfs<'a>(
+    graph: &'a GraphMap<GraphValve<'a>, u32, Directed>,
+    from: GraphValve<'a>,
+    time_budget: u32,
+) -> u32 {
+    let bits = valve_bits(graph);
+    let mut memo = Memo::new();
+    let best_score =
+        bounded_dfs_helper(graph, &bits, from, 0, time_budget, &mut memo);
+    if stats_wanted() {
+        eprintln!("memo: {}", memo.stats());
+    }
+    best_score
+}
+
+/// Performs a DFS over the problem graph, with the valves in the open bitmask
+/// already open. Returns the best score that can be added in the time left,
+/// memoised by valve, open valves and time left, since many orders of opening
+/// the same valves reach the same state.
+fn bounded_dfs_helper<'a>(
+    graph: &'a GraphMap<GraphValve<'a>, u32, Directed>,
+    bits: &HashMap<GraphValve<'a>, u64>,
+    from: GraphValve<'a>,
+    open: u64,
+    time_budget: u32,
+    memo: &mut Memo<(u64, u64, u32), u32, FxBuildHasher>,
+) -> u32 {
+    memo.get_or_insert_with((bits[&from], open, time_budget), |memo| {
+        let (mut open, mut time_budget) = (open, time_budget);
+
+        // Because of the graph reduction, we perform a greedy-ish search. If
+        // the valve hasn't been opened, always open it.
+
+        // Open this valve (if not open) as long as we have time. Skip the AA
+        // node. We can do this greedily because we've made a fully-connected
+        // graph.
+        let mut score = 0;
+        if open & bits[&from] == 0 && time_budget > 0 && from.name != "AA" {
+            open |= bits[&from];
+            time_budget -= 1;
+            score = from.rate * time_budget;
+        }
+
+        // Initialise best_score to 0 to allow for staying put. Then check if
+        // moving to a neighbour would be beneficial.
+        let mut best_score = 0;
+        for (_, neighbour, dist) in graph.edges(from) {
+            // Ignore nodes that we can't reach in the remaining time, and any
+            // we've already opened.
+            if time_budget < *dist || open & bits[&neighbour] != 0 {
+                continue;
+            }
+
+            let neighbour_best_score = bounded_dfs_helper(
+                graph,
+                bits,
+                neighbour,
+                open,
+                time_budget - dist,
+                memo,
+            );
+            best_score = best_score.max(neighbour_best_score);
+        }
+
+        score + best_score
+    })
+}
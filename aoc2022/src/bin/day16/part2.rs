@@ -0,0 +1,132 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use aoc2022::utils::memo::{FxBuildHasher, Memo};
+use aoc2022::utils::report::stats_wanted;
+use petgraph::prelude::*;
+use rayon::prelude::*;
+
+use crate::graph::{build_problem_graph, valve_bits, GraphValve};
+use crate::parse::State;
+
+pub fn solve_part2(s: &State) -> u32 {
+    let graph = build_problem_graph(s);
+
+    let aa: Vec<_> = graph.nodes().filter(|n| n.name == "AA").collect();
+    assert_eq!(aa.len(), 1);
+    let aa = aa[0];
+
+    // For this one, try something a bit different.
+    // Partition the graph in two and use the part1 algorithm on each partition.
+
+    partition_dfs(&graph, aa, 26)
+}
+
+fn partition_dfs<'a>(
+    graph: &'a GraphMap<GraphValve<'a>, u32, Directed>,
+    from: GraphValve<'a>,
+    time_budget: u32,
+) -> u32 {
+    let bits = valve_bits(graph);
+    let nodes: Vec<_> = graph.nodes().filter(|n| n.name != "AA").collect();
+
+    let n_nodes: u32 = graph.nodes().len().try_into().unwrap();
+
+    let progress = AtomicU32::new(0);
+    let bitvec_max = 1u64.checked_shl(n_nodes - 1).unwrap() - 1;
+
+    // NB: can halve the search space to avoid the human and elephant doing the
+    // same work.
+    // Each partition's searches reach many of the states other partitions'
+    // did, so share a memo between the partitions each rayon job handles.
+    let best_score = (0..=bitvec_max)
+        .into_par_iter()
+        .map_init(Memo::new, |memo, partition_bitvec| -> u32 {
+            let (mut nodes_human, mut nodes_elephant) = (0, 0);
+            for (i, n) in nodes.iter().enumerate() {
+                match partition_bitvec & 1 << i {
+                    0 => nodes_human |= bits[n],
+                    _ => nodes_elephant |= bits[n],
+                }
+            }
+
+            let score_human = partition_dfs_helper(
+                graph,
+                &bits,
+                from,
+                nodes_human,
+                time_budget,
+                memo,
+            );
+            let score_elephant = partition_dfs_helper(
+                graph,
+                &bits,
+                from,
+                nodes_elephant,
+                time_budget,
+                memo,
+            );
+
+            let progress = progress.fetch_add(1, Ordering::Relaxed);
+            if progress % 256 == 0 {
+                eprintln!("Progress: {progress}/{bitvec_max}");
+                if stats_wanted() {
+                    eprintln!("memo: {}", memo.stats());
+                }
+            }
+
+            score_human + score_elephant
+        })
+        .max();
+
+    best_score.unwrap()
+}
+
+/// Performs a DFS over the problem graph, visiting only the valves in the
+/// partition bitmask. Returns the best score that can be added in the time
+/// left, memoised by valve, valves left to visit and time left.
+fn partition_dfs_helper<'a>(
+    graph: &'a GraphMap<GraphValve<'a>, u32, Directed>,
+    bits: &HashMap<GraphValve<'a>, u64>,
+    from: GraphValve<'a>,
+    partition: u64,
+    time_budget: u32,
+    memo: &mut Memo<(u64, u64, u32), u32, FxBuildHasher>,
+) -> u32 {
+    memo.get_or_insert_with((bits[&from], partition, time_budget), |memo| {
+        let (mut partition, mut time_budget) = (partition, time_budget);
+
+        // Open this valve.
+        let mut score = 0;
+        if time_budget >= 1 && from.name != "AA" {
+            time_budget -= 1;
+            score = from.rate * time_budget;
+            partition &= !bits[&from];
+        }
+
+        let mut best_score = 0;
+
+        for (_, neighbour, dist) in graph.edges(from) {
+            // Ignore nodes that we can't reach in the remaining time.
+            if time_budget < *dist {
+                continue;
+            }
+
+            // or nodes that have been visted, or are outside the partition.
+            if partition & bits[&neighbour] == 0 {
+                continue;
+            }
+
+            best_score = best_score.max(partition_dfs_helper(
+                graph,
+                bits,
+                neighbour,
+                partition,
+                time_budget - dist,
+                memo,
+            ));
+        }
+
+        score + best_score
+    })
+}
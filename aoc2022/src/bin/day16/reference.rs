@@ -0,0 +1,181 @@
+//! A slow reference solver, which searches minute by minute over the original
+//! tunnels rather than relying on graph reduction or greedy valve opening. Used
+//! to check part1 and part2.
+
+use std::collections::HashMap;
+
+struct Valves {
+    rates: Vec<u32>,
+    tunnels: Vec<Vec<usize>>,
+    aa: usize,
+}
+
+/// Returns the most pressure one actor can release in the given minutes.
+pub fn most_pressure(input: &str, minutes: u32) -> u32 {
+    let v = parse(input);
+    alone(&v, v.aa, 0, minutes, &mut HashMap::new())
+}
+
+/// Returns the most pressure two actors can release in the given minutes.
+pub fn most_pressure_together(input: &str, minutes: u32) -> u32 {
+    let v = parse(input);
+    together(&v, (v.aa, v.aa), 0, minutes, &mut HashMap::new())
+}
+
+fn alone(
+    v: &Valves,
+    pos: usize,
+    open: u64,
+    minutes: u32,
+    memo: &mut HashMap<(usize, u64, u32), u32>,
+) -> u32 {
+    if minutes == 0 {
+        return 0;
+    }
+    if let Some(best) = memo.get(&(pos, open, minutes)) {
+        return *best;
+    }
+
+    let best = actions(v, pos, open, minutes)
+        .map(|(to, opened, released)| {
+            released + alone(v, to, open | opened, minutes - 1, memo)
+        })
+        .max()
+        .unwrap();
+
+    memo.insert((pos, open, minutes), best);
+    best
+}
+
+fn together(
+    v: &Valves,
+    (a, b): (usize, usize),
+    open: u64,
+    minutes: u32,
+    memo: &mut HashMap<((usize, usize), u64, u32), u32>,
+) -> u32 {
+    if minutes == 0 {
+        return 0;
+    }
+    // The actors are interchangeable.
+    let pos = (a.min(b), a.max(b));
+    if let Some(best) = memo.get(&(pos, open, minutes)) {
+        return *best;
+    }
+
+    let mut best = 0;
+    for (a_to, a_opened, a_released) in actions(v, a, open, minutes) {
+        for (b_to, b_opened, b_released) in actions(v, b, open, minutes) {
+            // Both can't open the same valve.
+            if a_opened != 0 && a_opened == b_opened {
+                continue;
+            }
+            let open = open | a_opened | b_opened;
+            let released = a_released
+                + b_released
+                + together(v, (a_to, b_to), open, minutes - 1, memo);
+            best = best.max(released);
+        }
+    }
+
+    memo.insert((pos, open, minutes), best);
+    best
+}
+
+/// Returns everything an actor at pos can do this minute, as where it ends up,
+/// the valve it opens (as a bit) and the pressure that releases by the end.
+fn actions(
+    v: &Valves,
+    pos: usize,
+    open: u64,
+    minutes: u32,
+) -> impl Iterator<Item = (usize, u64, u32)> + '_ {
+    let stay = (pos, 0, 0);
+    let opening = (v.rates[pos] > 0 && open & 1 << pos == 0)
+        .then(|| (pos, 1 << pos, v.rates[pos] * (minutes - 1)));
+    let moves = v.tunnels[pos].iter().map(|to| (*to, 0, 0));
+    [stay].into_iter().chain(opening).chain(moves)
+}
+
+/// Parses a scan, panicking if it's malformed or has tunnels that are one-way
+/// or lead nowhere.
+fn parse(input: &str) -> Valves {
+    let mut names = Vec::new();
+    let mut rates = Vec::new();
+    let mut tunnel_names = Vec::new();
+    for line in input.lines() {
+        let (valve, tunnels) = line.split_once("; ").unwrap();
+        let (name, rate) = valve
+            .strip_prefix("Valve ")
+            .unwrap()
+            .split_once(" has flow rate=")
+            .unwrap();
+        let tunnels = ["tunnel leads to valve ", "tunnels lead to valves "]
+            .iter()
+            .find_map(|p| tunnels.strip_prefix(p))
+            .unwrap();
+
+        assert!(!names.contains(&name), "duplicate valve {name}");
+        names.push(name);
+        rates.push(rate.parse().unwrap());
+        tunnel_names.push(tunnels.split(", ").collect::<Vec<_>>());
+    }
+    assert!(names.len() <= 64, "too many valves");
+
+    let index = |name: &str| names.iter().position(|n| *n == name).unwrap();
+    let tunnels: Vec<Vec<usize>> = tunnel_names
+        .iter()
+        .map(|ts| ts.iter().map(|t| index(t)).collect())
+        .collect();
+    for (from, tos) in tunnels.iter().enumerate() {
+        for to in tos {
+            assert!(tunnels[*to].contains(&from), "one-way tunnel");
+        }
+    }
+
+    Valves {
+        rates,
+        tunnels,
+        aa: index("AA"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc2022::gen;
+    use aoc2022::utils::file::{get_str_lines, read_input};
+    use aoc2022::utils::test::assert_no_divergence;
+    use aoc2022::YEAR;
+
+    use super::*;
+    use crate::parse::State;
+    use crate::part1::solve_part1;
+    use crate::part2::solve_part2;
+
+    fn state(input: &str) -> State {
+        State::parse(get_str_lines(input)).unwrap()
+    }
+
+    #[test]
+    fn test_example() {
+        let input = read_input(YEAR, "example/day16").unwrap();
+        assert_eq!(most_pressure(&input, 30), 1651);
+        assert_eq!(most_pressure_together(&input, 26), 1707);
+    }
+
+    #[test]
+    fn test_differential() {
+        assert_no_divergence(
+            gen::generator(16),
+            &[0, 1, 2, 4, 8],
+            |input| most_pressure(input, 30),
+            |input| solve_part1(&state(input)),
+        );
+        assert_no_divergence(
+            gen::generator(16),
+            &[0, 1, 2, 4, 6],
+            |input| most_pressure_together(input, 26),
+            |input| solve_part2(&state(input)),
+        );
+    }
+}
@@ -10,11 +10,12 @@ pub enum Jet {
     Right,
 }
 
-// Creates a Vec<Jet> from a puzzle input.
-pub fn jets_from(s: &str) -> Vec<Jet> {
-    s.chars()
-        .map(|c| -> Jet { c.try_into().unwrap() })
-        .collect()
+// Creates a Vec<Jet> from a puzzle input, which must have at least one jet.
+pub fn jets_from(s: &str) -> Result<Vec<Jet>, &'static str> {
+    if s.is_empty() {
+        return Err("no jets in input");
+    }
+    s.chars().map(Jet::try_from).collect()
 }
 
 impl Display for Jet {
@@ -118,12 +119,14 @@ impl State {
     }
 
     // Drops the next rock and memoizes the result, over and over again, until
-    // it detects a cycle.
+    // it detects a cycle. Gives up after max_rocks rocks, as some jet patterns
+    // never let the column be compacted, so never cycle.
     pub fn drop_rocks_memo(
         &mut self,
         jets: &Vec<Jet>,
-    ) -> (HashableStateResult, HashableStateResult) {
-        for rock_count in 1u64.. {
+        max_rocks: u64,
+    ) -> Option<(HashableStateResult, HashableStateResult)> {
+        for rock_count in 1..=max_rocks {
             self.drop_next_rock(jets);
             self.compact();
             let hsr = HashableStateResult {
@@ -133,10 +136,10 @@ impl State {
             if let Some(cycle_hsr) =
                 self.memo_table.insert(self.hs.clone(), hsr)
             {
-                return (cycle_hsr, hsr);
+                return Some((cycle_hsr, hsr));
             }
         }
-        unreachable!();
+        None
     }
 
     // Drops the next rock in this state.
@@ -289,12 +292,18 @@ impl Rock {
 
 #[cfg(test)]
 mod tests {
+    use aoc2022::gen;
+    use aoc2022::utils::fuzz::{parser_panics, ANY_TEXT};
+    use itertools::Itertools;
+    use proptest::prelude::*;
+
     use super::*;
 
     #[test]
     fn test_drop_rock() {
         let mut s = State::new();
-        let jet_iter = jets_from(">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>");
+        let jet_iter =
+            jets_from(">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>").unwrap();
 
         for _ in 0..10 {
             s.drop_next_rock(&jet_iter);
@@ -382,4 +391,32 @@ mod tests {
         );
         assert_eq!(s.height(), old_height);
     }
+
+    #[test]
+    fn test_generated() {
+        gen::for_each_input(17, gen::TEST_SIZES, |input| {
+            let input = input.trim_end();
+            let jets = jets_from(input).unwrap();
+            assert_eq!(jets.iter().map(|j| j.to_string()).join(""), input);
+
+            // No rock is more than four high.
+            let mut s = State::new();
+            for _ in 0..100 {
+                s.drop_next_rock(&jets);
+            }
+            assert!(s.height() <= 400);
+        });
+    }
+
+    /// A regex for inputs shaped like a jet pattern.
+    const INPUT_LIKE: &str = r"[<>]{0,40}\PC?";
+
+    proptest! {
+        #[test]
+        fn prop_parser_never_panics(
+            input in prop_oneof![ANY_TEXT, INPUT_LIKE],
+        ) {
+            prop_assert!(!parser_panics(jets_from, &input));
+        }
+    }
 }
@@ -0,0 +1,71 @@
+use std::error::Error;
+
+use aoc2022::utils::file::get_input_lines;
+use aoc2022::utils::report::Report;
+use aoc2022::YEAR;
+
+use crate::dt::*;
+
+mod dt;
+#[cfg(test)]
+mod reference;
+
+const PART2_TARGET: u64 = 1000000000000;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let mut report = Report::new(YEAR, 17)?;
+
+    let jets = report.parse(|| -> Result<_, Box<dyn Error>> {
+        let mut input_iter = get_input_lines(YEAR, "day17")?;
+        let input = input_iter.next().ok_or("input was empty")??;
+        if input_iter.next().is_some() {
+            return Err("expected a single line of input".into());
+        }
+        Ok(jets_from(input.as_str())?)
+    })?;
+
+    report.part(1, || solve_part1(&jets));
+    report.part(2, || solve_part2(&jets, PART2_TARGET));
+
+    Ok(())
+}
+
+fn solve_part1(jets: &Vec<Jet>) -> usize {
+    let mut s = State::new();
+
+    for _ in 0..2022 {
+        s.drop_next_rock(jets);
+    }
+
+    s.height()
+}
+
+fn solve_part2(jets: &Vec<Jet>, target: u64) -> u64 {
+    let mut s = State::new();
+
+    let (cycle_from, cycle_to) = match s.drop_rocks_memo(jets, target) {
+        Some(cycle) => cycle,
+        // Every rock has already been dropped.
+        None => return s.height() as u64,
+    };
+
+    let mut height = cycle_from.total_height;
+    let mut count = cycle_from.rock_count;
+    let cycle_height = cycle_to.total_height - cycle_from.total_height;
+    let cycle_count = cycle_to.rock_count - cycle_from.rock_count;
+
+    let cycle_iterations = (target - count) / cycle_count;
+    let cycle_remainder = (target - count) % cycle_count;
+
+    height += cycle_height * cycle_iterations;
+    count += cycle_count * cycle_iterations;
+
+    let h_before = s.height();
+    for _ in 0..cycle_remainder {
+        s.drop_next_rock(jets);
+        count += 1;
+    }
+    height += (s.height() - h_before) as u64;
+
+    height
+}
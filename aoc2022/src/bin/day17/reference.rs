@@ -0,0 +1,111 @@
+//! A slow reference solver, which simulates every rock in an unbounded chamber
+//! without compaction or cycle detection. Used to check dt::State.
+
+use std::collections::HashSet;
+
+const WIDTH: i64 = 7;
+
+// Each rock's cells, measured from its bottom-left corner.
+const ROCKS: [&[(i64, i64)]; 5] = [
+    &[(0, 0), (1, 0), (2, 0), (3, 0)],
+    &[(1, 0), (0, 1), (1, 1), (2, 1), (1, 2)],
+    &[(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)],
+    &[(0, 0), (0, 1), (0, 2), (0, 3)],
+    &[(0, 0), (1, 0), (0, 1), (1, 1)],
+];
+
+/// Returns the height of the tower after each of the first n rocks has
+/// settled. Panics if the jet pattern is empty or malformed.
+pub fn heights(jets: &str, n: usize) -> Vec<u64> {
+    let jets: Vec<i64> = jets
+        .trim_end()
+        .chars()
+        .map(|c| match c {
+            '<' => -1,
+            '>' => 1,
+            _ => panic!("bad jet {c:?}"),
+        })
+        .collect();
+    assert!(!jets.is_empty(), "no jets");
+
+    let mut filled: HashSet<(i64, i64)> = HashSet::new();
+    let mut height = 0;
+    let mut jet_iter = jets.iter().cycle();
+    let fits = |filled: &HashSet<_>, rock: &[(i64, i64)], x: i64, y: i64| {
+        rock.iter().all(|(dx, dy)| {
+            let (x, y) = (x + dx, y + dy);
+            (0..WIDTH).contains(&x) && y >= 0 && !filled.contains(&(x, y))
+        })
+    };
+
+    (0..n)
+        .map(|i| {
+            let rock = ROCKS[i % ROCKS.len()];
+            let (mut x, mut y) = (2, height + 3);
+            loop {
+                let dx = jet_iter.next().unwrap();
+                if fits(&filled, rock, x + dx, y) {
+                    x += dx;
+                }
+                if !fits(&filled, rock, x, y - 1) {
+                    break;
+                }
+                y -= 1;
+            }
+            for (dx, dy) in rock {
+                filled.insert((x + dx, y + dy));
+                height = height.max(y + dy + 1);
+            }
+            height as u64
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc2022::gen;
+    use aoc2022::utils::test::assert_no_divergence;
+
+    use super::*;
+    use crate::dt::{jets_from, State};
+    use crate::solve_part2;
+
+    const EXAMPLE: &str = ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>";
+
+    /// Returns the height after each rock, compacting after every one.
+    fn optimised(jets: &str, n: usize) -> Vec<u64> {
+        let jets = jets_from(jets.trim_end()).unwrap();
+        let mut s = State::new();
+        (0..n)
+            .map(|_| {
+                s.drop_next_rock(&jets);
+                s.compact();
+                s.height() as u64
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_example() {
+        assert_eq!(heights(EXAMPLE, 2022)[2021], 3068);
+    }
+
+    #[test]
+    fn test_differential() {
+        let sizes = [1, 2, 5, 10, 40];
+        assert_no_divergence(
+            gen::generator(17),
+            &sizes,
+            |jets| heights(jets, 300),
+            |jets| optimised(jets, 300),
+        );
+
+        // Long enough for most inputs to cycle, and some to cycle late.
+        assert_no_divergence(
+            gen::generator(17),
+            &sizes,
+            |jets| heights(jets, 2000)[1999],
+            |jets| solve_part2(&jets_from(jets.trim_end()).unwrap(), 2000),
+        );
+    }
+}
@@ -0,0 +1,26 @@
+#[macro_use]
+extern crate scan_fmt;
+
+use std::error::Error;
+
+use aoc2022::utils::file::get_input_lines;
+use aoc2022::utils::report::Report;
+use aoc2022::YEAR;
+use dt::parse_lines;
+
+use crate::part1::solve_part1;
+use crate::part2::solve_part2;
+
+mod dt;
+mod part1;
+mod part2;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let mut report = Report::new(YEAR, 18)?;
+
+    let voxels =
+        report.parse(|| parse_lines(get_input_lines(YEAR, "day18")?))?;
+    report.part(1, || solve_part1(&voxels));
+    report.part(2, || solve_part2(&voxels));
+    Ok(())
+}
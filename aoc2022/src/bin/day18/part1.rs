@@ -0,0 +1,69 @@
+use std::collections::HashSet;
+
+use crate::dt::*;
+
+pub fn solve_part1(voxels: &[Voxel]) -> u32 {
+    let voxels: HashSet<Voxel> = voxels.iter().copied().collect();
+
+    // For each voxel, count the number of exposed faces, and sum up.
+    voxels
+        .iter()
+        .map(|v| -> u32 {
+            let mut s = 0;
+            // Check in each different direction.
+            for d_v in NEIGHBOUR_FACES {
+                let v = (*v + d_v).unwrap();
+                if !voxels.contains(&v) {
+                    s += 1;
+                }
+            }
+            s
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc2022::gen;
+    use aoc2022::utils::file::get_str_lines;
+    use aoc2022::utils::fuzz::{parser_panics, ANY_TEXT};
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_solve_part1() {
+        let voxels: Vec<Voxel> = vec![(1, 1, 1).into(), (2, 1, 1).into()];
+
+        assert_eq!(solve_part1(&voxels), 10);
+    }
+
+    #[test]
+    fn test_generated() {
+        gen::for_each_input(18, gen::TEST_SIZES, |input| {
+            let voxels = parse_lines(get_str_lines(input)).unwrap();
+            assert_eq!(voxels.len(), input.lines().count());
+            for (v, line) in voxels.iter().zip(input.lines()) {
+                assert_eq!(format!("{},{},{}", v.x, v.y, v.z), line);
+            }
+
+            let part_one = solve_part1(&voxels);
+            assert!(part_one <= 6 * voxels.len() as u32);
+            assert!(crate::part2::solve_part2(&voxels) <= part_one);
+        });
+    }
+
+    /// A regex for inputs shaped like a scan of cubes.
+    const INPUT_LIKE: &str =
+        r"(-?[0-9]{0,6},?-?[0-9]{0,6},?-?[0-9]{0,6}\n){0,5}";
+
+    proptest! {
+        #[test]
+        fn prop_parser_never_panics(
+            input in prop_oneof![ANY_TEXT, INPUT_LIKE],
+        ) {
+            let cubes = |s: &str| parse_lines(get_str_lines(s));
+            prop_assert!(!parser_panics(cubes, &input));
+        }
+    }
+}
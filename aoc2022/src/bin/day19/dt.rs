@@ -4,6 +4,7 @@ use std::ops::*;
 
 use aoc2022::min;
 use aoc2022::utils::file::get_input_lines;
+use aoc2022::YEAR;
 use num::CheckedSub;
 use scan_fmt::parse::ScanError;
 
@@ -169,7 +170,7 @@ impl TryFrom<&str> for Blueprint {
 }
 
 pub fn get_blueprints(file: &str) -> Result<Blueprints, Box<dyn Error>> {
-    let lines = get_input_lines(file)?;
+    let lines = get_input_lines(YEAR, file)?;
 
     let mut bps: Vec<Blueprint> = Vec::new();
     for line in lines {
@@ -182,6 +183,10 @@ pub fn get_blueprints(file: &str) -> Result<Blueprints, Box<dyn Error>> {
 
 #[cfg(test)]
 mod tests {
+    use aoc2022::gen;
+    use aoc2022::utils::fuzz::{parser_panics, ANY_TEXT};
+    use proptest::prelude::*;
+
     use super::*;
 
     #[test]
@@ -252,4 +257,57 @@ mod tests {
             5
         );
     }
+
+    #[test]
+    fn test_generated() {
+        gen::for_each_input(19, gen::TEST_SIZES, |input| {
+            for (i, line) in input.lines().enumerate() {
+                let bp = Blueprint::try_from(line).unwrap();
+                let printed = format!(
+                    concat!(
+                        "Blueprint {}: ",
+                        "Each ore robot costs {} ore. ",
+                        "Each clay robot costs {} ore. ",
+                        "Each obsidian robot costs {} ore and {} clay. ",
+                        "Each geode robot costs {} ore and {} obsidian."
+                    ),
+                    bp.id,
+                    bp.ore_robot_cost.ore,
+                    bp.clay_robot_cost.ore,
+                    bp.obsidian_robot_cost.ore,
+                    bp.obsidian_robot_cost.clay,
+                    bp.geode_robot_cost.ore,
+                    bp.geode_robot_cost.obsidian,
+                );
+                assert_eq!(printed, line);
+                assert_eq!(bp.id as usize, i + 1);
+                assert_eq!(bp.ore_robot_cost.clay, 0);
+                assert!(bp.obsidian_robot_cost.clay >= 5);
+                assert!(bp.geode_robot_cost.obsidian >= 5);
+            }
+        });
+    }
+
+    /// A regex for inputs shaped like a list of blueprints.
+    const INPUT_LIKE: &str = concat!(
+        r"(Blueprint -?[0-9]{0,6}: Each ore robot costs -?[0-9]{0,6} ore\. ",
+        r"Each clay robot costs -?[0-9]{0,6} ore\. ",
+        r"Each obsidian robot costs -?[0-9]{0,6} ore and -?[0-9]{0,6} clay\. ",
+        r"Each geode robot costs -?[0-9]{0,6} ore and -?[0-9]{0,6} obsidian\.",
+        r"\n){0,3}",
+    );
+
+    proptest! {
+        #[test]
+        fn prop_parser_never_panics(
+            input in prop_oneof![ANY_TEXT, INPUT_LIKE],
+        ) {
+            let blueprints = |s: &str| {
+                s.lines()
+                    .map(Blueprint::try_from)
+                    .collect::<Result<Vec<_>, _>>()
+            };
+            prop_assert!(!parser_panics(blueprints, &input));
+        }
+    }
 }
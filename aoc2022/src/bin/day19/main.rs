@@ -0,0 +1,50 @@
+#[macro_use]
+extern crate scan_fmt;
+
+use std::error::Error;
+
+use aoc2022::utils::report::Report;
+use aoc2022::YEAR;
+use rayon::prelude::*;
+
+use dt::*;
+use state::*;
+
+mod dt;
+#[cfg(test)]
+mod reference;
+mod state;
+
+const PART1_MINUTES: u16 = 24;
+const PART2_MINUTES: u16 = 32;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let mut report = Report::new(YEAR, 19)?;
+
+    let blueprints = report.parse(|| get_blueprints("day19"))?;
+
+    report.part(1, || {
+        blueprints
+            .0
+            .par_iter()
+            .map(|bp| {
+                StateSet::get_at_time(bp, PART1_MINUTES).get_quality_score(bp)
+            })
+            .inspect(|r| eprintln!("got a result: {r}"))
+            .sum::<u16>()
+    });
+
+    report.part(2, || {
+        blueprints
+            .0
+            .iter()
+            .take(3)
+            .par_bridge()
+            .map(|bp| StateSet::get_at_time(bp, PART2_MINUTES).get_max_geodes())
+            .inspect(|r| eprintln!("got a result: {r}"))
+            .map(|s| s as u32)
+            .product::<u32>()
+    });
+
+    Ok(())
+}
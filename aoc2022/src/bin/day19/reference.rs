@@ -0,0 +1,135 @@
+//! A slow reference solver, which explores every choice of robot to build each
+//! minute without any bound-based pruning. Used to check StateSet.
+//!
+//! To keep the search tractable, it only merges states in ways that can't
+//! change the answer: it never builds more robots of a kind than could be spent
+//! in a minute, and forgets resources beyond what could ever be spent.
+
+use std::collections::HashSet;
+
+// Robot counts then resource counts, both indexed by ore, clay, obsidian then
+// geode.
+type State = ([u16; 4], [u16; 4]);
+
+/// Returns the most geodes each blueprint can open in the given minutes. Panics
+/// if any blueprint is malformed.
+pub fn max_geodes(input: &str, minutes: u16) -> Vec<u16> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            let costs = parse(i + 1, line);
+            let mut states: HashSet<State> =
+                HashSet::from([([1, 0, 0, 0], [0; 4])]);
+            for minute in 1..=minutes {
+                states = states
+                    .iter()
+                    .flat_map(|s| next(&costs, s, minutes - minute))
+                    .collect();
+            }
+            states
+                .iter()
+                .map(|(_, resources)| resources[3])
+                .max()
+                .unwrap()
+        })
+        .collect()
+}
+
+/// Returns the states reachable in a minute, after which minutes_left remain:
+/// building nothing, or any one robot that can be afforded at the start of the
+/// minute.
+fn next<'a>(
+    costs: &'a [[u16; 4]; 4],
+    (robots, resources): &'a State,
+    minutes_left: u16,
+) -> impl Iterator<Item = State> + 'a {
+    // The most of each resource that can be spent in a minute.
+    let most_spent =
+        [0, 1, 2].map(|i| costs.iter().map(|c| c[i]).max().unwrap());
+
+    let collect = move |r: [u16; 4]| -> [u16; 4] {
+        let mut r = [0, 1, 2, 3].map(|i| r[i] + robots[i]);
+        for i in 0..3 {
+            r[i] = r[i].min(most_spent[i] * minutes_left);
+        }
+        r
+    };
+
+    let idle = (*robots, collect(*resources));
+    let builds = (0..4).filter_map(move |robot| {
+        if robot < 3 && robots[robot] >= most_spent[robot] {
+            return None;
+        }
+        let mut left = *resources;
+        for (r, cost) in left.iter_mut().zip(costs[robot]) {
+            *r = r.checked_sub(cost)?;
+        }
+        let mut robots = *robots;
+        robots[robot] += 1;
+        Some((robots, collect(left)))
+    });
+    [idle].into_iter().chain(builds)
+}
+
+/// Parses the nth blueprint into the cost of each robot, panicking unless it
+/// is exactly in the puzzle's format.
+fn parse(n: usize, line: &str) -> [[u16; 4]; 4] {
+    let numbers: Vec<u16> = line
+        .split(|c: char| !c.is_ascii_digit())
+        .filter(|s| !s.is_empty())
+        .map(|s| s.parse().unwrap())
+        .collect();
+    let (id, a, b, c, d, e, f) = match numbers[..] {
+        [id, a, b, c, d, e, f] => (id, a, b, c, d, e, f),
+        _ => panic!("expected seven numbers"),
+    };
+    assert_eq!(id as usize, n);
+    assert_eq!(
+        line,
+        format!(
+            "Blueprint {id}: Each ore robot costs {a} ore. Each clay robot \
+            costs {b} ore. Each obsidian robot costs {c} ore and {d} clay. \
+            Each geode robot costs {e} ore and {f} obsidian."
+        )
+    );
+    [[a, 0, 0, 0], [b, 0, 0, 0], [c, d, 0, 0], [e, 0, f, 0]]
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc2022::gen;
+    use aoc2022::utils::file::read_input;
+    use aoc2022::utils::test::assert_no_divergence;
+    use aoc2022::YEAR;
+
+    use super::*;
+    use crate::dt::Blueprint;
+    use crate::state::StateSet;
+    use crate::PART1_MINUTES;
+
+    #[test]
+    fn test_example() {
+        let input = read_input(YEAR, "example/day19").unwrap();
+        assert_eq!(max_geodes(&input, PART1_MINUTES), [9, 12]);
+    }
+
+    #[test]
+    fn test_differential() {
+        assert_no_divergence(
+            gen::generator(19),
+            &[1],
+            |input| max_geodes(input, PART1_MINUTES),
+            |input| {
+                input
+                    .lines()
+                    .map(|line| {
+                        let bp = Blueprint::try_from(line).unwrap();
+                        StateSet::get_at_time(&bp, PART1_MINUTES)
+                            .get_max_geodes()
+                    })
+                    .collect::<Vec<_>>()
+            },
+        );
+    }
+}
@@ -0,0 +1,68 @@
+use std::error::Error;
+use std::io;
+
+use aoc2022::utils::file::get_input_lines;
+use aoc2022::utils::int::bigint_mode;
+use aoc2022::utils::report::Report;
+use aoc2022::YEAR;
+use num::BigInt;
+
+use sol::*;
+
+mod sol;
+
+const PART2_KEY: i64 = 811589153;
+const PART2_ROUNDS: u8 = 10;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let mut report = Report::new(YEAR, 20)?;
+
+    let input =
+        report.parse(|| parse_lines(get_input_lines(YEAR, "day20")?))?;
+    report.part(1, || mix_and_score(&input).into_iter().sum::<i16>());
+
+    if bigint_mode() {
+        report.try_part(2, || {
+            decrypt_and_score::<BigInt>(&input, PART2_KEY, PART2_ROUNDS)
+        })?;
+    } else {
+        report.try_part(2, || {
+            decrypt_and_score::<i64>(&input, PART2_KEY, PART2_ROUNDS)
+        })?;
+    }
+
+    Ok(())
+}
+
+fn parse_lines(
+    lines: impl Iterator<Item = Result<String, io::Error>>,
+) -> Result<Vec<i16>, Box<dyn Error>> {
+    let mut r: Vec<i16> = Vec::new();
+    for line in lines {
+        r.push(line?.parse()?);
+    }
+
+    Ok(r)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc2022::utils::file::get_str_lines;
+    use aoc2022::utils::fuzz::{parser_panics, ANY_TEXT};
+    use proptest::prelude::*;
+
+    use super::*;
+
+    /// A regex for inputs shaped like an encrypted file.
+    const INPUT_LIKE: &str = r"(-?[0-9]{0,7}\n){0,6}";
+
+    proptest! {
+        #[test]
+        fn prop_parser_never_panics(
+            input in prop_oneof![ANY_TEXT, INPUT_LIKE],
+        ) {
+            let numbers = |s: &str| parse_lines(get_str_lines(s));
+            prop_assert!(!parser_panics(numbers, &input));
+        }
+    }
+}
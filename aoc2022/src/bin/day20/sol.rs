@@ -0,0 +1,200 @@
+use std::fmt::Debug;
+
+use aoc2022::utils::int::{ArithError, Int};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct TaggedI16 {
+    val: i16,
+    tag: bool,
+}
+
+pub fn perform_mix(v: &[i16]) -> Vec<i16> {
+    // Tag each item in the list with a flag indicating if it's been processed
+    // yet.
+    let mut v: Vec<TaggedI16> = v
+        .iter()
+        .map(|val| TaggedI16 {
+            val: *val,
+            tag: false,
+        })
+        .collect();
+
+    // Loop over the indexes in the list, but every time we insert ahead of this
+    // position, we must not advance the loop.
+    let mut i = 0;
+    while let Some(val) = v.get(i) {
+        let val = val.to_owned();
+        if val.tag {
+            i += 1;
+            continue;
+        }
+
+        let mut val = v.remove(i);
+        assert!(!val.tag);
+        val.tag = true;
+        let mut new_idx: usize = (i as i16 + val.val)
+            .rem_euclid(v.len().try_into().unwrap())
+            .try_into()
+            .unwrap();
+
+        if val.val < 0 && new_idx == 0 {
+            new_idx = v.len();
+        }
+
+        v.insert(new_idx, val);
+    }
+
+    v.into_iter()
+        .inspect(|val| assert!(val.tag))
+        .map(|val| val.val)
+        .collect()
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TaggedInt<T> {
+    pub val: T,
+    pub original_idx: u16,
+}
+
+pub fn perform_mix_part2<T: Int>(
+    v: &mut Vec<TaggedInt<T>>,
+) -> Result<(), ArithError> {
+    if v.len() < 2 {
+        return Ok(());
+    }
+
+    for original_idx in 0..v.len() {
+        let idx = v
+            .iter()
+            .position(|v| v.original_idx as usize == original_idx)
+            .unwrap();
+
+        let val = v.remove(idx);
+        // Only the value modulo the list's length matters, so reduce it first
+        // rather than adding the index to it.
+        let shift = val.val.mod_floor(&T::try_from_usize(v.len())?);
+        let mut new_idx = (idx + shift.to_usize().unwrap()) % v.len();
+
+        if val.val < T::zero() && new_idx == 0 {
+            new_idx = v.len();
+        }
+
+        v.insert(new_idx, val);
+    }
+
+    Ok(())
+}
+
+pub fn score_i16(res: &Vec<i16>) -> [i16; 3] {
+    let idx_0 = res.iter().position(|&v| v == 0).unwrap();
+    assert_eq!(res[idx_0], 0);
+    [
+        res[(idx_0 + 1000) % res.len()],
+        res[(idx_0 + 2000) % res.len()],
+        res[(idx_0 + 3000) % res.len()],
+    ]
+}
+
+pub fn score_int<T: Int>(res: &[TaggedInt<T>]) -> [T; 3] {
+    let idx_0 = res.iter().position(|v| v.val.is_zero()).unwrap();
+    [
+        res[(idx_0 + 1000) % res.len()].val.clone(),
+        res[(idx_0 + 2000) % res.len()].val.clone(),
+        res[(idx_0 + 3000) % res.len()].val.clone(),
+    ]
+}
+
+pub fn mix_and_score(v: &[i16]) -> [i16; 3] {
+    let res = perform_mix(v);
+    score_i16(&res)
+}
+
+/// Solves part two: multiplies each number by the key, mixes the list the
+/// given number of times, and sums the score. Works in T, which must be big
+/// enough to hold the numbers once multiplied.
+pub fn decrypt_and_score<T: Int>(
+    v: &[i16],
+    key: i64,
+    rounds: u8,
+) -> Result<T, ArithError> {
+    let key = T::try_from_i64(key)?;
+    let mut v = v
+        .iter()
+        .enumerate()
+        .map(|(i, &val)| {
+            Ok(TaggedInt {
+                val: T::try_from_i64(val.into())?.try_mul(&key)?,
+                original_idx: i.try_into().unwrap(),
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    for _ in 0..rounds {
+        eprint!(".");
+        perform_mix_part2(&mut v)?;
+    }
+    eprintln!();
+
+    score_int(&v)
+        .iter()
+        .try_fold(T::zero(), |a, b| a.try_add(b))
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc2022::gen;
+    use aoc2022::utils::file::get_str_lines;
+    use num::BigInt;
+
+    use super::*;
+
+    #[test]
+    fn perform_mix_on_example() {
+        let ex: Vec<i16> = vec![1, 2, -3, 3, -2, 0, 4];
+        let ex = perform_mix(&ex);
+
+        assert_eq!(ex, vec![1, 2, -3, 4, 0, 3, -2]);
+    }
+
+    #[test]
+    fn mix_and_score_on_example() {
+        let ex: Vec<i16> = vec![1, 2, -3, 3, -2, 0, 4];
+        let ex = mix_and_score(&ex);
+
+        assert_eq!(ex, [4, -3, 2]);
+    }
+
+    #[test]
+    fn decrypt_and_score_on_example() {
+        let ex: Vec<i16> = vec![1, 2, -3, 3, -2, 0, 4];
+        let key = 811589153;
+
+        assert_eq!(decrypt_and_score::<i64>(&ex, key, 10), Ok(1623178306));
+        assert_eq!(
+            decrypt_and_score::<BigInt>(&ex, key, 10),
+            Ok(1623178306.into())
+        );
+        // The key times 3 doesn't fit in an i32.
+        assert_eq!(
+            decrypt_and_score::<i32>(&ex, key, 10),
+            Err(ArithError::Overflow)
+        );
+    }
+
+    #[test]
+    fn perform_mix_on_generated() {
+        gen::for_each_input(20, &[0, 1, 10, 1000], |input| {
+            let mut nums = crate::parse_lines(get_str_lines(input)).unwrap();
+            assert_eq!(nums.iter().filter(|n| **n == 0).count(), 1);
+            let printed: String =
+                nums.iter().map(|n| format!("{n}\n")).collect();
+            assert_eq!(printed, input);
+
+            // Mixing only reorders the numbers.
+            let mut mixed = perform_mix(&nums);
+            mixed.sort_unstable();
+            nums.sort_unstable();
+            assert_eq!(mixed, nums);
+        });
+    }
+}
@@ -3,6 +3,8 @@ use std::fmt::{Debug, Display, Write};
 use std::str::FromStr;
 
 use aoc2022::utils::file::get_input_lines;
+use aoc2022::utils::int::{ArithError, Int};
+use aoc2022::YEAR;
 
 /// An Op is one of the five binary operators each monkey supports.
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
@@ -17,41 +19,40 @@ pub enum Op {
 impl Op {
     /// Given the operands, apply this operator to those operands and return
     /// the result.
-    pub fn apply(&self, lhs: i64, rhs: i64) -> i64 {
+    pub fn apply<T: Int>(&self, lhs: &T, rhs: &T) -> Result<T, ArithError> {
         match self {
-            Self::Add => lhs.checked_add(rhs),
-            Self::Sub => lhs.checked_sub(rhs),
-            Self::Mul => lhs.checked_mul(rhs),
-            Self::Div => lhs.checked_div(rhs),
+            Self::Add => lhs.try_add(rhs),
+            Self::Sub => lhs.try_sub(rhs),
+            Self::Mul => lhs.try_mul(rhs),
+            Self::Div => lhs.try_div(rhs),
             Self::Eq => {
                 assert_eq!(lhs, rhs);
-                Some(lhs)
+                Ok(lhs.clone())
             },
         }
-        .unwrap()
     }
 
     /// Given the right-hand operand and the value to which the operation must
     /// resolve to, determine the value to which the left-hand subtree must
     /// resolve to.
-    pub fn find_lhs(&self, rhs: i64, val: i64) -> i64 {
+    pub fn find_lhs<T: Int>(&self, rhs: &T, val: &T) -> Result<T, ArithError> {
         match self {
-            Op::Add => val.checked_sub(rhs).unwrap(), // ? + r = v
-            Op::Sub => val.checked_add(rhs).unwrap(), // ? - r = v
-            Op::Mul => val.checked_div(rhs).unwrap(), // ? * r = v
-            Op::Div => val.checked_mul(rhs).unwrap(), // ? / r = v
-            Op::Eq => rhs,
+            Op::Add => val.try_sub(rhs), // ? + r = v
+            Op::Sub => val.try_add(rhs), // ? - r = v
+            Op::Mul => val.try_div(rhs), // ? * r = v
+            Op::Div => val.try_mul(rhs), // ? / r = v
+            Op::Eq => Ok(rhs.clone()),
         }
     }
 
     /// As find_lhs, but determines the right-hand operand.
-    pub fn find_rhs(&self, lhs: i64, val: i64) -> i64 {
+    pub fn find_rhs<T: Int>(&self, lhs: &T, val: &T) -> Result<T, ArithError> {
         match self {
-            Op::Add => val.checked_sub(lhs).unwrap(), // l + ? = v
-            Op::Sub => lhs.checked_sub(val).unwrap(), // l - ? = v // NB: order
-            Op::Mul => val.checked_div(lhs).unwrap(), // l * ? = v
-            Op::Div => lhs.checked_div(val).unwrap(), // l / ? = v // NB: order
-            Op::Eq => lhs,
+            Op::Add => val.try_sub(lhs), // l + ? = v
+            Op::Sub => lhs.try_sub(val), // l - ? = v // NB: order
+            Op::Mul => val.try_div(lhs), // l * ? = v
+            Op::Div => lhs.try_div(val), // l / ? = v // NB: order
+            Op::Eq => Ok(lhs.clone()),
         }
     }
 }
@@ -96,16 +97,10 @@ impl FromStr for Ident {
     type Err = String;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Self(
-            s.chars()
-                .map(|c| -> u8 {
-                    assert!(c.is_ascii(), "non-ASCII char {c}");
-                    c.try_into().unwrap()
-                })
-                .collect::<Vec<_>>()
-                .try_into()
-                .unwrap(),
-        ))
+        match s.as_bytes().try_into() {
+            Ok(bytes) if s.is_ascii() => Ok(Self(bytes)),
+            _ => Err(format!("{s:?} is not four ASCII chars")),
+        }
     }
 }
 
@@ -127,22 +122,23 @@ pub struct NamedExpr {
 }
 
 /// An enum used during the solve that can contain an expression, value, or
-/// the single unknown value to determine.
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
-pub enum ResolvableExpr {
+/// the single unknown value to determine. Values are of type T, e.g. i64, or
+/// BigInt for inputs whose values don't fit in one.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum ResolvableExpr<T> {
     Expr(Expr),
-    Val(i64),
+    Val(T),
     Unknown,
 }
 
 /// A ResolvableExpr with an associated name, just like NamedExpr.
-#[derive(Clone, Copy, Eq, Hash, PartialEq)]
-pub struct NamedResolvableExpr {
+#[derive(Clone, Eq, Hash, PartialEq)]
+pub struct NamedResolvableExpr<T> {
     pub name: Ident,
-    pub expr: ResolvableExpr,
+    pub expr: ResolvableExpr<T>,
 }
 
-impl FromStr for NamedResolvableExpr {
+impl<T: Int> FromStr for NamedResolvableExpr<T> {
     type Err = String;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
@@ -159,8 +155,7 @@ impl FromStr for NamedResolvableExpr {
             2 => Ok(Self {
                 name,
                 expr: ResolvableExpr::Val(
-                    parts[1]
-                        .parse()
+                    T::parse_int(parts[1])
                         .map_err(|e| format!("unable to parse {s:?}: {e}"))?,
                 ),
             }),
@@ -177,13 +172,61 @@ impl FromStr for NamedResolvableExpr {
     }
 }
 
-pub fn parse_lines(
+pub fn parse_lines<T: Int>(
     file: &str,
-) -> Result<Vec<NamedResolvableExpr>, Box<dyn Error>> {
-    let lines = get_input_lines(file)?;
-    let mut r: Vec<NamedResolvableExpr> = Vec::new();
+) -> Result<Vec<NamedResolvableExpr<T>>, Box<dyn Error>> {
+    let lines = get_input_lines(YEAR, file)?;
+    let mut r: Vec<NamedResolvableExpr<T>> = Vec::new();
     for line in lines {
         r.push(line?.parse()?);
     }
     Ok(r)
 }
+
+#[cfg(test)]
+mod tests {
+    use aoc2022::utils::file::read_input;
+    use aoc2022::utils::fuzz::{
+        assert_parser_never_panics, parser_panics, ANY_TEXT,
+    };
+    use aoc2022::YEAR;
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_fuzz() {
+        for input in ["ro: 1", "r\u{f6}ot: 1", "root: abcd + ef\u{e9}"] {
+            assert!(
+                input.parse::<NamedResolvableExpr<i64>>().is_err(),
+                "{input}"
+            );
+        }
+
+        let example = read_input(YEAR, "example/day21").unwrap();
+        assert_parser_never_panics("day21", &[&example], |input| {
+            input
+                .lines()
+                .map(str::parse::<NamedResolvableExpr<i64>>)
+                .collect::<Result<Vec<_>, _>>()
+        });
+    }
+
+    /// A regex for inputs shaped like the monkeys' jobs.
+    const INPUT_LIKE: &str =
+        r"([a-z]{0,5}: ?(-?[0-9]{0,21}|[a-z]{0,5} [-+*/%] [a-z]{0,5})\n){0,4}";
+
+    proptest! {
+        #[test]
+        fn prop_parser_never_panics(
+            input in prop_oneof![ANY_TEXT, INPUT_LIKE],
+        ) {
+            let jobs = |s: &str| {
+                s.lines()
+                    .map(str::parse::<NamedResolvableExpr<i64>>)
+                    .collect::<Result<Vec<_>, _>>()
+            };
+            prop_assert!(!parser_panics(jobs, &input));
+        }
+    }
+}
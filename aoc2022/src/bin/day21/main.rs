@@ -0,0 +1,52 @@
+use std::error::Error;
+
+use aoc2022::utils::int::{bigint_mode, ArithError, Int};
+use aoc2022::utils::report::Report;
+use aoc2022::YEAR;
+use dt::*;
+use num::BigInt;
+use solver::*;
+
+mod dt;
+mod solver;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let mut report = Report::new(YEAR, 21)?;
+
+    if bigint_mode() {
+        solve::<BigInt>(&mut report)
+    } else {
+        solve::<i64>(&mut report)
+    }
+}
+
+/// Solves both parts, with every monkey's value as a T.
+fn solve<T: Int>(report: &mut Report) -> Result<(), Box<dyn Error>> {
+    let mut exprs = report.parse(|| parse_lines::<T>("day21"))?;
+
+    report.try_part(1, || {
+        let (_, resolved) = solve_exprs(&exprs)?;
+        Ok::<_, ArithError>(resolved[&IDENT_ROOT].clone())
+    })?;
+
+    // In preparation for part two, re-use the initial part one state, but
+    // change the values of the "root" and "humn" nodes accordingly,
+    for expr in exprs.iter_mut() {
+        if expr.name == IDENT_ROOT {
+            if let ResolvableExpr::Expr(ref mut e) = expr.expr {
+                e.op = Op::Eq;
+            } else {
+                panic!("root not an expr as expected");
+            }
+        } else if expr.name == IDENT_HUMN {
+            expr.expr = ResolvableExpr::Unknown;
+        }
+    }
+
+    report.try_part(2, || {
+        let (pending, resolved) = solve_exprs(&exprs)?;
+        solve_unknown(&pending, &resolved)
+    })?;
+
+    Ok(())
+}
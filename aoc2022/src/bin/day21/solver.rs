@@ -0,0 +1,243 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc2022::utils::int::{ArithError, Int};
+
+use crate::dt::*;
+
+/// Given a collection of Exprs, evaluates those exprs until it can make no
+/// further progress. Returns a HashSet of unevaluated NamedExprs, and a map
+/// of fully-evaluated Exprs to their final value.
+/// This function solves part one, and is used to pre-process part two.
+pub fn solve_exprs<T: Int>(
+    exprs: &[NamedResolvableExpr<T>],
+) -> Result<(HashSet<NamedExpr>, HashMap<Ident, T>), ArithError> {
+    // A HashMap of all exprs we've not been able to resolve yet.
+    let mut exprs_pending: HashSet<NamedExpr> = HashSet::new();
+    // A HashMap of exprs whose value is now known.
+    let mut exprs_resolved: HashMap<Ident, T> = HashMap::new();
+
+    // For each NamedExpr, put resolved values into exprs_resolved, and
+    // unresolved values innto exprs_pending.
+    for named_expr in exprs {
+        match &named_expr.expr {
+            &ResolvableExpr::Expr(expr) => {
+                assert!(exprs_pending.insert(NamedExpr {
+                    name: named_expr.name,
+                    expr
+                }))
+            },
+            ResolvableExpr::Val(v) => assert!(exprs_resolved
+                .insert(named_expr.name, v.clone())
+                .is_none()),
+            ResolvableExpr::Unknown => (),
+        }
+    }
+
+    // Spin until exprs_resolved contains root (or we stop resolving exprs).
+    loop {
+        let prev_len = exprs_pending.len();
+
+        let mut newly_resolved_exprs: Vec<NamedExpr> = Vec::new();
+        for &expr in exprs_pending.iter() {
+            let value = match (
+                exprs_resolved.get(&expr.expr.lhs),
+                exprs_resolved.get(&expr.expr.rhs),
+            ) {
+                (Some(lhs), Some(rhs)) => expr.expr.op.apply(lhs, rhs)?,
+                _ => continue,
+            };
+            exprs_resolved.insert(expr.name, value);
+            newly_resolved_exprs.push(expr);
+        }
+
+        newly_resolved_exprs
+            .into_iter()
+            .for_each(|expr| assert!(exprs_pending.remove(&expr)));
+
+        if exprs_pending.len() == prev_len {
+            // Unable to evaluate anything else.
+            break;
+        }
+    }
+
+    Ok((exprs_pending, exprs_resolved))
+}
+
+/// Given an output from solve_exprs, attempt to determine the unknown value.
+/// Returns the final values resolved from the attempt.
+/// This function solves part two.
+///
+/// * `pending` is a set of named monkeys with expressions that can't be fully
+///   resolved yet.
+/// * `resolved` is a map of names to their values that have been fully
+///   determined.
+pub fn solve_unknown<T: Int>(
+    pending: &HashSet<NamedExpr>,
+    resolved: &HashMap<Ident, T>,
+) -> Result<T, ArithError> {
+    // Mash both the pending and resolved values into a single map of indent
+    // to ResolvableExpr.
+    let mut state: HashMap<Ident, ResolvableExpr<T>> = HashMap::new();
+
+    for ne in pending {
+        assert!(state
+            .insert(ne.name, ResolvableExpr::Expr(ne.expr))
+            .is_none(),);
+    }
+
+    for (&ident, v) in resolved {
+        assert!(state
+            .insert(ident, ResolvableExpr::Val(v.clone()))
+            .is_none());
+    }
+
+    assert!(state.insert(IDENT_HUMN, ResolvableExpr::Unknown).is_none());
+
+    // println!("State is: {state:#?}");
+
+    // Now work downwards from the root. At this point, all the child nodes of
+    // the root should have a single unknown value. Find this value at each
+    // stage to determine the value the next expr must resolve to, and repeat.
+
+    let root = match state.get(&IDENT_ROOT) {
+        Some(&ResolvableExpr::Expr(e)) => e,
+        _ => panic!(),
+    };
+
+    let mut target_val;
+    let mut cur_ident;
+
+    if let ResolvableExpr::Val(lhs) = &state[&root.lhs] {
+        target_val = lhs.clone();
+        cur_ident = root.rhs;
+    } else if let ResolvableExpr::Val(rhs) = &state[&root.rhs] {
+        target_val = rhs.clone();
+        cur_ident = root.lhs;
+    } else {
+        panic!("could not identify a resolved value in the root");
+    }
+
+    // println!("want {cur_ident} = {target_val}");
+
+    while cur_ident != IDENT_HUMN {
+        let this_node = match state.get(&cur_ident) {
+            Some(&ResolvableExpr::Expr(e)) => e,
+            _ => panic!(),
+        };
+
+        let lhs = &state[&this_node.lhs];
+        let rhs = &state[&this_node.rhs];
+
+        if let ResolvableExpr::Val(lhs) = lhs {
+            assert!(!matches!(rhs, ResolvableExpr::Val(_)));
+            target_val = this_node.op.find_rhs(lhs, &target_val)?;
+            cur_ident = this_node.rhs;
+        } else if let ResolvableExpr::Val(rhs) = rhs {
+            assert!(!matches!(lhs, ResolvableExpr::Val(_)));
+            target_val = this_node.op.find_lhs(rhs, &target_val)?;
+            cur_ident = this_node.lhs;
+        } else {
+            panic!("no candidate val for ident {cur_ident}");
+        }
+    }
+
+    Ok(target_val)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc2022::gen;
+    use num::BigInt;
+
+    use super::*;
+
+    #[test]
+    fn test_huge_literals() {
+        let input = "root: aaaa + bbbb\n\
+            aaaa: cccc * dddd\n\
+            cccc: 100000000000000000000\n\
+            dddd: humn - eeee\n\
+            humn: 7\n\
+            eeee: 3\n\
+            bbbb: 900000000000000000000";
+
+        // The literals don't fit in an i64, so it can't even be parsed.
+        assert!(input
+            .lines()
+            .any(|l| l.parse::<NamedResolvableExpr<i64>>().is_err()));
+
+        let mut exprs: Vec<NamedResolvableExpr<BigInt>> =
+            input.lines().map(|l| l.parse().unwrap()).collect();
+        let (_, resolved) = solve_exprs(&exprs).unwrap();
+        assert_eq!(
+            resolved[&IDENT_ROOT],
+            "1300000000000000000000".parse().unwrap()
+        );
+
+        for expr in exprs.iter_mut() {
+            if expr.name == IDENT_ROOT {
+                if let ResolvableExpr::Expr(ref mut e) = expr.expr {
+                    e.op = Op::Eq;
+                }
+            } else if expr.name == IDENT_HUMN {
+                expr.expr = ResolvableExpr::Unknown;
+            }
+        }
+        let (pending, resolved) = solve_exprs(&exprs).unwrap();
+        assert_eq!(solve_unknown(&pending, &resolved), Ok(BigInt::from(12)));
+
+        // Literals that fit, but whose product doesn't, give an error.
+        let input =
+            input.replace("100000000000000000000", "5000000000000000000");
+        let exprs: Vec<NamedResolvableExpr<i64>> = input
+            .lines()
+            .filter(|l| !l.starts_with("bbbb"))
+            .map(|l| l.parse().unwrap())
+            .collect();
+        assert_eq!(solve_exprs(&exprs).unwrap_err(), ArithError::Overflow);
+    }
+
+    #[test]
+    fn test_generated() {
+        gen::for_each_input(21, gen::TEST_SIZES, |input| {
+            let mut exprs: Vec<NamedResolvableExpr<i64>> =
+                input.lines().map(|l| l.parse().unwrap()).collect();
+            for (e, line) in exprs.iter().zip(input.lines()) {
+                let printed = match e.expr {
+                    ResolvableExpr::Val(v) => format!("{}: {v}", e.name),
+                    ResolvableExpr::Expr(Expr { lhs, op, rhs }) => {
+                        let op = match op {
+                            Op::Add => '+',
+                            Op::Sub => '-',
+                            Op::Mul => '*',
+                            Op::Div => '/',
+                            Op::Eq => {
+                                unreachable!("monkeys never test equality")
+                            },
+                        };
+                        format!("{}: {lhs} {op} {rhs}", e.name)
+                    },
+                    ResolvableExpr::Unknown => unreachable!(),
+                };
+                assert_eq!(printed, line);
+            }
+
+            // Root's operands are equal, so humn already gives equality.
+            let (_, resolved) = solve_exprs(&exprs).unwrap();
+            assert_eq!(resolved[&IDENT_ROOT] % 2, 0);
+            let humn = resolved[&IDENT_HUMN];
+
+            for expr in exprs.iter_mut() {
+                if expr.name == IDENT_ROOT {
+                    if let ResolvableExpr::Expr(ref mut e) = expr.expr {
+                        e.op = Op::Eq;
+                    }
+                } else if expr.name == IDENT_HUMN {
+                    expr.expr = ResolvableExpr::Unknown;
+                }
+            }
+            let (pending, resolved) = solve_exprs(&exprs).unwrap();
+            assert_eq!(solve_unknown(&pending, &resolved), Ok(humn));
+        });
+    }
+}
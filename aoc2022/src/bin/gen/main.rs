@@ -0,0 +1,36 @@
+use std::env;
+use std::error::Error;
+use std::io::{self, Write};
+
+use aoc2022::gen;
+
+const USAGE: &str = "usage: gen DAY [--seed N] [--size N]";
+
+/// Writes a random input for a day to stdout. The same day, seed and size
+/// always give the same input.
+fn main() -> Result<(), Box<dyn Error>> {
+    let mut day: Option<u8> = None;
+    let mut seed: u64 = 0;
+    let mut size: usize = 10;
+
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--seed" => seed = args.next().ok_or(USAGE)?.parse()?,
+            "--size" => size = args.next().ok_or(USAGE)?.parse()?,
+            _ if day.is_none() => day = Some(arg.parse()?),
+            _ => return Err(USAGE.into()),
+        }
+    }
+    let day = day.ok_or(USAGE)?;
+
+    let input = gen::generate(day, seed, size).ok_or_else(|| {
+        format!(
+            "no generator for day {day}: expected one of {:?}",
+            gen::DAYS
+        )
+    })?;
+    io::stdout().write_all(input.as_bytes())?;
+
+    Ok(())
+}
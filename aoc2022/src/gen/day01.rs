@@ -0,0 +1,24 @@
+use std::fmt::Write;
+
+use crate::utils::rng::Rng;
+
+/// Generates size elves' calorie lists (at least three), one number per line
+/// and separated by blank lines. Some elves carry nothing, giving runs of
+/// blank lines.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut s = String::new();
+    for elf in 0..size.max(3) {
+        if elf > 0 {
+            s.push('\n');
+        }
+        let items = if rng.chance(1, 10) {
+            0
+        } else {
+            rng.range_usize(1..=8)
+        };
+        for _ in 0..items {
+            writeln!(s, "{}", rng.range(1..=99_999)).unwrap();
+        }
+    }
+    s
+}
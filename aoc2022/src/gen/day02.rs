@@ -0,0 +1,13 @@
+use crate::utils::rng::Rng;
+
+/// Generates size (at least one) rounds of "[ABC] [XYZ]".
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut s = String::new();
+    for _ in 0..size.max(1) {
+        s.push(*rng.choose(&['A', 'B', 'C']));
+        s.push(' ');
+        s.push(*rng.choose(&['X', 'Y', 'Z']));
+        s.push('\n');
+    }
+    s
+}
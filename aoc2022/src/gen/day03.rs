@@ -0,0 +1,81 @@
+use std::collections::HashSet;
+
+use crate::utils::rng::Rng;
+
+/// The item types, in priority order.
+const ITEMS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Generates size (at least one) groups of three rucksacks. Each rucksack's
+/// compartments share exactly one item type, and each group's rucksacks share
+/// exactly one badge item type.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut s = String::new();
+    for _ in 0..size.max(1) {
+        let badge = *rng.choose(ITEMS);
+        let first = rucksack(rng, badge, &HashSet::new());
+        let second = rucksack(rng, badge, &HashSet::new());
+
+        // The last rucksack must avoid anything else in both of the others
+        // for the badge to be the only type common to all three.
+        let first_items: HashSet<u8> = first.bytes().collect();
+        let avoid = second
+            .bytes()
+            .filter(|i| *i != badge && first_items.contains(i))
+            .collect();
+        let third = rucksack(rng, badge, &avoid);
+
+        for line in [first, second, third] {
+            s.push_str(line.as_str());
+            s.push('\n');
+        }
+    }
+    s
+}
+
+/// Generates one rucksack containing badge and none of avoid, whose
+/// compartments have exactly one item type in common.
+fn rucksack(rng: &mut Rng, badge: u8, avoid: &HashSet<u8>) -> String {
+    let mut pool: Vec<u8> = ITEMS
+        .iter()
+        .copied()
+        .filter(|i| !avoid.contains(i) && *i != badge)
+        .collect();
+    rng.shuffle(&mut pool);
+
+    // The shared item is either the badge itself, or another item type.
+    let shared = if pool.is_empty() || rng.chance(1, 4) {
+        badge
+    } else {
+        pool.pop().unwrap()
+    };
+
+    // Split the remaining types between the compartments so nothing else is
+    // shared; the badge goes in the left one if it's not the shared item.
+    let split = pool.len() / 2;
+    let (left_pool, right_pool) = pool.split_at(split);
+    let len = rng.range_usize(2..=16);
+
+    let mut left = vec![shared];
+    let mut right = vec![shared];
+    if shared != badge {
+        left.push(badge);
+    }
+    for (compartment, pool) in
+        [(&mut left, left_pool), (&mut right, right_pool)]
+    {
+        while compartment.len() < len {
+            match pool.is_empty() {
+                true => compartment.push(compartment[0]),
+                false => compartment.push(*rng.choose(pool)),
+            }
+        }
+        rng.shuffle(compartment);
+    }
+
+    // The badge may have made the left compartment longer; pad the right.
+    while right.len() < left.len() {
+        right.push(shared);
+    }
+
+    String::from_utf8(left.into_iter().chain(right).collect()).unwrap()
+}
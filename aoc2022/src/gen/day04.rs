@@ -0,0 +1,19 @@
+use std::fmt::Write;
+
+use crate::utils::rng::Rng;
+
+/// Generates size (at least one) pairs of section assignments "a-b,c-d".
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut s = String::new();
+    for _ in 0..size.max(1) {
+        let (a, b) = range(rng);
+        let (c, d) = range(rng);
+        writeln!(s, "{a}-{b},{c}-{d}").unwrap();
+    }
+    s
+}
+
+fn range(rng: &mut Rng) -> (i64, i64) {
+    let from = rng.range(1..=99);
+    (from, rng.range(from..=99))
+}
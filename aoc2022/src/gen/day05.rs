@@ -0,0 +1,63 @@
+use std::fmt::Write;
+
+use crate::utils::rng::Rng;
+
+/// Generates a crate diagram with size stacks (at least one, and possibly more
+/// than nine), followed by 2 * size + 1 moves, each valid given the moves
+/// before it.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let num_stacks = size.max(1);
+    let mut stacks: Vec<Vec<char>> = (0..num_stacks)
+        .map(|_| {
+            (0..rng.range_usize(0..=6))
+                .map(|_| (b'A' + rng.below(26) as u8) as char)
+                .collect()
+        })
+        .collect();
+
+    // Make sure there's a crate to move.
+    if stacks.iter().all(|s| s.is_empty()) {
+        stacks[0].push('A');
+    }
+
+    let mut s = String::new();
+    let height = stacks.iter().map(|s| s.len()).max().unwrap();
+    for row in (0..height).rev() {
+        let cells: Vec<String> = stacks
+            .iter()
+            .map(|stack| match stack.get(row) {
+                Some(c) => format!("[{c}]"),
+                None => "   ".to_string(),
+            })
+            .collect();
+        writeln!(s, "{}", cells.join(" ")).unwrap();
+    }
+    let labels: Vec<String> =
+        (1..=num_stacks).map(|i| format!("{i:^3}")).collect();
+    writeln!(s, "{}\n", labels.join(" ")).unwrap();
+
+    for _ in 0..(2 * size + 1) {
+        let non_empty: Vec<usize> =
+            (0..num_stacks).filter(|i| !stacks[*i].is_empty()).collect();
+        let from = *rng.choose(&non_empty);
+        let to = if num_stacks == 1 {
+            from
+        } else {
+            // Pick any other stack.
+            let to = rng.range_usize(0..=num_stacks - 2);
+            if to >= from {
+                to + 1
+            } else {
+                to
+            }
+        };
+        let count = rng.range_usize(1..=stacks[from].len());
+
+        let at = stacks[from].len() - count;
+        let moved: Vec<char> = stacks[from].drain(at..).rev().collect();
+        stacks[to].extend(moved);
+        writeln!(s, "move {count} from {} to {}", from + 1, to + 1).unwrap();
+    }
+
+    s
+}
@@ -0,0 +1,15 @@
+use crate::utils::rng::Rng;
+
+/// Generates a datastream of 14 + size lowercase letters. The alphabet used is
+/// sometimes restricted, so a start-of-message marker may not exist.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let alphabet = match rng.chance(1, 4) {
+        true => rng.range(4..=20) as u8,
+        false => 26,
+    };
+    let mut s: String = (0..14 + size)
+        .map(|_| (b'a' + rng.below(alphabet.into()) as u8) as char)
+        .collect();
+    s.push('\n');
+    s
+}
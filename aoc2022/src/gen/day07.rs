@@ -0,0 +1,119 @@
+use std::collections::HashSet;
+use std::fmt::Write;
+
+use crate::utils::rng::Rng;
+
+use super::lowercase;
+
+/// The puzzle needs at least this much in use to have to delete anything.
+const MIN_TOTAL_SIZE: u64 = 40_000_001;
+
+struct Node {
+    name: String,
+    children: Vec<usize>,
+    files: Vec<(String, u64)>,
+    // Names used by children and files, which must be unique.
+    names: HashSet<String>,
+}
+
+/// Generates a terminal session exploring a tree of size directories below
+/// the root, each listed exactly once. Trees are often deep, as each new
+/// directory has even odds of being made inside the last one made. The total
+/// size of all files is always over 40,000,000.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut nodes = vec![Node {
+        name: "/".to_string(),
+        children: Vec::new(),
+        files: Vec::new(),
+        names: HashSet::new(),
+    }];
+
+    for i in 1..=size {
+        let parent = match rng.chance(1, 2) {
+            true => i - 1,
+            false => rng.range_usize(0..=i - 1),
+        };
+        let name = unique_name(rng, &mut nodes[parent].names, false);
+        nodes[parent].children.push(i);
+        nodes.push(Node {
+            name,
+            children: Vec::new(),
+            files: Vec::new(),
+            names: HashSet::new(),
+        });
+    }
+
+    let mut total = 0;
+    for node in nodes.iter_mut() {
+        for _ in 0..rng.range_usize(0..=4) {
+            let name = unique_name(rng, &mut node.names, true);
+            let size = rng.range(1..=300_000) as u64;
+            total += size;
+            node.files.push((name, size));
+        }
+    }
+    if total < MIN_TOTAL_SIZE {
+        let name = unique_name(rng, &mut nodes[0].names, true);
+        let size = MIN_TOTAL_SIZE - total + rng.range(0..=10_000_000) as u64;
+        nodes[0].files.push((name, size));
+    }
+
+    let mut lines = vec!["$ cd /".to_string()];
+    visit(rng, &nodes, 0, &mut lines);
+
+    // Like real inputs, don't bother returning to the root at the end.
+    while lines.last().map(|l| l.as_str()) == Some("$ cd ..") {
+        lines.pop();
+    }
+
+    let mut s = String::new();
+    for line in lines {
+        writeln!(s, "{line}").unwrap();
+    }
+    s
+}
+
+/// Appends the commands to list the given node and then visit its children.
+fn visit(rng: &mut Rng, nodes: &[Node], idx: usize, lines: &mut Vec<String>) {
+    let node = &nodes[idx];
+    lines.push("$ ls".to_string());
+
+    let mut entries: Vec<String> = node
+        .children
+        .iter()
+        .map(|c| format!("dir {}", nodes[*c].name))
+        .chain(
+            node.files
+                .iter()
+                .map(|(name, size)| format!("{size} {name}")),
+        )
+        .collect();
+    rng.shuffle(&mut entries);
+    lines.extend(entries);
+
+    let mut children = node.children.clone();
+    rng.shuffle(&mut children);
+    for child in children {
+        lines.push(format!("$ cd {}", nodes[child].name));
+        visit(rng, nodes, child, lines);
+        lines.push("$ cd ..".to_string());
+    }
+}
+
+/// Picks a name not already in used, and adds it. Files may have an extension.
+fn unique_name(
+    rng: &mut Rng,
+    used: &mut HashSet<String>,
+    file: bool,
+) -> String {
+    loop {
+        let mut name = lowercase(rng, 1..=8);
+        if file && rng.chance(1, 2) {
+            name.push('.');
+            name.push_str(lowercase(rng, 1..=3).as_str());
+        }
+        if used.insert(name.clone()) {
+            return name;
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use crate::utils::rng::Rng;
+
+/// Generates a size by size (at least 1x1) grid of tree heights.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let mut s = String::with_capacity(size * (size + 1));
+    for _ in 0..size {
+        for _ in 0..size {
+            s.push((b'0' + rng.below(10) as u8) as char);
+        }
+        s.push('\n');
+    }
+    s
+}
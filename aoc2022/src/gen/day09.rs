@@ -0,0 +1,13 @@
+use std::fmt::Write;
+
+use crate::utils::rng::Rng;
+
+/// Generates size (at least one) head movements "[UDLR] n", n in 1..=20.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut s = String::new();
+    for _ in 0..size.max(1) {
+        let dir = rng.choose(&['U', 'D', 'L', 'R']);
+        writeln!(s, "{dir} {}", rng.range(1..=20)).unwrap();
+    }
+    s
+}
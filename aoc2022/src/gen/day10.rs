@@ -0,0 +1,32 @@
+use std::fmt::Write;
+
+use crate::utils::rng::Rng;
+
+/// X is kept within this distance of zero, so the signal strengths (up to 220
+/// times X) and their sum can't overflow an i32.
+const MAX_X: i64 = 1_000_000;
+
+/// Generates noop and addx instructions taking at least max(240, size) cycles,
+/// enough to draw the whole CRT. addx values range up to MAX_X in size.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut s = String::new();
+    let mut cycles = 0;
+    let mut x: i64 = 1;
+
+    while cycles < size.max(240) {
+        if rng.chance(1, 3) {
+            s.push_str("noop\n");
+            cycles += 1;
+        } else {
+            // Mostly small values, with the odd huge jump.
+            let by = match rng.chance(1, 10) {
+                true => rng.range(-MAX_X - x..=MAX_X - x),
+                false => rng.range((-20).max(-MAX_X - x)..=20.min(MAX_X - x)),
+            };
+            x += by;
+            writeln!(s, "addx {by}").unwrap();
+            cycles += 2;
+        }
+    }
+    s
+}
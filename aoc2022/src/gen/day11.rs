@@ -0,0 +1,50 @@
+use std::fmt::Write;
+
+use crate::utils::rng::Rng;
+
+/// Divisors are distinct primes, as in real inputs, keeping their LCM small.
+const PRIMES: [u64; 9] = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+
+/// Generates notes on size monkeys (clamped to 2..=8), each holding 1 to 8
+/// items. Each monkey throws to two other monkeys, which differ from each other
+/// whenever there are three or more monkeys.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let num_monkeys = size.clamp(2, 8);
+    let mut divisors = PRIMES;
+    rng.shuffle(&mut divisors);
+
+    let mut s = String::new();
+    for (n, divisor) in divisors.iter().take(num_monkeys).enumerate() {
+        if n > 0 {
+            s.push('\n');
+        }
+
+        let items: Vec<String> = (0..rng.range_usize(1..=8))
+            .map(|_| rng.range(1..=99).to_string())
+            .collect();
+
+        let op = match rng.below(4) {
+            0 => "* old".to_string(),
+            1 => format!("* {}", rng.range(2..=19)),
+            _ => format!("+ {}", rng.range(1..=8)),
+        };
+
+        // Pick two distinct targets, neither of which is this monkey.
+        let mut targets: Vec<usize> =
+            (0..num_monkeys).filter(|m| *m != n).collect();
+        rng.shuffle(&mut targets);
+        let (if_true, if_false) = match targets[..] {
+            [t] => (t, t),
+            [t, f, ..] => (t, f),
+            [] => unreachable!("there are always at least two monkeys"),
+        };
+
+        writeln!(s, "Monkey {n}:").unwrap();
+        writeln!(s, "  Starting items: {}", items.join(", ")).unwrap();
+        writeln!(s, "  Operation: new = old {op}").unwrap();
+        writeln!(s, "  Test: divisible by {divisor}").unwrap();
+        writeln!(s, "    If true: throw to monkey {if_true}").unwrap();
+        writeln!(s, "    If false: throw to monkey {if_false}").unwrap();
+    }
+    s
+}
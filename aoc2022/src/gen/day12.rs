@@ -0,0 +1,36 @@
+use crate::utils::rng::Rng;
+
+/// Generates a heightmap 26 + size wide and 8 + size / 2 high. A path climbing
+/// steadily from S to E always runs along one row, so E is always reachable;
+/// every other square has a random height.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let width = 26 + size;
+    let height = 8 + size / 2;
+
+    let mut rows: Vec<Vec<char>> = (0..height)
+        .map(|_| {
+            (0..width)
+                .map(|_| (b'a' + rng.below(26) as u8) as char)
+                .collect()
+        })
+        .collect();
+
+    // Heights along the path rise from a to z, by at most one per step as the
+    // path is at least 26 squares long.
+    let path = &mut rows[rng.range_usize(0..=height - 1)];
+    for (x, c) in path.iter_mut().enumerate() {
+        *c = (b'a' + (x * 25 / (width - 1)) as u8) as char;
+    }
+    path[0] = 'S';
+    path[width - 1] = 'E';
+    if rng.chance(1, 2) {
+        path.reverse();
+    }
+
+    let mut s = String::with_capacity(height * (width + 1));
+    for row in rows {
+        s.extend(row);
+        s.push('\n');
+    }
+    s
+}
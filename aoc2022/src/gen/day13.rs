@@ -0,0 +1,36 @@
+use crate::utils::rng::Rng;
+
+/// How deeply lists may nest within a packet.
+const MAX_DEPTH: usize = 4;
+
+/// Generates size (at least one) pairs of packets. Packets are lists of up to
+/// five items, which are integers in 0..=10 or further lists.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut s = String::new();
+    for i in 0..size.max(1) {
+        if i > 0 {
+            s.push('\n');
+        }
+        for _ in 0..2 {
+            push_list(rng, &mut s, MAX_DEPTH);
+            s.push('\n');
+        }
+    }
+    s
+}
+
+/// Appends a random list, whose items may be lists up to depth more deep.
+fn push_list(rng: &mut Rng, s: &mut String, depth: usize) {
+    s.push('[');
+    for i in 0..rng.range_usize(0..=5) {
+        if i > 0 {
+            s.push(',');
+        }
+        if depth > 0 && rng.chance(1, 3) {
+            push_list(rng, s, depth - 1);
+        } else {
+            s.push_str(rng.range(0..=10).to_string().as_str());
+        }
+    }
+    s.push(']');
+}
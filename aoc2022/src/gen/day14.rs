@@ -0,0 +1,29 @@
+use crate::utils::rng::Rng;
+
+/// Generates size (at least one) paths of rock, each of 2 to 5 points joined
+/// by horizontal and vertical lines. Rock lies below the sand source at
+/// 500,0, within 20 + size of it on either axis.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let spread = 20 + size as i64;
+
+    let mut s = String::new();
+    for _ in 0..size.max(1) {
+        let mut x = rng.range(500 - spread..=500 + spread);
+        let mut y = rng.range(1..=spread);
+        let mut points = vec![format!("{x},{y}")];
+
+        let mut horizontal = rng.chance(1, 2);
+        for _ in 1..rng.range_usize(2..=5) {
+            match horizontal {
+                true => x = rng.range(500 - spread..=500 + spread),
+                false => y = rng.range(1..=spread),
+            }
+            horizontal = !horizontal;
+            points.push(format!("{x},{y}"));
+        }
+
+        s.push_str(points.join(" -> ").as_str());
+        s.push('\n');
+    }
+    s
+}
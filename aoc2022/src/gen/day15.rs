@@ -0,0 +1,48 @@
+use std::collections::HashSet;
+use std::fmt::Write;
+
+use crate::utils::rng::Rng;
+
+/// Generates reports from size (at least one) sensors, in an area 20 + 4 * size
+/// across centred on 0,0. Each sensor's closest beacon is unique, as the puzzle
+/// promises, and no sensor sits on a beacon or another sensor.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let span = 20 + 4 * size as i64;
+    let point = |rng: &mut Rng| {
+        (
+            rng.range(-span / 2..=span / 2),
+            rng.range(-span / 2..=span / 2),
+        )
+    };
+
+    let mut beacons = HashSet::new();
+    while beacons.len() < size / 2 + 1 {
+        beacons.insert(point(rng));
+    }
+    let beacons: Vec<(i64, i64)> = beacons.into_iter().collect();
+
+    let mut sensors = HashSet::new();
+    let mut s = String::new();
+    while sensors.len() < size.max(1) {
+        let sensor = point(rng);
+        if beacons.contains(&sensor) || sensors.contains(&sensor) {
+            continue;
+        }
+
+        let dist =
+            |b: &(i64, i64)| (b.0 - sensor.0).abs() + (b.1 - sensor.1).abs();
+        let closest = beacons.iter().min_by_key(|b| dist(b)).unwrap();
+        if beacons.iter().filter(|b| dist(b) == dist(closest)).count() > 1 {
+            continue;
+        }
+
+        sensors.insert(sensor);
+        writeln!(
+            s,
+            "Sensor at x={}, y={}: closest beacon is at x={}, y={}",
+            sensor.0, sensor.1, closest.0, closest.1
+        )
+        .unwrap();
+    }
+    s
+}
@@ -0,0 +1,83 @@
+use std::collections::{BTreeSet, HashSet};
+use std::fmt::Write;
+
+use crate::utils::rng::Rng;
+
+/// At most this many valves have a positive flow rate, which is what the
+/// solvers' running time depends on.
+const MAX_WORKING_VALVES: usize = 10;
+
+/// Generates a scan of 2 + size valves (at most 676), always including AA with
+/// a flow rate of zero. Tunnels are two-way, and every valve has at least one.
+/// Half the time, given at least four valves, some of them form a separate
+/// group that can't be reached from AA.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let num_valves = (2 + size).min(26 * 26);
+
+    let mut names = vec!["AA".to_string()];
+    let mut used: HashSet<String> = names.iter().cloned().collect();
+    while names.len() < num_valves {
+        let name: String = (0..2)
+            .map(|_| (b'A' + rng.below(26) as u8) as char)
+            .collect();
+        if used.insert(name.clone()) {
+            names.push(name);
+        }
+    }
+
+    // Valves from detached onwards are unreachable from AA.
+    let detached = match num_valves >= 4 && rng.chance(1, 2) {
+        true => num_valves - rng.range_usize(2..=num_valves / 2),
+        false => num_valves,
+    };
+    let group = |i: usize| match i < detached {
+        true => 0..=detached - 1,
+        false => detached..=num_valves - 1,
+    };
+
+    // Join each valve to an earlier one in its group, so the whole group is
+    // reachable, then add a few more tunnels within groups to make loops.
+    let mut tunnels: Vec<BTreeSet<usize>> = vec![BTreeSet::new(); num_valves];
+    let mut join = |a: usize, b: usize| {
+        if a != b {
+            tunnels[a].insert(b);
+            tunnels[b].insert(a);
+        }
+    };
+    for i in 1..num_valves {
+        if i != detached {
+            join(i, rng.range_usize(*group(i).start()..=i - 1));
+        }
+    }
+    for _ in 0..num_valves / 3 {
+        let a = rng.range_usize(0..=num_valves - 1);
+        join(a, rng.range_usize(group(a)));
+    }
+
+    let mut working = 0;
+    let mut lines = Vec::with_capacity(num_valves);
+    for (i, name) in names.iter().enumerate() {
+        let rate =
+            match i > 0 && working < MAX_WORKING_VALVES && rng.chance(1, 3) {
+                true => {
+                    working += 1;
+                    rng.range(1..=25)
+                },
+                false => 0,
+            };
+
+        let mut to: Vec<&str> =
+            tunnels[i].iter().map(|t| names[*t].as_str()).collect();
+        rng.shuffle(&mut to);
+        let mut line = format!("Valve {name} has flow rate={rate}; ");
+        match to.len() {
+            1 => write!(line, "tunnel leads to valve {}", to[0]),
+            _ => write!(line, "tunnels lead to valves {}", to.join(", ")),
+        }
+        .unwrap();
+        lines.push(line);
+    }
+
+    rng.shuffle(&mut lines);
+    lines.join("\n") + "\n"
+}
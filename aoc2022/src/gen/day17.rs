@@ -0,0 +1,9 @@
+use crate::utils::rng::Rng;
+
+/// Generates a jet pattern of size (at least one) pushes.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut s: String =
+        (0..size.max(1)).map(|_| *rng.choose(&['<', '>'])).collect();
+    s.push('\n');
+    s
+}
@@ -0,0 +1,26 @@
+use std::collections::HashSet;
+use std::fmt::Write;
+
+use crate::utils::rng::Rng;
+
+/// Generates size (at least one) distinct cubes. They're packed into a box
+/// with room for about twice as many, so they clump together and enclose air
+/// pockets.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let count = size.max(1);
+    let mut side = 1;
+    while side * side * side < 2 * count {
+        side += 1;
+    }
+    let max = side as i64 - 1;
+
+    let mut seen = HashSet::new();
+    let mut s = String::new();
+    while seen.len() < count {
+        let v = (rng.range(0..=max), rng.range(0..=max), rng.range(0..=max));
+        if seen.insert(v) {
+            writeln!(s, "{},{},{}", v.0, v.1, v.2).unwrap();
+        }
+    }
+    s
+}
@@ -0,0 +1,30 @@
+use std::fmt::Write;
+
+use crate::utils::rng::Rng;
+
+/// Generates size (at least one) blueprints, with robot costs in the same
+/// ranges as real inputs.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut s = String::new();
+    for id in 1..=size.max(1) {
+        writeln!(
+            s,
+            concat!(
+                "Blueprint {}: ",
+                "Each ore robot costs {} ore. ",
+                "Each clay robot costs {} ore. ",
+                "Each obsidian robot costs {} ore and {} clay. ",
+                "Each geode robot costs {} ore and {} obsidian."
+            ),
+            id,
+            rng.range(2..=4),
+            rng.range(2..=4),
+            rng.range(2..=4),
+            rng.range(5..=20),
+            rng.range(2..=4),
+            rng.range(5..=20),
+        )
+        .unwrap();
+    }
+    s
+}
@@ -0,0 +1,25 @@
+use crate::utils::rng::Rng;
+
+/// Real inputs have 5000 numbers; more could overflow the part one solver.
+const MAX_LEN: usize = 5000;
+
+/// Generates 2 + size numbers (at most 5000), with magnitudes up to 10000.
+/// Exactly one of them is 0, as the puzzle requires.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let len = (2 + size).min(MAX_LEN);
+    let mut nums: Vec<i64> = (1..len)
+        .map(|_| match rng.range(-10_000..=9_999) {
+            n if n >= 0 => n + 1,
+            n => n,
+        })
+        .collect();
+    nums.push(0);
+    rng.shuffle(&mut nums);
+
+    let mut s = String::new();
+    for n in nums {
+        s.push_str(n.to_string().as_str());
+        s.push('\n');
+    }
+    s
+}
@@ -0,0 +1,121 @@
+use std::collections::HashSet;
+
+use crate::utils::rng::Rng;
+
+use super::lowercase;
+
+enum Job {
+    Number(i64),
+    Op(usize, char, usize),
+}
+
+struct Monkey {
+    name: String,
+    job: Job,
+}
+
+/// Generates size (at least one) monkeys doing operations below root, plus
+/// the monkeys yelling numbers they depend on. Every value is positive and
+/// every division is exact, and root's two operands are equal, so humn's
+/// number is also the answer to part two.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut monkeys = Vec::new();
+    let mut used: HashSet<String> =
+        ["root", "humn"].iter().map(|s| s.to_string()).collect();
+
+    // The tree is built from the top down, choosing operands that give each
+    // monkey the value it's meant to have.
+    let half = rng.range(1..=1_000_000);
+    let ops = size.max(1);
+    let lhs_ops = rng.range_usize(0..=ops);
+    let lhs = build(rng, &mut monkeys, &mut used, half, lhs_ops);
+    let rhs = build(rng, &mut monkeys, &mut used, half, ops - lhs_ops);
+    monkeys.push(Monkey {
+        name: "root".to_string(),
+        job: Job::Op(lhs, '+', rhs),
+    });
+
+    let leaves: Vec<usize> = (0..monkeys.len())
+        .filter(|i| matches!(monkeys[*i].job, Job::Number(_)))
+        .collect();
+    let humn = *rng.choose(&leaves);
+    monkeys[humn].name = "humn".to_string();
+
+    let mut lines: Vec<String> = monkeys
+        .iter()
+        .map(|m| match m.job {
+            Job::Number(n) => format!("{}: {n}", m.name),
+            Job::Op(l, op, r) => format!(
+                "{}: {} {op} {}",
+                m.name, monkeys[l].name, monkeys[r].name
+            ),
+        })
+        .collect();
+    rng.shuffle(&mut lines);
+    lines.join("\n") + "\n"
+}
+
+/// Adds monkeys yelling val using ops operations in total, returning the index
+/// of the top one. val must be positive.
+fn build(
+    rng: &mut Rng,
+    monkeys: &mut Vec<Monkey>,
+    used: &mut HashSet<String>,
+    val: i64,
+    ops: usize,
+) -> usize {
+    let job = match ops {
+        0 => Job::Number(val),
+        _ => {
+            let (lhs, op, rhs) = operands(rng, val);
+            let lhs_ops = rng.range_usize(0..=ops - 1);
+            let lhs = build(rng, monkeys, used, lhs, lhs_ops);
+            let rhs = build(rng, monkeys, used, rhs, ops - 1 - lhs_ops);
+            Job::Op(lhs, op, rhs)
+        },
+    };
+
+    let name = loop {
+        let name = lowercase(rng, 4..=4);
+        if used.insert(name.clone()) {
+            break name;
+        }
+    };
+    monkeys.push(Monkey { name, job });
+    monkeys.len() - 1
+}
+
+/// Picks an operation and positive operands giving the positive val exactly.
+fn operands(rng: &mut Rng, val: i64) -> (i64, char, i64) {
+    match rng.below(4) {
+        0 if val > 1 => {
+            let lhs = rng.range(1..=val - 1);
+            (lhs, '+', val - lhs)
+        },
+        1 => {
+            let rhs = rng.range(1..=100);
+            if let Some(lhs) = val.checked_add(rhs) {
+                return (lhs, '-', rhs);
+            }
+            (val, '*', 1)
+        },
+        2 => {
+            let divisors: Vec<i64> =
+                (2..=10).filter(|d| val % d == 0).collect();
+            match divisors.is_empty() {
+                true => (val, '*', 1),
+                false => {
+                    let d = *rng.choose(&divisors);
+                    (val / d, '*', d)
+                },
+            }
+        },
+        _ => {
+            let rhs = rng.range(2..=10);
+            match val.checked_mul(rhs) {
+                Some(lhs) => (lhs, '/', rhs),
+                None => (val, '*', 1),
+            }
+        },
+    }
+}
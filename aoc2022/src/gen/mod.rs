@@ -0,0 +1,141 @@
+//! gen produces random puzzle inputs in each day's exact input format, for
+//! stress testing parsers and solvers beyond the example and real inputs.
+//!
+//! Each day's module has a `generate(rng, size)` function. What size controls
+//! varies by day (e.g. the number of elves, lines or monkeys), and is
+//! documented on each; inputs grow with it, and any size is accepted.
+//!
+//! Each day's tests run its parsers and solvers on generated inputs through
+//! for_each_input, and check its parser round-trips them.
+
+use std::ops::{Range, RangeInclusive};
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::utils::rng::Rng;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+
+/// The days a generator exists for.
+pub const DAYS: RangeInclusive<u8> = 1..=21;
+
+/// The seeds for_each_input generates inputs from, at each size.
+pub const TEST_SEEDS: Range<u64> = 0..20;
+
+/// The sizes most days' tests generate inputs of.
+pub const TEST_SIZES: &[usize] = &[0, 1, 10, 100];
+
+/// Generates an input for the given day from the seed and size. Returns None
+/// if there's no generator for that day.
+pub fn generate(day: u8, seed: u64, size: usize) -> Option<String> {
+    let rng = &mut Rng::new(seed);
+    Some(match day {
+        1 => day01::generate(rng, size),
+        2 => day02::generate(rng, size),
+        3 => day03::generate(rng, size),
+        4 => day04::generate(rng, size),
+        5 => day05::generate(rng, size),
+        6 => day06::generate(rng, size),
+        7 => day07::generate(rng, size),
+        8 => day08::generate(rng, size),
+        9 => day09::generate(rng, size),
+        10 => day10::generate(rng, size),
+        11 => day11::generate(rng, size),
+        12 => day12::generate(rng, size),
+        13 => day13::generate(rng, size),
+        14 => day14::generate(rng, size),
+        15 => day15::generate(rng, size),
+        16 => day16::generate(rng, size),
+        17 => day17::generate(rng, size),
+        18 => day18::generate(rng, size),
+        19 => day19::generate(rng, size),
+        20 => day20::generate(rng, size),
+        21 => day21::generate(rng, size),
+        _ => return None,
+    })
+}
+
+/// Returns the generator for the given day as a closure over seed and size,
+/// for utils::test::assert_no_divergence. Panics if there's no generator for
+/// that day.
+pub fn generator(day: u8) -> impl Fn(u64, usize) -> String {
+    assert!(DAYS.contains(&day), "no generator for day {day}");
+    move |seed, size| generate(day, seed, size).unwrap()
+}
+
+/// Calls f with the day's input for each of TEST_SEEDS at each of the given
+/// sizes, for tests. If f panics, the seed and size are written to stderr
+/// first, so the input can be regenerated with the gen binary. Panics if
+/// there's no generator for that day.
+pub fn for_each_input(day: u8, sizes: &[usize], mut f: impl FnMut(&str)) {
+    let generate = generator(day);
+    for seed in TEST_SEEDS {
+        for &size in sizes {
+            let input = generate(seed, size);
+            let result = panic::catch_unwind(AssertUnwindSafe(|| f(&input)));
+            if let Err(e) = result {
+                eprintln!("failed on day {day} --seed {seed} --size {size}");
+                panic::resume_unwind(e);
+            }
+        }
+    }
+}
+
+/// Returns a random lowercase ASCII string with a length in the given range.
+fn lowercase(rng: &mut Rng, len: RangeInclusive<usize>) -> String {
+    (0..rng.range_usize(len))
+        .map(|_| (b'a' + rng.below(26) as u8) as char)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test::catch_unwind_silent;
+
+    #[test]
+    fn test_generate() {
+        for day in DAYS {
+            for size in [0, 1, 10] {
+                let a = generate(day, 1, size).unwrap();
+                assert_eq!(a, generate(day, 1, size).unwrap());
+                assert!(!a.is_empty(), "day {day} gave an empty input");
+                assert!(a.ends_with('\n'), "day {day} lacks a final newline");
+            }
+        }
+        assert!(generate(0, 1, 1).is_none());
+        assert!(generate(22, 1, 1).is_none());
+    }
+
+    #[test]
+    fn test_for_each_input() {
+        let mut inputs = Vec::new();
+        for_each_input(1, &[0, 5], |input| inputs.push(input.to_string()));
+        assert_eq!(inputs.len(), 2 * TEST_SEEDS.count());
+        assert_eq!(inputs[1], generate(1, 0, 5).unwrap());
+
+        let failed = catch_unwind_silent(|| {
+            for_each_input(1, &[1], |input| assert!(input.is_empty()))
+        });
+        assert!(failed.is_err());
+    }
+}
@@ -0,0 +1,9 @@
+//! Solutions to Advent of Code 2022. The shared library lives in aoc_core, and
+//! is re-exported here so each day can use it as before.
+
+pub use aoc_core::{max, min, types, utils};
+
+pub mod gen;
+
+/// The year of the event these solutions are for, which selects `data/2022`.
+pub const YEAR: u16 = 2022;
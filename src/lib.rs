@@ -1,2 +0,0 @@
-pub mod types;
-pub mod utils;